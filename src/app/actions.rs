@@ -1,11 +1,16 @@
-use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
 
 use crate::app::state::{
-    AddPartitionsFormState, AlterConfigFormState, BrokerInfo, ConnectionFormState, ConnectionProfile,
-    ConsumerGroupDetail, ConsumerGroupInfo, KafkaMessage, Level, ModalType, OffsetMode, ProduceFormState,
-    PurgeTopicFormState, Screen, SidebarItem, TopicCreateFormState, TopicDetail,
-    TopicInfo, TopicSortField,
+    AddPartitionsFormState, AlterConfigFormState, Bookmarks, BrokerDetail, BrokerInfo, CommandPaletteState, ConnectionFormState,
+    ConnectionProfile,
+    ConsumerGroupDetail, ConsumerGroupInfo, ExportFormat, GroupSortField, HistoryBrowserState, HistoryEntry,
+    KafkaMessage, Level, LogDetailState, ModalType, OffsetMode, ProduceFormState, PurgeTopicFormState, ResetOffsetsFormState,
+    Screen, SeekFormState, SessionSnapshot, SidebarItem, TopicCreateFormState, TopicDetail, TopicInfo, TopicSortField,
 };
+use crate::kafka::client::Delivered;
+use crate::kafka::protobuf::ProtoDescriptors;
+use crate::kafka::scan::{ScanPattern, ScanStop};
 
 #[derive(Debug, Clone)]
 pub enum Action {
@@ -26,42 +31,83 @@ pub enum Action {
     Disconnect,
     ConnectionSuccess,
     ConnectionFailed(String),
+    ConnectionRetrying { attempt: u32, max: u32, error: String },
+    /// A request failed because the brokers stopped answering; `failed` reports it
+    /// if reconnecting and running `retry` again doesn't help
+    ConnectionLost { retry: Box<Command>, failed: Box<Action> },
+    Reconnected,
+    ReconnectFailed(String),
+    /// Health ping finished: latency, or `None` if it failed
+    ClusterPinged(Option<std::time::Duration>),
     LoadSavedConnections,
     ConnectionsLoaded(Vec<ConnectionProfile>),
     SaveConnection(ConnectionProfile),
     RequestDeleteConnection,
     DeleteConnection(uuid::Uuid),
     ConnectionDeleted(uuid::Uuid),
+    BookmarksLoaded(Bookmarks),
+    SessionLoaded(SessionSnapshot),
+    ProtobufDescriptorsLoaded(Arc<ProtoDescriptors>),
+    ProtobufDescriptorsLoadFailed(String),
 
     // Topics
     FetchTopics,
     TopicsFetched(Vec<TopicInfo>),
     TopicsFetchFailed(String),
+    LoadTopicMessageCounts,
+    TopicMessageCountsFetched(Vec<(String, i64)>),
+    TopicMessageCountsFetchFailed(String),
     SelectTopic(usize),
     FilterTopics(String),
     ClearTopicFilter,
+    ToggleTopicFilterRegex,
+    ToggleTopicFilterFuzzy,
     SortTopics(TopicSortField),
-    CreateTopic { name: String, partitions: i32, replication_factor: i32 },
+    ToggleTopicBookmark,
+    ToggleTopicBookmarksOnly,
+    ToggleUnderReplicatedOnly,
+    CycleTopicSort,
+    ReverseTopicSort,
+    /// Open the create-topic form and look up the broker count for it
+    RequestCreateTopic,
+    CreateTopic { name: String, partitions: i32, replication_factor: i32, configs: Vec<(String, String)> },
     TopicCreated { name: String, partitions: i32, replication_factor: i32 },
     TopicCreateFailed(String),
+    RequestDeleteTopic(String),
     DeleteTopic(String),
     TopicDeleted(String),
     TopicDeleteFailed(String),
+    /// Check or uncheck the selected topic for a bulk delete
+    ToggleTopicMark,
+    /// Delete the checked topics, or the selected one when none are checked
+    RequestDeleteTopics,
+    TopicsDeleted { deleted: Vec<String>, failed: Vec<(String, String)> },
     RequestViewTopicDetails,
     ViewTopicDetails(String),
     TopicDetailsFetched(TopicDetail),
     TopicDetailsFetchFailed(String),
+    /// Re-read only the partition watermarks of the topic on the details screen
+    RefreshTopicWatermarks(String),
+    /// (partition, low, high) for each partition
+    TopicWatermarksFetched { topic: String, watermarks: Vec<(i32, i64, i64)> },
+    TopicWatermarksFetchFailed(String),
     SwitchTopicDetailTab,
+    CopyTopicConfigValue,
+    /// Copy the reviewed config change, or all of them, as `key: old → new` lines
+    CopyConfigChanges { all: bool },
     ViewTopicMessages(String),
+    /// Open the topic's messages filtered to one partition
+    ViewPartitionMessages { topic: String, partition: i32 },
 
     // Topic Management
     AddPartitions { topic: String, new_count: i32 },
     PartitionsAdded(String),
     PartitionsAddFailed(String),
-    AlterTopicConfig { topic: String, configs: Vec<(String, String)> },
+    AlterTopicConfig { topic: String, configs: Vec<(String, String)>, deletes: Vec<String> },
     TopicConfigAltered(String),
     TopicConfigAlterFailed(String),
     PurgeTopic { topic: String, before_offset: i64 },
+    PurgeEstimated { topic: String, before_offset: i64, count: i64 },
     TopicPurged(String),
     TopicPurgeFailed(String),
     UpdateAddPartitionsForm(AddPartitionsFormState),
@@ -69,20 +115,68 @@ pub enum Action {
     UpdatePurgeTopicForm(PurgeTopicFormState),
 
     // Messages
-    FetchMessages { topic: String, offset_mode: OffsetMode, partition: Option<i32> },
-    MessagesFetched(Vec<KafkaMessage>),
+    FetchMessages { topic: String, offset_mode: OffsetMode, partition: Option<i32>, limit: usize },
+    CycleFetchLimit,
+    /// Fetch the page of messages before (`older`) or after the loaded ones
+    PageMessages { topic: String, older: bool },
+    /// `timed_out`: the fetch deadline passed before the limit or the end of the topic
+    MessagesFetched { messages: Vec<KafkaMessage>, timed_out: bool },
     MessageReceived(KafkaMessage),
     MessagesFetchFailed(String),
     SelectMessage(usize),
     SetOffsetMode(OffsetMode),
     SetPartitionFilter(Option<i32>),
+    RequestSeek,
+    UpdateSeekForm(SeekFormState),
     StartConsuming { topic: String },
     StopConsuming,
-    ProduceMessage { topic: String, key: Option<String>, value: String, headers: HashMap<String, String> },
-    MessageProduced,
+    ConsumerFailed(String),
+    ProduceMessage { topic: String, key: Option<String>, value: String, headers: Vec<(String, String)> },
+    /// `last`: where the last of the `count` messages landed
+    MessageProduced { count: usize, last: Option<Delivered> },
     MessageProduceFailed(String),
+    ProduceFileReadFailed { form: ProduceFormState, error: String },
     ToggleMessageDetail,
+    CopyMessageValue,
+    CopyMessageKey,
+    NextSearchMatch,
+    PrevSearchMatch,
+    TogglePrettyJson,
+    CyclePayloadView,
+    ToggleDecompress,
+    /// Switch the message list between chronological and partition/offset order
+    ToggleMessageOrder,
+    /// Cycle message timestamps between clock, relative and ISO-8601
+    CycleTimestampFormat,
+    /// Browse the selected value as a collapsible JSON tree, or leave the tree
+    ToggleJsonTree,
+    /// Move the tree cursor by this many rows, clamped to the visible ones
+    MoveJsonTreeCursor(isize),
+    /// Open the node under the cursor, or step into an open one
+    ExpandJsonNode,
+    /// Close the node under the cursor, or step out to its parent
+    CollapseJsonNode,
+    ToggleJsonNode,
+    CopyJsonNode,
+    /// Ask for the descriptor set and protobuf type used to decode the current topic
+    RequestProtobufType,
+    ScrollMessageDetailUp,
+    ScrollMessageDetailDown,
     ClearMessages,
+    ExportMessages(ExportFormat),
+    MessagesExported(PathBuf),
+    MessagesExportFailed(String),
+    /// Matches found since the last report; `scanned` counts every message read so far
+    ScanProgress { scanned: u64, matches: Vec<KafkaMessage> },
+    ScanFinished { scanned: u64, stop: ScanStop },
+    ScanFailed(String),
+    CancelScan,
+
+    // History
+    ShowHistory,
+    HistoryLoaded(Vec<HistoryEntry>),
+    UpdateHistoryBrowser(HistoryBrowserState),
+    ClearHistory,
 
     // Consumer Groups
     FetchConsumerGroups,
@@ -91,20 +185,46 @@ pub enum Action {
     SelectConsumerGroup(usize),
     FilterConsumerGroups(String),
     ClearConsumerGroupFilter,
+    SortConsumerGroups(GroupSortField),
+    ToggleGroupBookmark,
+    ToggleGroupBookmarksOnly,
+    ToggleGroupConsumersOnly,
+    CycleConsumerGroupSort,
+    ReverseConsumerGroupSort,
     ViewConsumerGroupDetails(String),
     ConsumerGroupDetailsFetched(ConsumerGroupDetail),
     ConsumerGroupDetailsFetchFailed(String),
     SwitchConsumerGroupDetailTab,
+    RequestResetOffsets,
+    UpdateResetOffsetsForm(ResetOffsetsFormState),
+    ConsumerGroupOffsetsReset(String),
+    ConsumerGroupOffsetsResetFailed(String),
 
     // Brokers
     FetchBrokers,
     BrokersFetched { brokers: Vec<BrokerInfo>, cluster_id: Option<String> },
     BrokersFetchFailed(String),
+    CopyBrokerAddress,
+    FetchBrokerDetails(i32),
+    BrokerDetailsFetched(BrokerDetail),
+    BrokerDetailsFetchFailed(String),
+
+    // Throughput
+    RefreshThroughput,
+    /// Summed high watermark per topic, taken at `at`
+    ThroughputSampled { at: std::time::Instant, high_watermarks: Vec<(String, i64)> },
+    ThroughputSampleFailed(String),
 
     // Logs
     ClearLogs,
+    ExportLogs,
+    ViewLogEntry,
+    UpdateLogDetail(LogDetailState),
+    CopyLogEntry,
     CycleLogFilter,
     SetLogFilter(Option<Level>),
+    /// Show only log entries containing the text; empty clears it
+    FilterLogs(String),
 
     // UI
     ShowHelp,
@@ -118,6 +238,14 @@ pub enum Action {
     UpdateTopicCreateForm(TopicCreateFormState),
     UpdateProduceForm(ProduceFormState),
     ShowToast { message: String, level: Level },
+    /// Copy the active screen's table to the clipboard as Markdown
+    CopyView,
+    OpenCommandPalette,
+    /// Switch offsets, lag and counts between abbreviated and exact
+    ToggleNumberFormat,
+    UpdateCommandPalette(CommandPaletteState),
+    /// Close the palette and run the action its command's key maps to
+    RunPaletteCommand { label: &'static str, action: Box<Action> },
     DismissToast(uuid::Uuid),
 
     // Navigation
@@ -133,33 +261,75 @@ pub enum Action {
     Cancel,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum Command {
     None,
     Batch(Vec<Command>),
 
     // Kafka
     ConnectToKafka(ConnectionProfile),
+    /// Replace the client with a fresh one for `profile`, then run `retry`, or report `failed`
+    Reconnect { profile: ConnectionProfile, retry: Box<Command>, failed: Box<Action> },
     DisconnectFromKafka,
+    PingCluster,
     FetchTopicList,
+    FetchTopicMessageCounts(Vec<String>),
     FetchTopicDetails(String),
-    CreateKafkaTopic { name: String, partitions: i32, replication_factor: i32 },
+    /// Fetch the partition watermarks alone, without metadata details or config
+    FetchTopicWatermarks(String),
+    CreateKafkaTopic { name: String, partitions: i32, replication_factor: i32, configs: Vec<(String, String)> },
     DeleteKafkaTopic(String),
+    DeleteKafkaTopics(Vec<String>),
     FetchMessages { topic: String, offset_mode: OffsetMode, partition: Option<i32>, limit: usize },
     StartMessageConsumer { topic: String, offset_mode: OffsetMode, partition: Option<i32> },
     StopMessageConsumer,
-    ProduceKafkaMessage { topic: String, key: Option<String>, value: String, headers: HashMap<String, String> },
+    ScanMessages { topic: String, offset_mode: OffsetMode, partition: Option<i32>, pattern: ScanPattern },
+    CancelScan,
+    /// `partition: None` leaves the choice to the partitioner
+    ProduceKafkaMessage {
+        topic: String,
+        key: Option<String>,
+        value: String,
+        headers: Vec<(String, String)>,
+        partition: Option<i32>,
+        count: usize,
+    },
+    /// Read the payload from the file named in the form's value field, then produce it
+    ProduceFromFile { form: ProduceFormState, count: usize },
     FetchConsumerGroupList,
     FetchConsumerGroupDetails(String),
+    ResetConsumerGroupOffsets { group_id: String, topic: String, partition: Option<i32>, mode: OffsetMode },
     FetchBrokerList,
+    FetchBrokerDetails(i32),
+    SampleThroughput,
 
     // Topic Management
     AddTopicPartitions { topic: String, new_count: i32 },
-    AlterKafkaTopicConfig { topic: String, configs: Vec<(String, String)> },
+    /// Set `configs` and remove the overrides named in `deletes`
+    AlterKafkaTopicConfig { topic: String, configs: Vec<(String, String)>, deletes: Vec<String> },
+    /// Count what a purge would delete so it can be confirmed first
+    EstimatePurge { topic: String, before_offset: i64 },
     PurgeKafkaTopic { topic: String, before_offset: i64 },
 
     // Storage
+    ExportMessages { path: PathBuf, format: ExportFormat },
+    /// Write the in-memory log entries, oldest first, to a text file
+    ExportLogs(PathBuf),
     LoadConnectionProfiles,
     SaveConnectionProfile(ConnectionProfile),
     DeleteConnectionProfile(uuid::Uuid),
+    ExportConnectionProfiles { path: PathBuf, include_secrets: bool },
+    ImportConnectionProfiles { path: PathBuf, overwrite: bool },
+    LoadHistory,
+    RecordHistory(HistoryEntry),
+    ClearHistory,
+    LoadBookmarks(uuid::Uuid),
+    SaveBookmarks { profile_id: uuid::Uuid, bookmarks: Bookmarks },
+    SaveRecentCommands(Vec<String>),
+    LoadSession(uuid::Uuid),
+    SaveSession { profile_id: uuid::Uuid, session: SessionSnapshot },
+    LoadProtobufDescriptors(PathBuf),
+
+    // System
+    CopyToClipboard(String),
 }
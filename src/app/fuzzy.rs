@@ -0,0 +1,129 @@
+//! fzf-style fuzzy matching for the topic filter.
+//!
+//! A pattern matches when its characters appear in order in the text, ignoring case.
+//! Matches that start words (`team.service` → `ts`) or run together score higher, so
+//! abbreviations of structured topic names rank above scattered hits.
+
+/// Points for each matched character
+const SCORE_MATCH: i32 = 16;
+/// Extra points when a match directly follows the previous one
+const BONUS_CONSECUTIVE: i32 = 8;
+/// Extra points when a match starts the text or follows a separator
+const BONUS_BOUNDARY: i32 = 10;
+/// Cost of opening a gap between two matches
+const PENALTY_GAP_START: i32 = 3;
+/// Cost of every further character in a gap
+const PENALTY_GAP_EXTENSION: i32 = 1;
+/// Score of a cell no alignment reaches
+const NONE: i32 = i32::MIN / 2;
+
+/// A successful match: its score and the char positions in the text it used.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FuzzyMatch {
+    pub score: i32,
+    pub positions: Vec<usize>,
+}
+
+/// Match `pattern` against `text`, or `None` if its characters don't all appear in order.
+///
+/// Picks the highest-scoring alignment, so `oe` in `orders.events` takes the `e` that
+/// starts `events` rather than the first one in `orders`.
+pub fn fuzzy_match(pattern: &str, text: &str) -> Option<FuzzyMatch> {
+    let pattern: Vec<char> = pattern.chars().flat_map(char::to_lowercase).collect();
+    let chars: Vec<char> = text.chars().collect();
+    let lower: Vec<char> = chars.iter().map(|c| c.to_lowercase().next().unwrap_or(*c)).collect();
+
+    // Cheap rejection before scoring: the pattern must be a subsequence at all
+    let mut rest = pattern.iter().peekable();
+    for c in &lower {
+        if rest.peek() == Some(&c) {
+            rest.next();
+        }
+    }
+    if rest.peek().is_some() {
+        return None;
+    }
+    if pattern.is_empty() {
+        return Some(FuzzyMatch { score: 0, positions: Vec::new() });
+    }
+
+    let bonus: Vec<i32> = (0..chars.len())
+        .map(|i| {
+            let boundary = i == 0
+                || is_separator(chars[i - 1])
+                || (chars[i - 1].is_lowercase() && chars[i].is_uppercase());
+            SCORE_MATCH + if boundary { BONUS_BOUNDARY } else { 0 }
+        })
+        .collect();
+
+    // scores[j][i]: best score for pattern[..=j] with pattern[j] matched at text[i]
+    let mut scores = vec![vec![NONE; chars.len()]; pattern.len()];
+    for (i, c) in lower.iter().enumerate() {
+        if *c == pattern[0] {
+            scores[0][i] = bonus[i];
+        }
+    }
+    for j in 1..pattern.len() {
+        // Best predecessor at least two characters back, already charged for its gap
+        let mut gapped = NONE;
+        for i in 1..chars.len() {
+            if i >= 2 {
+                gapped = (gapped - PENALTY_GAP_EXTENSION).max(scores[j - 1][i - 2] - PENALTY_GAP_START);
+            }
+            if lower[i] == pattern[j] {
+                let prev = (scores[j - 1][i - 1] + BONUS_CONSECUTIVE).max(gapped);
+                if prev > NONE / 2 {
+                    scores[j][i] = prev + bonus[i];
+                }
+            }
+        }
+    }
+
+    // Walk back from the best end; ties keep the earliest end
+    let last = &scores[pattern.len() - 1];
+    let (mut i, score) = last.iter().copied().enumerate().fold((0, NONE), |best, (i, s)| if s > best.1 { (i, s) } else { best });
+    let mut positions = vec![i];
+    for j in (1..pattern.len()).rev() {
+        let target = scores[j][i] - bonus[i];
+        i = if scores[j - 1][i - 1] + BONUS_CONSECUTIVE == target {
+            i - 1
+        } else {
+            (0..i - 1)
+                .rev()
+                .find(|&k| scores[j - 1][k] - PENALTY_GAP_START - PENALTY_GAP_EXTENSION * (i - k - 2) as i32 == target)
+                .expect("a scored match has a predecessor")
+        };
+        positions.push(i);
+    }
+    positions.reverse();
+    Some(FuzzyMatch { score, positions })
+}
+
+fn is_separator(c: char) -> bool {
+    matches!(c, '.' | '-' | '_' | '/' | ':' | ' ')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fuzzy_match_positions_and_ranking() {
+        let m = fuzzy_match("tsev1", "team.service.entity.v1").unwrap();
+        assert_eq!(m.positions, [0, 5, 13, 20, 21]);
+        assert!(fuzzy_match("tsev2", "team.service.entity.v1").is_none());
+        assert_eq!(fuzzy_match("", "anything").unwrap().positions, Vec::<usize>::new());
+
+        // Case is ignored, and the run "ord" wins over the scattered word starts
+        assert_eq!(fuzzy_match("ORD", "o.r.orders").unwrap().positions, [4, 5, 6]);
+        assert_eq!(fuzzy_match("oe", "orders.events").unwrap().positions, [0, 7]);
+
+        // Word starts and runs beat the same letters scattered mid-word
+        let abbrev = fuzzy_match("oe", "orders.events").unwrap().score;
+        let scattered = fuzzy_match("oe", "shoe-store").unwrap().score;
+        assert!(abbrev > scattered, "{} <= {}", abbrev, scattered);
+        let run = fuzzy_match("pay", "payments").unwrap().score;
+        let spread = fuzzy_match("pay", "p.a.y").unwrap().score;
+        assert!(run > spread);
+    }
+}
@@ -1,7 +1,7 @@
 //! Broker action handlers.
 
 use crate::app::actions::{Action, Command};
-use crate::app::state::{AppState, Level};
+use crate::app::state::{AppState, ConnectionStatus, Level, ModalType};
 
 use super::super::update::toast;
 
@@ -15,8 +15,24 @@ pub fn handle(state: &mut AppState, action: &Action) -> Option<Command> {
 
         Action::BrokersFetched { brokers, cluster_id } => {
             state.brokers_state.brokers = brokers.clone();
+            state.brokers_state.hosts = brokers.iter().map(|b| (b.id, format!("{}:{}", b.host, b.port))).collect();
             state.brokers_state.cluster_id = cluster_id.clone();
             state.brokers_state.loading = false;
+            if let Some(ModalType::TopicCreateForm(f)) = &mut state.ui_state.active_modal {
+                f.broker_count = Some(brokers.len());
+            }
+            // Brokers can drop out after connecting; empty screens would pass for an empty cluster.
+            // An error status with a profile still active is that degradation, cleared once they return.
+            let degraded = matches!(state.connection.status, ConnectionStatus::Error(_))
+                && state.connection.active_profile.is_some();
+            if brokers.is_empty() && state.connection.status == ConnectionStatus::Connected {
+                let e = "the cluster reports no reachable brokers".to_string();
+                toast(state, &format!("Connection degraded: {}", e), Level::Error);
+                state.connection.status = ConnectionStatus::Error(e);
+            } else if !brokers.is_empty() && degraded {
+                state.connection.status = ConnectionStatus::Connected;
+                toast(state, "Brokers reachable again", Level::Success);
+            }
             Some(Command::None)
         }
 
@@ -26,6 +42,28 @@ pub fn handle(state: &mut AppState, action: &Action) -> Option<Command> {
             Some(Command::None)
         }
 
+        Action::CopyBrokerAddress => Some(
+            state
+                .brokers_state
+                .brokers
+                .get(state.brokers_state.selected_index)
+                .map(|b| Command::CopyToClipboard(format!("{}:{}", b.host, b.port)))
+                .unwrap_or(Command::None),
+        ),
+
+        Action::FetchBrokerDetails(id) => Some(Command::FetchBrokerDetails(*id)),
+
+        Action::BrokerDetailsFetched(detail) => {
+            state.brokers_state.current_detail = Some(detail.clone());
+            state.brokers_state.config_selected = 0;
+            Some(Command::None)
+        }
+
+        Action::BrokerDetailsFetchFailed(e) => {
+            toast(state, &format!("Failed to fetch broker config: {}", e), Level::Error);
+            Some(Command::None)
+        }
+
         _ => None,
     }
 }
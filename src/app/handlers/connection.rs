@@ -1,16 +1,25 @@
 //! Connection-related action handlers.
 
 use crate::app::actions::{Action, Command};
-use crate::app::state::{AppState, ConfirmAction, ConnectionStatus, Level, ModalType, Screen};
+use chrono::Utc;
 
-use super::super::update::toast;
+use crate::app::state::{
+    AppState, ConfirmAction, ConnectionProfile, ConnectionState, ConnectionStatus, Level, ModalType, PingStatus, Screen,
+    SidebarItem,
+};
+use crate::storage::connections::expand_home;
+
+use super::super::update::{toast, update};
 
 /// Handle connection actions.
 pub fn handle(state: &mut AppState, action: &Action) -> Option<Command> {
     match action {
+        Action::Connect(_) if state.connection.status.is_connecting() => Some(Command::None),
+
         Action::Connect(profile) => {
             state.connection.status = ConnectionStatus::Connecting;
             state.connection.active_profile = Some(profile.clone());
+            state.connection.protobuf = None;
             toast(state, &format!("Connecting to {}...", profile.brokers), Level::Info);
             Some(Command::ConnectToKafka(profile.clone()))
         }
@@ -18,36 +27,147 @@ pub fn handle(state: &mut AppState, action: &Action) -> Option<Command> {
         Action::ConnectionSuccess => {
             state.connection.status = ConnectionStatus::Connected;
             state.active_screen = Screen::Topics;
-            toast(state, "Connected", Level::Success);
-            let mut cmds = vec![Command::FetchTopicList, Command::FetchConsumerGroupList];
             if let Some(p) = &state.connection.active_profile {
+                state.messages_state.offset_mode = p.start_offset.offset_mode();
+            }
+            toast(state, "Connected", Level::Success);
+            // The broker list also resolves leader ids to hosts in the topic details
+            let mut cmds = vec![Command::FetchTopicList, Command::FetchConsumerGroupList, Command::FetchBrokerList];
+            if let Some(p) = state.connection.active_profile.as_mut() {
+                p.last_used = Some(Utc::now());
+            }
+            if let Some(p) = state.connection.active_profile.as_ref().filter(|_| !state.connection.ephemeral) {
+                // Keep the Welcome list in step, so it leads with this profile after a disconnect
+                let saved = &mut state.connection.available_profiles;
+                match saved.iter_mut().find(|s| s.id == p.id) {
+                    Some(s) => *s = p.clone(),
+                    None => saved.push(p.clone()),
+                }
+                cmds.push(Command::LoadBookmarks(p.id));
                 cmds.push(Command::SaveConnectionProfile(p.clone()));
+                if state.ui_state.remember_session {
+                    cmds.push(Command::LoadSession(p.id));
+                }
+            }
+            if let Some(path) = state.connection.active_profile.as_ref().and_then(|p| p.protobuf_descriptor_set.as_ref()) {
+                cmds.push(Command::LoadProtobufDescriptors(expand_home(path)));
             }
+            state.connection.sort_profiles_by_recency();
             Some(Command::Batch(cmds))
         }
 
+        Action::ProtobufDescriptorsLoaded(descriptors) => {
+            state.connection.protobuf = Some(descriptors.clone());
+            Some(Command::None)
+        }
+
+        Action::ProtobufDescriptorsLoadFailed(e) => {
+            state.connection.protobuf = None;
+            toast(state, e, Level::Error);
+            Some(Command::None)
+        }
+
+        Action::ClusterPinged(latency) => {
+            state.connection.ping_in_flight = false;
+            state.connection.last_ping = Some(PingStatus { latency: *latency, at: Utc::now() });
+            Some(Command::None)
+        }
+
+        Action::ConnectionRetrying { attempt, max, error } => {
+            state.connection.status = ConnectionStatus::Retrying { attempt: *attempt, max: *max };
+            toast(state, &format!("{} - retrying ({}/{})", error, attempt, max), Level::Warning);
+            Some(Command::None)
+        }
+
+        Action::ConnectionLost { retry, failed } => {
+            if !state.connection.can_reconnect(Utc::now()) {
+                return Some(update(state, (**failed).clone()));
+            }
+            let profile = state.connection.active_profile.clone()?;
+            if state.connection.status == ConnectionStatus::Connected {
+                tracing::warn!(brokers = %profile.brokers, "Connection lost, reconnecting");
+                state.connection.status = ConnectionStatus::Reconnecting;
+                toast(state, "Connection lost - reconnecting...", Level::Warning);
+            }
+            Some(Command::Reconnect { profile, retry: retry.clone(), failed: failed.clone() })
+        }
+
+        Action::Reconnected => {
+            tracing::info!("Reconnected");
+            state.connection.status = ConnectionStatus::Connected;
+            state.connection.last_reconnect = Some(Utc::now());
+            toast(state, "Reconnected", Level::Success);
+            Some(Command::None)
+        }
+
+        Action::ReconnectFailed(e) => {
+            tracing::warn!(error = %e, "Reconnect failed");
+            state.connection.status = ConnectionStatus::Error(e.clone());
+            state.connection.last_reconnect = Some(Utc::now());
+            toast(state, &format!("Reconnect failed: {}", e), Level::Error);
+            Some(Command::None)
+        }
+
         Action::ConnectionFailed(e) => {
             state.connection.status = ConnectionStatus::Error(e.clone());
             state.connection.active_profile = None;
-            toast(state, &format!("Connection failed: {}", e), Level::Error);
+            state.connection.ephemeral = false;
+            toast(state, e, Level::Error);
             Some(Command::None)
         }
 
         Action::Disconnect => {
-            state.connection = Default::default();
+            let save = save_session(state);
+            let available_profiles = std::mem::take(&mut state.connection.available_profiles);
+            state.connection = ConnectionState { available_profiles, ..Default::default() };
             state.topics_state = Default::default();
             state.messages_state = Default::default();
             state.consumer_groups_state = Default::default();
+            state.throughput_state = Default::default();
             state.active_screen = Screen::Welcome;
             state.screen_history.clear();
-            Some(Command::DisconnectFromKafka)
+            Some(match save {
+                Some(save) => Command::Batch(vec![save, Command::DisconnectFromKafka]),
+                None => Command::DisconnectFromKafka,
+            })
+        }
+
+        Action::BookmarksLoaded(b) => {
+            state.topics_state.bookmarks = b.topics.clone();
+            state.consumer_groups_state.bookmarks = b.groups.clone();
+            Some(Command::None)
+        }
+
+        Action::SessionLoaded(session) => {
+            let topics = &mut state.topics_state;
+            topics.set_filter(session.topic_filter.clone());
+            topics.restore_selection = session.topic.clone();
+            let groups = &mut state.consumer_groups_state;
+            groups.filter = session.group_filter.clone();
+            groups.restore_selection = session.group.clone();
+            // The lists may already be in by now
+            restore_selections(state);
+            if session.screen == SidebarItem::Topics {
+                return Some(Command::None);
+            }
+            super::navigation::handle(state, &Action::SelectSidebarItem(session.screen.clone()))
         }
 
         Action::LoadSavedConnections => Some(Command::LoadConnectionProfiles),
 
         Action::ConnectionsLoaded(p) => {
             state.connection.available_profiles = p.clone();
-            Some(Command::None)
+            state.connection.sort_profiles_by_recency();
+            let Some(name) = state.connection.pending_profile.take() else {
+                return Some(Command::None);
+            };
+            match p.iter().find(|profile| profile.name == name) {
+                Some(profile) => handle(state, &Action::Connect(profile.clone())),
+                None => {
+                    toast(state, &format!("No saved connection named '{}'", name), Level::Error);
+                    Some(Command::None)
+                }
+            }
         }
 
         Action::SaveConnection(p) => Some(Command::SaveConnectionProfile(p.clone())),
@@ -83,3 +203,45 @@ pub fn handle(state: &mut AppState, action: &Action) -> Option<Command> {
         _ => None,
     }
 }
+
+/// Change the active profile, keep the saved-profile list in step and persist the change.
+pub(super) fn update_active_profile(state: &mut AppState, change: impl FnOnce(&mut ConnectionProfile)) -> Command {
+    let Some(profile) = state.connection.active_profile.as_mut() else {
+        return Command::None;
+    };
+    change(profile);
+    let profile = profile.clone();
+    if let Some(saved) = state.connection.available_profiles.iter_mut().find(|p| p.id == profile.id) {
+        *saved = profile.clone();
+    }
+    if state.connection.ephemeral {
+        Command::None
+    } else {
+        Command::SaveConnectionProfile(profile)
+    }
+}
+
+/// Save where the user is on the active profile, when sessions are remembered.
+pub(super) fn save_session(state: &AppState) -> Option<Command> {
+    let profile = state.connection.active_profile.as_ref()?;
+    if !state.ui_state.remember_session || state.connection.ephemeral || state.active_screen == Screen::Welcome {
+        return None;
+    }
+    Some(Command::SaveSession { profile_id: profile.id, session: state.session() })
+}
+
+/// Select the topic and group named by a restored session, once their lists have loaded.
+pub(super) fn restore_selections(state: &mut AppState) {
+    let topics = &mut state.topics_state;
+    if !topics.topics.is_empty() {
+        if let Some(name) = topics.restore_selection.take() {
+            topics.selected_index = topics.filtered_topics().iter().position(|t| t.name == name).unwrap_or(0);
+        }
+    }
+    let groups = &mut state.consumer_groups_state;
+    if !groups.groups.is_empty() {
+        if let Some(id) = groups.restore_selection.take() {
+            groups.selected_index = groups.filtered_groups().iter().position(|g| g.group_id == id).unwrap_or(0);
+        }
+    }
+}
@@ -1,9 +1,14 @@
 //! Consumer group action handlers.
 
 use crate::app::actions::{Action, Command};
-use crate::app::state::{AppState, ConsumerGroupDetailTab, Level, Screen};
+use std::collections::BTreeSet;
+
+use crate::app::state::{
+    AppState, ConsumerGroupDetailTab, GroupSortField, Level, ModalType, ResetOffsetsFormState, Screen,
+};
 
 use super::super::update::toast;
+use super::topics::save_bookmarks;
 
 /// Handle consumer group actions.
 pub fn handle(state: &mut AppState, action: &Action) -> Option<Command> {
@@ -16,7 +21,9 @@ pub fn handle(state: &mut AppState, action: &Action) -> Option<Command> {
         Action::ConsumerGroupsFetched(groups) => {
             state.consumer_groups_state.groups = groups.clone();
             state.consumer_groups_state.loading = false;
+            sort_groups(state);
             state.consumer_groups_state.selected_index = 0;
+            super::connection::restore_selections(state);
             Some(Command::None)
         }
 
@@ -45,6 +52,55 @@ pub fn handle(state: &mut AppState, action: &Action) -> Option<Command> {
             Some(Command::None)
         }
 
+        Action::SortConsumerGroups(field) => {
+            let cs = &mut state.consumer_groups_state;
+            if cs.sort_by == *field {
+                cs.sort_ascending = !cs.sort_ascending;
+            } else {
+                cs.sort_by = field.clone();
+                cs.sort_ascending = true;
+            }
+            sort_groups(state);
+            Some(Command::None)
+        }
+
+        Action::ToggleGroupBookmark => {
+            let Some(id) = state.consumer_groups_state.selected_group().map(|g| g.group_id.clone()) else {
+                return Some(Command::None);
+            };
+            let cs = &mut state.consumer_groups_state;
+            if !cs.bookmarks.remove(&id) {
+                cs.bookmarks.insert(id);
+            }
+            cs.selected_index = cs.selected_index.min(cs.filtered_groups().len().saturating_sub(1));
+            Some(save_bookmarks(state))
+        }
+
+        Action::ToggleGroupBookmarksOnly => {
+            state.consumer_groups_state.bookmarks_only = !state.consumer_groups_state.bookmarks_only;
+            state.consumer_groups_state.selected_index = 0;
+            Some(Command::None)
+        }
+
+        Action::ToggleGroupConsumersOnly => {
+            state.consumer_groups_state.consumers_only = !state.consumer_groups_state.consumers_only;
+            state.consumer_groups_state.selected_index = 0;
+            Some(Command::None)
+        }
+
+        Action::CycleConsumerGroupSort => {
+            state.consumer_groups_state.sort_by = state.consumer_groups_state.sort_by.next();
+            state.consumer_groups_state.sort_ascending = true;
+            sort_groups(state);
+            Some(Command::None)
+        }
+
+        Action::ReverseConsumerGroupSort => {
+            state.consumer_groups_state.sort_ascending = !state.consumer_groups_state.sort_ascending;
+            sort_groups(state);
+            Some(Command::None)
+        }
+
         Action::ViewConsumerGroupDetails(id) => {
             state.screen_history.push(state.active_screen.clone());
             state.consumer_groups_state.current_detail = None;
@@ -71,6 +127,75 @@ pub fn handle(state: &mut AppState, action: &Action) -> Option<Command> {
             Some(Command::None)
         }
 
+        Action::RequestResetOffsets => {
+            let Some(detail) = &state.consumer_groups_state.current_detail else {
+                return Some(Command::None);
+            };
+            if !matches!(detail.state.to_lowercase().as_str(), "empty" | "dead") {
+                let msg = format!(
+                    "Cannot reset offsets while '{}' is {}; stop its consumers first",
+                    detail.group_id, detail.state
+                );
+                toast(state, &msg, Level::Error);
+                return Some(Command::None);
+            }
+            let topics: Vec<String> = detail.offsets.iter()
+                .map(|o| o.topic.clone())
+                .collect::<BTreeSet<_>>()
+                .into_iter()
+                .collect();
+            if topics.is_empty() {
+                toast(state, "No committed offsets to reset", Level::Warning);
+                return Some(Command::None);
+            }
+            state.ui_state.active_modal = Some(ModalType::ResetOffsetsForm(
+                ResetOffsetsFormState::new(detail.group_id.clone(), topics),
+            ));
+            Some(Command::None)
+        }
+
+        Action::UpdateResetOffsetsForm(f) => {
+            if let Some(ModalType::ResetOffsetsForm(s)) = &mut state.ui_state.active_modal {
+                *s = f.clone();
+            }
+            Some(Command::None)
+        }
+
+        Action::ConsumerGroupOffsetsReset(group_id) => {
+            toast(state, &format!("Offsets reset for '{}'", group_id), Level::Success);
+            Some(Command::FetchConsumerGroupDetails(group_id.clone()))
+        }
+
+        Action::ConsumerGroupOffsetsResetFailed(e) => {
+            toast(state, &format!("Reset offsets failed: {}", e), Level::Error);
+            Some(Command::None)
+        }
+
         _ => None,
     }
 }
+
+/// Re-sort the group list, keeping the selected group selected within the current filter.
+fn sort_groups(state: &mut AppState) {
+    let selected = state.consumer_groups_state.selected_group().map(|g| g.group_id.clone());
+    let asc = state.consumer_groups_state.sort_ascending;
+    state.consumer_groups_state.groups.sort_by(|a, b| {
+        let cmp = match state.consumer_groups_state.sort_by {
+            GroupSortField::GroupId => a.group_id.cmp(&b.group_id),
+            GroupSortField::State => a.state.cmp(&b.state),
+            GroupSortField::Members => a.members_count.cmp(&b.members_count),
+            GroupSortField::Lag => a.total_lag.cmp(&b.total_lag),
+        };
+        if asc {
+            cmp
+        } else {
+            cmp.reverse()
+        }
+    });
+
+    let filtered = state.consumer_groups_state.filtered_groups();
+    let index = selected
+        .and_then(|id| filtered.iter().position(|g| g.group_id == id))
+        .unwrap_or(0);
+    state.consumer_groups_state.selected_index = index.min(filtered.len().saturating_sub(1));
+}
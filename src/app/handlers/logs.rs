@@ -1,7 +1,10 @@
 //! Log screen action handlers.
 
 use crate::app::actions::{Action, Command};
-use crate::app::state::{AppState, Level};
+use crate::app::state::{AppState, Level, LogDetailState, ModalType};
+use crate::storage::export::default_log_export_path;
+
+use super::super::update::toast;
 
 /// Handle log screen actions.
 pub fn handle(state: &mut AppState, action: &Action) -> Option<Command> {
@@ -11,6 +14,36 @@ pub fn handle(state: &mut AppState, action: &Action) -> Option<Command> {
             Some(Command::None)
         }
 
+        Action::ExportLogs => {
+            if state.logs_state.entries.is_empty() {
+                toast(state, "No log entries to export", Level::Warning);
+                return Some(Command::None);
+            }
+            Some(Command::ExportLogs(default_log_export_path()))
+        }
+
+        Action::ViewLogEntry => {
+            if let Some(entry) = state.logs_state.selected_entry().cloned() {
+                state.ui_state.active_modal = Some(ModalType::LogDetail(LogDetailState::new(entry)));
+            }
+            Some(Command::None)
+        }
+
+        Action::UpdateLogDetail(d) => {
+            if let Some(ModalType::LogDetail(s)) = &mut state.ui_state.active_modal {
+                *s = d.clone();
+            }
+            Some(Command::None)
+        }
+
+        Action::CopyLogEntry => {
+            let line = match &state.ui_state.active_modal {
+                Some(ModalType::LogDetail(d)) => Some(d.entry.line()),
+                _ => state.logs_state.selected_entry().map(|e| e.line()),
+            };
+            Some(line.map(Command::CopyToClipboard).unwrap_or(Command::None))
+        }
+
         Action::CycleLogFilter => {
             state.logs_state.filter_level = match state.logs_state.filter_level {
                 None => Some(Level::Error),
@@ -29,6 +62,11 @@ pub fn handle(state: &mut AppState, action: &Action) -> Option<Command> {
             Some(Command::None)
         }
 
+        Action::FilterLogs(text) => {
+            state.logs_state.set_filter_text(text);
+            Some(Command::None)
+        }
+
         _ => None,
     }
 }
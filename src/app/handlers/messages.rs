@@ -1,7 +1,11 @@
 //! Message-related action handlers.
 
 use crate::app::actions::{Action, Command};
-use crate::app::state::{AppState, Level, OffsetMode, Screen};
+use crate::app::state::{
+    AppState, HistoryBrowserState, InputAction, Level, ModalType, Navigable, OffsetMode, Screen, SeekFormState, TopicScan,
+};
+use crate::kafka::scan::{ScanPattern, ScanStop};
+use crate::storage::export::default_export_path;
 
 use super::super::update::toast;
 
@@ -12,6 +16,7 @@ pub fn handle(state: &mut AppState, action: &Action) -> Option<Command> {
             topic,
             offset_mode,
             partition,
+            limit,
         } => {
             state.messages_state.loading = true;
             state.messages_state.offset_mode = offset_mode.clone();
@@ -20,19 +25,48 @@ pub fn handle(state: &mut AppState, action: &Action) -> Option<Command> {
                 topic: topic.clone(),
                 offset_mode: offset_mode.clone(),
                 partition: *partition,
-                limit: 100,
+                limit: *limit,
             })
         }
 
-        Action::MessagesFetched(msgs) => {
-            state.messages_state.messages = msgs.clone();
+        Action::PageMessages { topic, older } => {
+            let ms = &state.messages_state;
+            let Some(starts) = ms.page_offsets(*older) else {
+                let reason = if ms.messages.is_empty() { "No messages loaded to page from" } else { "Already at offset 0 in every partition" };
+                toast(state, reason, Level::Warning);
+                return Some(Command::None);
+            };
+            let fetch = Action::FetchMessages {
+                topic: topic.clone(),
+                offset_mode: OffsetMode::Partitions(starts),
+                partition: ms.partition_filter,
+                limit: ms.fetch_limit,
+            };
+            handle(state, &fetch)
+        }
+
+        Action::CycleFetchLimit => {
+            state.messages_state.cycle_fetch_limit();
+            let limit = state.messages_state.fetch_limit;
+            toast(state, &format!("Fetch limit: {}", limit), Level::Info);
+            Some(Command::None)
+        }
+
+        Action::MessagesFetched { messages, timed_out } => {
+            state.messages_state.scan = None;
+            state.messages_state.messages = messages.clone();
             state.messages_state.loading = false;
-            state.messages_state.selected_index = 0;
+            state.messages_state.set_selected_index(0);
+            state.messages_state.sort_messages();
+            if *timed_out {
+                let limit = state.messages_state.fetch_limit;
+                toast(state, &format!("Fetched {}/{} messages (timed out)", messages.len(), limit), Level::Info);
+            }
             Some(Command::None)
         }
 
         Action::MessageReceived(msg) => {
-            state.messages_state.messages.push(msg.clone());
+            state.messages_state.push_message(msg.clone());
             Some(Command::None)
         }
 
@@ -48,7 +82,7 @@ pub fn handle(state: &mut AppState, action: &Action) -> Option<Command> {
 
         Action::SelectMessage(i) => {
             if *i < state.messages_state.messages.len() {
-                state.messages_state.selected_index = *i;
+                state.messages_state.set_selected_index(*i);
             }
             Some(Command::None)
         }
@@ -63,13 +97,42 @@ pub fn handle(state: &mut AppState, action: &Action) -> Option<Command> {
             Some(Command::None)
         }
 
+        Action::RequestSeek => {
+            if let Screen::Messages { topic_name } = &state.active_screen {
+                let ms = &state.messages_state;
+                state.ui_state.active_modal = Some(ModalType::SeekForm(SeekFormState::new(
+                    topic_name.clone(),
+                    &ms.offset_mode,
+                    ms.partition_filter,
+                )));
+            }
+            Some(Command::None)
+        }
+
+        Action::UpdateSeekForm(f) => {
+            if let Some(ModalType::SeekForm(s)) = &mut state.ui_state.active_modal {
+                *s = f.clone();
+            }
+            Some(Command::None)
+        }
+
         Action::StartConsuming { topic } => {
-            state.messages_state.consumer_running = true;
-            Some(Command::StartMessageConsumer {
+            let ms = &mut state.messages_state;
+            ms.consumer_running = true;
+            // Only "latest" tails new records; any other position replays what is already loaded
+            if ms.offset_mode != OffsetMode::Latest {
+                ms.messages.clear();
+                ms.set_selected_index(0);
+            }
+            let start = Command::StartMessageConsumer {
                 topic: topic.clone(),
                 offset_mode: state.messages_state.offset_mode.clone(),
                 partition: state.messages_state.partition_filter,
-            })
+            };
+            match state.messages_state.scan.take().is_some_and(|s| s.running) {
+                true => Some(Command::Batch(vec![Command::CancelScan, start])),
+                false => Some(start),
+            }
         }
 
         Action::StopConsuming => {
@@ -77,6 +140,12 @@ pub fn handle(state: &mut AppState, action: &Action) -> Option<Command> {
             Some(Command::StopMessageConsumer)
         }
 
+        Action::ConsumerFailed(e) => {
+            state.messages_state.consumer_running = false;
+            toast(state, &format!("Live consumer stopped: {}", e), Level::Error);
+            Some(Command::None)
+        }
+
         Action::ProduceMessage {
             topic,
             key,
@@ -87,17 +156,26 @@ pub fn handle(state: &mut AppState, action: &Action) -> Option<Command> {
             key: key.clone(),
             value: value.clone(),
             headers: headers.clone(),
+            partition: None,
+            count: 1,
         }),
 
-        Action::MessageProduced => {
+        Action::MessageProduced { count, last } => {
             state.ui_state.active_modal = None;
-            toast(state, "Message produced", Level::Success);
+            let landed = last.map(|d| format!("partition {} @ offset {}", d.partition, d.offset));
+            let message = match (count, landed) {
+                (1, Some(landed)) => format!("Produced to {}", landed),
+                (1, None) => "Message produced".to_string(),
+                (n, Some(landed)) => format!("Produced {} messages, the last to {}", n, landed),
+                (n, None) => format!("Produced {} messages", n),
+            };
+            toast(state, &message, Level::Success);
             if let Screen::Messages { topic_name } = &state.active_screen {
                 Some(Command::FetchMessages {
                     topic: topic_name.clone(),
                     offset_mode: OffsetMode::Latest,
                     partition: None,
-                    limit: 100,
+                    limit: state.messages_state.fetch_limit,
                 })
             } else {
                 Some(Command::None)
@@ -109,17 +187,295 @@ pub fn handle(state: &mut AppState, action: &Action) -> Option<Command> {
             Some(Command::None)
         }
 
+        Action::ProduceFileReadFailed { form, error } => {
+            toast(state, &format!("Cannot read payload file: {}", error), Level::Error);
+            state.ui_state.active_modal = Some(ModalType::ProduceForm(form.clone()));
+            Some(Command::None)
+        }
+
         Action::ToggleMessageDetail => {
-            state.messages_state.detail_expanded = !state.messages_state.detail_expanded;
+            let ms = &mut state.messages_state;
+            ms.detail_expanded = !ms.detail_expanded;
+            ms.json_tree &= ms.detail_expanded;
+            Some(Command::None)
+        }
+
+        Action::ToggleJsonTree => {
+            if state.messages_state.json_tree {
+                state.messages_state.json_tree = false;
+            } else if state.selected_message_json().is_some() {
+                let ms = &mut state.messages_state;
+                ms.json_tree = true;
+                ms.detail_expanded = true;
+                ms.tree_selected = 0;
+            } else {
+                toast(state, "Message value is not JSON", Level::Warning);
+            }
+            Some(Command::None)
+        }
+
+        Action::MoveJsonTreeCursor(delta) => {
+            let len = state.json_tree_rows().map_or(0, |rows| rows.len());
+            let ms = &mut state.messages_state;
+            ms.tree_selected = ms.tree_selected.saturating_add_signed(*delta).min(len.saturating_sub(1));
+            Some(Command::None)
+        }
+
+        Action::ExpandJsonNode | Action::CollapseJsonNode | Action::ToggleJsonNode => {
+            let Some(rows) = state.json_tree_rows() else {
+                return Some(Command::None);
+            };
+            let ms = &mut state.messages_state;
+            let Some(row) = rows.get(ms.tree_selected) else {
+                return Some(Command::None);
+            };
+            let expanded = row.is_expanded();
+            if matches!(action, Action::ExpandJsonNode) && expanded {
+                // Step onto the first child; an empty container has none
+                if rows.get(ms.tree_selected + 1).is_some_and(|next| next.depth > row.depth) {
+                    ms.tree_selected += 1;
+                }
+            } else if matches!(action, Action::CollapseJsonNode) && !expanded {
+                let parent = row.parent_path();
+                if let Some(i) = rows.iter().position(|r| r.path == parent) {
+                    ms.tree_selected = i;
+                }
+            } else if row.is_container() && !ms.tree_expanded.remove(&row.path) {
+                ms.tree_expanded.insert(row.path.clone());
+            }
+            Some(Command::None)
+        }
+
+        Action::CopyJsonNode => {
+            let value = state.selected_message_json();
+            let rows = state.json_tree_rows().unwrap_or_default();
+            // A bare scalar's single row has the empty pointer, which is the whole value
+            let node = rows.get(state.messages_state.tree_selected).and_then(|row| value.as_ref()?.pointer(&row.path).cloned());
+            Some(match node {
+                Some(serde_json::Value::String(s)) => Command::CopyToClipboard(s),
+                Some(node) => Command::CopyToClipboard(serde_json::to_string_pretty(&node).unwrap_or_default()),
+                None => Command::None,
+            })
+        }
+
+        Action::CopyMessageValue => Some(
+            state
+                .messages_state
+                .selected_message()
+                .map(|m| Command::CopyToClipboard(m.value.clone()))
+                .unwrap_or(Command::None),
+        ),
+
+        Action::CopyMessageKey => {
+            match state.messages_state.selected_message().map(|m| m.key.clone()) {
+                Some(Some(key)) => Some(Command::CopyToClipboard(key)),
+                Some(None) => {
+                    toast(state, "Message has no key", Level::Info);
+                    Some(Command::None)
+                }
+                None => Some(Command::None),
+            }
+        }
+
+        Action::NextSearchMatch | Action::PrevSearchMatch => {
+            let forward = matches!(action, Action::NextSearchMatch);
+            if state.messages_state.message_search.is_some() {
+                match state.messages_state.find_match(forward) {
+                    Some(i) => state.messages_state.set_selected_index(i),
+                    None => toast(state, "No matching messages", Level::Info),
+                }
+            }
+            Some(Command::None)
+        }
+
+        Action::TogglePrettyJson => {
+            state.messages_state.pretty_json = !state.messages_state.pretty_json;
+            state.messages_state.detail_scroll = 0;
+            Some(Command::None)
+        }
+
+        Action::ToggleDecompress => {
+            state.messages_state.decompress = !state.messages_state.decompress;
+            state.messages_state.detail_scroll = 0;
+            Some(Command::None)
+        }
+
+        Action::ToggleMessageOrder => {
+            let ms = &mut state.messages_state;
+            ms.order = ms.order.toggle();
+            ms.sort_messages();
+            let order = ms.order.display_name();
+            toast(state, &format!("Sorted {}", order), Level::Info);
+            Some(Command::None)
+        }
+
+        Action::CycleTimestampFormat => {
+            let ms = &mut state.messages_state;
+            ms.timestamp_format = ms.timestamp_format.next();
+            let format = ms.timestamp_format.display_name();
+            toast(state, &format!("Timestamps: {}", format), Level::Info);
+            Some(Command::None)
+        }
+
+        Action::CyclePayloadView => {
+            state.messages_state.payload_view = state.messages_state.payload_view.next();
+            state.messages_state.detail_scroll = 0;
+            Some(Command::None)
+        }
+
+        Action::RequestProtobufType => {
+            if let (Screen::Messages { topic_name }, Some(profile)) =
+                (&state.active_screen, &state.connection.active_profile)
+            {
+                let path = profile.protobuf_descriptor_set.clone().unwrap_or_default();
+                state.ui_state.active_modal = Some(protobuf_descriptors_prompt(topic_name, path));
+            }
+            Some(Command::None)
+        }
+
+        Action::ScrollMessageDetailUp => {
+            state.messages_state.detail_scroll = state.messages_state.detail_scroll.saturating_sub(1);
+            Some(Command::None)
+        }
+
+        Action::ScrollMessageDetailDown => {
+            let ms = &mut state.messages_state;
+            if ms.detail_expanded {
+                ms.detail_scroll = ms.detail_scroll.saturating_add(1).min(ms.detail_max_scroll.get());
+            }
             Some(Command::None)
         }
 
         Action::ClearMessages => {
             state.messages_state.messages.clear();
-            state.messages_state.selected_index = 0;
+            state.messages_state.message_search = None;
+            state.messages_state.set_selected_index(0);
+            Some(Command::None)
+        }
+
+        Action::ExportMessages(format) => {
+            let Screen::Messages { topic_name } = &state.active_screen else {
+                return Some(Command::None);
+            };
+            if state.messages_state.messages.is_empty() {
+                toast(state, "No messages to export", Level::Warning);
+                return Some(Command::None);
+            }
+            Some(Command::ExportMessages {
+                path: default_export_path(topic_name, *format),
+                format: *format,
+            })
+        }
+
+        Action::MessagesExported(path) => {
+            toast(state, &format!("Exported to {}", path.display()), Level::Success);
+            Some(Command::None)
+        }
+
+        Action::MessagesExportFailed(e) => {
+            toast(state, &format!("Export failed: {}", e), Level::Error);
+            Some(Command::None)
+        }
+
+        Action::ScanProgress { scanned, matches } => {
+            let ms = &mut state.messages_state;
+            let Some(scan) = &mut ms.scan else {
+                return Some(Command::None);
+            };
+            scan.scanned = *scanned;
+            scan.matched += matches.len();
+            for msg in matches {
+                ms.push_message(msg.clone());
+            }
+            ms.sort_messages();
+            Some(Command::None)
+        }
+
+        Action::ScanFinished { scanned, stop } => {
+            let Some(scan) = &mut state.messages_state.scan else {
+                return Some(Command::None);
+            };
+            scan.running = false;
+            scan.scanned = *scanned;
+            let message = format!("Found {} matches in {} messages ({})", scan.matched, scanned, stop.describe());
+            let level = if *stop == ScanStop::Cancelled { Level::Info } else { Level::Success };
+            toast(state, &message, level);
+            Some(Command::None)
+        }
+
+        Action::ScanFailed(e) => {
+            if let Some(scan) = &mut state.messages_state.scan {
+                scan.running = false;
+            }
+            toast(state, &format!("Scan failed: {}", e), Level::Error);
             Some(Command::None)
         }
 
+        Action::CancelScan => Some(Command::CancelScan),
+
+        Action::ShowHistory => Some(Command::LoadHistory),
+
+        Action::HistoryLoaded(entries) => {
+            state.ui_state.active_modal = Some(ModalType::History(HistoryBrowserState {
+                entries: entries.clone(),
+                ..Default::default()
+            }));
+            Some(Command::None)
+        }
+
+        Action::UpdateHistoryBrowser(h) => {
+            if let Some(ModalType::History(s)) = &mut state.ui_state.active_modal {
+                *s = h.clone();
+            }
+            Some(Command::None)
+        }
+
+        Action::ClearHistory => {
+            if let Some(ModalType::History(s)) = &mut state.ui_state.active_modal {
+                *s = HistoryBrowserState::default();
+            }
+            Some(Command::ClearHistory)
+        }
+
         _ => None,
     }
 }
+
+/// Replace the listed messages with the matches of a scan from the current seek position.
+///
+/// The browser's "latest" position has no fixed start, so the scan then covers the whole topic.
+pub(super) fn start_scan(state: &mut AppState, topic: String, input: &str, pattern: ScanPattern) -> Command {
+    let ms = &mut state.messages_state;
+    let mut cmds = Vec::new();
+    if std::mem::take(&mut ms.consumer_running) {
+        cmds.push(Command::StopMessageConsumer);
+    }
+    ms.messages.clear();
+    ms.message_search = None;
+    ms.set_selected_index(0);
+    ms.scan = Some(TopicScan { pattern: input.trim().to_string(), running: true, ..Default::default() });
+    let offset_mode = match &ms.offset_mode {
+        OffsetMode::Latest => OffsetMode::Earliest,
+        mode => mode.clone(),
+    };
+    cmds.push(Command::ScanMessages { topic, offset_mode, partition: ms.partition_filter, pattern });
+    Command::Batch(cmds)
+}
+
+pub(super) fn protobuf_descriptors_prompt(topic: &str, value: String) -> ModalType {
+    ModalType::Input {
+        title: "Protobuf descriptor set".into(),
+        placeholder: "path to a .protoset file; empty turns protobuf decoding off".into(),
+        value,
+        action: InputAction::SetProtobufDescriptors { topic: topic.to_string() },
+    }
+}
+
+pub(super) fn protobuf_type_prompt(topic: &str, value: String) -> ModalType {
+    ModalType::Input {
+        title: format!("Protobuf type for '{}'", topic),
+        placeholder: "full message name, e.g. shop.v1.Order; empty shows it as text".into(),
+        value,
+        action: InputAction::SetProtobufType { topic: topic.to_string() },
+    }
+}
@@ -10,5 +10,6 @@ pub mod logs;
 pub mod messages;
 pub mod navigation;
 pub mod system;
+pub mod throughput;
 pub mod topics;
 pub mod ui;
@@ -1,7 +1,10 @@
 //! Navigation action handlers.
 
 use crate::app::actions::{Action, Command};
-use crate::app::state::{AppState, Navigable, Screen};
+use crate::app::state::{AppState, Navigable, Screen, TopicDetailTab};
+
+/// Rows PageUp/PageDown move on screens that don't report their height
+const PAGE_ROWS: usize = 10;
 
 /// Handle navigation actions.
 pub fn handle(state: &mut AppState, action: &Action) -> Option<Command> {
@@ -10,20 +13,30 @@ pub fn handle(state: &mut AppState, action: &Action) -> Option<Command> {
             if &state.active_screen == screen {
                 return Some(Command::None);
             }
-            state.screen_history.push(state.active_screen.clone());
+            // Lists are roots: Esc from one of them never returns to whatever was open before
+            if screen.is_top_level() {
+                state.screen_history.clear();
+            } else {
+                state.screen_history.push(state.active_screen.clone());
+            }
             state.active_screen = screen.clone();
-            Some(match screen {
+            let cmd = match screen {
                 Screen::Topics => Command::FetchTopicList,
                 Screen::ConsumerGroups => Command::FetchConsumerGroupList,
                 Screen::Brokers => Command::FetchBrokerList,
-                Screen::Messages { topic_name } => Command::FetchMessages {
-                    topic: topic_name.clone(),
-                    offset_mode: state.messages_state.offset_mode.clone(),
-                    partition: state.messages_state.partition_filter,
-                    limit: 100,
-                },
+                Screen::Throughput => super::throughput::sample(state),
+                Screen::Messages { topic_name } => {
+                    state.messages_state.open_topic(topic_name);
+                    Command::FetchMessages {
+                        topic: topic_name.clone(),
+                        offset_mode: state.messages_state.offset_mode.clone(),
+                        partition: state.messages_state.partition_filter,
+                        limit: state.messages_state.fetch_limit,
+                    }
+                }
                 _ => Command::None,
-            })
+            };
+            Some(with_consumer_stop(state, cmd))
         }
 
         Action::GoBack => {
@@ -33,10 +46,17 @@ pub fn handle(state: &mut AppState, action: &Action) -> Option<Command> {
             if std::mem::take(&mut state.ui_state.show_help) {
                 return Some(Command::None);
             }
+            if state.active_screen.is_top_level() {
+                // Nothing to go back to from a list; hand focus to the sidebar instead
+                if state.active_screen != Screen::Welcome {
+                    state.ui_state.sidebar_focused = true;
+                }
+                return Some(Command::None);
+            }
             if let Some(prev) = state.screen_history.pop() {
                 state.active_screen = prev;
             }
-            Some(Command::None)
+            Some(with_consumer_stop(state, Command::None))
         }
 
         Action::FocusSidebar => {
@@ -74,15 +94,11 @@ pub fn handle(state: &mut AppState, action: &Action) -> Option<Command> {
             Some(Command::None)
         }
         Action::PageUp => {
-            for _ in 0..10 {
-                nav_up(state);
-            }
+            page(state, -1);
             Some(Command::None)
         }
         Action::PageDown => {
-            for _ in 0..10 {
-                nav_down(state);
-            }
+            page(state, 1);
             Some(Command::None)
         }
         Action::ScrollToTop => {
@@ -100,6 +116,41 @@ pub fn handle(state: &mut AppState, action: &Action) -> Option<Command> {
     }
 }
 
+/// Stop the live consumer and any topic scan when the active screen is no longer the message browser.
+fn with_consumer_stop(state: &mut AppState, cmd: Command) -> Command {
+    let ms = &mut state.messages_state;
+    if matches!(state.active_screen, Screen::Messages { .. }) || !(ms.consumer_running || ms.scan_running()) {
+        return cmd;
+    }
+    let mut stops = Vec::new();
+    if std::mem::take(&mut ms.consumer_running) {
+        stops.push(Command::StopMessageConsumer);
+    }
+    if ms.scan.take().is_some_and(|s| s.running) {
+        stops.push(Command::CancelScan);
+    }
+    stops.push(cmd);
+    Command::Batch(stops)
+}
+
+/// Move a page up (`-1`) or down (`1`).
+///
+/// The topic list pages by the rows it last showed, in one step so its filter runs once;
+/// everything else moves a fixed number of rows.
+fn page(state: &mut AppState, direction: isize) {
+    if !state.ui_state.sidebar_focused && state.active_screen == Screen::Topics {
+        let rows = state.topics_state.page_rows.get().max(1) as isize;
+        return state.topics_state.nav_by(direction * rows);
+    }
+    for _ in 0..PAGE_ROWS {
+        if direction < 0 {
+            nav_up(state);
+        } else {
+            nav_down(state);
+        }
+    }
+}
+
 fn nav_up(state: &mut AppState) {
     if state.ui_state.sidebar_focused {
         return sidebar_prev(state);
@@ -110,6 +161,17 @@ fn nav_up(state: &mut AppState) {
         Screen::ConsumerGroups => state.consumer_groups_state.nav_up(),
         Screen::Welcome => state.connection.nav_up(),
         Screen::Logs => state.logs_state.nav_up(),
+        Screen::Brokers => state.brokers_state.nav_up(),
+        Screen::Throughput => state.throughput_state.nav_up(),
+        Screen::BrokerDetails { .. } => {
+            state.brokers_state.config_selected = state.brokers_state.config_selected.saturating_sub(1);
+        }
+        Screen::TopicDetails { .. } if state.topics_state.detail_tab == TopicDetailTab::Config => {
+            state.topics_state.config_selected = state.topics_state.config_selected.saturating_sub(1);
+        }
+        Screen::TopicDetails { .. } => {
+            state.topics_state.partition_selected = state.topics_state.partition_selected.saturating_sub(1);
+        }
         _ => {}
     }
 }
@@ -124,6 +186,26 @@ fn nav_down(state: &mut AppState) {
         Screen::ConsumerGroups => state.consumer_groups_state.nav_down(),
         Screen::Welcome => state.connection.nav_down(),
         Screen::Logs => state.logs_state.nav_down(),
+        Screen::Brokers => state.brokers_state.nav_down(),
+        Screen::Throughput => state.throughput_state.nav_down(),
+        Screen::BrokerDetails { .. } => {
+            let count = state.brokers_state.current_detail.as_ref().map_or(0, |d| d.config.len());
+            if state.brokers_state.config_selected + 1 < count {
+                state.brokers_state.config_selected += 1;
+            }
+        }
+        Screen::TopicDetails { .. } if state.topics_state.detail_tab == TopicDetailTab::Config => {
+            let count = state.topics_state.current_detail.as_ref().map_or(0, |d| d.config.len());
+            if state.topics_state.config_selected + 1 < count {
+                state.topics_state.config_selected += 1;
+            }
+        }
+        Screen::TopicDetails { .. } => {
+            let count = state.topics_state.current_detail.as_ref().map_or(0, |d| d.partitions.len());
+            if state.topics_state.partition_selected + 1 < count {
+                state.topics_state.partition_selected += 1;
+            }
+        }
         _ => {}
     }
 }
@@ -157,16 +239,12 @@ fn handle_select(state: &mut AppState) -> Command {
         Screen::Topics => {
             let name = state.topics_state.selected_topic().map(|t| t.name.clone());
             name.map(|n| {
-                state.screen_history.push(state.active_screen.clone());
-                state.messages_state.current_topic = Some(n.clone());
-                state.messages_state.messages.clear();
-                state.messages_state.selected_index = 0;
-                state.active_screen = Screen::Messages { topic_name: n.clone() };
+                super::topics::open_messages(state, &n);
                 Command::FetchMessages {
                     topic: n,
                     offset_mode: state.messages_state.offset_mode.clone(),
                     partition: state.messages_state.partition_filter,
-                    limit: 100,
+                    limit: state.messages_state.fetch_limit,
                 }
             })
             .unwrap_or(Command::None)
@@ -189,6 +267,19 @@ fn handle_select(state: &mut AppState) -> Command {
             })
             .unwrap_or(Command::None)
         }
+        Screen::Brokers => {
+            let id = state.brokers_state.brokers.get(state.brokers_state.selected_index).map(|b| b.id);
+            id.map(|id| {
+                state.screen_history.push(state.active_screen.clone());
+                state.brokers_state.current_detail = None;
+                state.active_screen = Screen::BrokerDetails { broker_id: id };
+                Command::FetchBrokerDetails(id)
+            })
+            .unwrap_or(Command::None)
+        }
+        Screen::Logs => super::logs::handle(state, &Action::ViewLogEntry).unwrap_or(Command::None),
+        // One connect at a time; the Welcome screen shows its progress
+        Screen::Welcome if state.connection.status.is_connecting() => Command::None,
         Screen::Welcome => {
             let profile = state
                 .connection
@@ -1,8 +1,14 @@
 //! System-level action handlers (Tick, Quit, Resize).
 
+use std::time::Instant;
+
 use crate::app::actions::{Action, Command};
-use crate::app::state::AppState;
+use chrono::Utc;
+
+use crate::app::state::{AppState, ConnectionStatus, Screen};
 
+use super::connection::save_session;
+use super::throughput::sample as sample_throughput;
 use super::ui::expire_toasts;
 
 /// Handle system-level actions.
@@ -10,13 +16,34 @@ pub fn handle(state: &mut AppState, action: &Action) -> Option<Command> {
     match action {
         Action::Tick => {
             expire_toasts(&mut state.ui_state.toast_messages);
-            Some(Command::None)
+            if state.connection.status != ConnectionStatus::Connected {
+                return Some(Command::None);
+            }
+            let mut cmds = Vec::new();
+            let conn = &mut state.connection;
+            let ping_due = conn.last_ping.as_ref().is_none_or(|p| p.is_stale(Utc::now()));
+            if !conn.ping_in_flight && ping_due {
+                conn.ping_in_flight = true;
+                cmds.push(Command::PingCluster);
+            }
+            // Rates only need sampling while someone is looking at them
+            if state.active_screen == Screen::Throughput && state.throughput_state.is_due(Instant::now()) {
+                cmds.push(sample_throughput(state));
+            }
+            Some(match cmds.len() {
+                0 => Command::None,
+                1 => cmds.remove(0),
+                _ => Command::Batch(cmds),
+            })
         }
         Action::Quit => {
             state.running = false;
+            Some(save_session(state).unwrap_or(Command::None))
+        }
+        Action::Resize(width, height) => {
+            state.ui_state.terminal_size = (*width, *height);
             Some(Command::None)
         }
-        Action::Resize(_, _) => Some(Command::None),
         _ => None,
     }
 }
@@ -0,0 +1,40 @@
+//! Cluster throughput action handlers.
+
+use crate::app::actions::{Action, Command};
+use crate::app::state::{AppState, Level, WatermarkSample};
+
+use super::super::update::toast;
+
+/// Handle throughput actions.
+pub fn handle(state: &mut AppState, action: &Action) -> Option<Command> {
+    match action {
+        Action::RefreshThroughput => Some(sample(state)),
+
+        Action::ThroughputSampled { at, high_watermarks } => {
+            state.throughput_state.record(WatermarkSample {
+                at: *at,
+                high_watermarks: high_watermarks.iter().cloned().collect(),
+            });
+            let count = state.throughput_state.top_rates().len();
+            state.throughput_state.selected_index = state.throughput_state.selected_index.min(count.saturating_sub(1));
+            Some(Command::None)
+        }
+
+        Action::ThroughputSampleFailed(e) => {
+            state.throughput_state.loading = false;
+            toast(state, &format!("Failed to sample throughput: {}", e), Level::Error);
+            Some(Command::None)
+        }
+
+        _ => None,
+    }
+}
+
+/// Start a watermark sample unless one is already in flight.
+pub(super) fn sample(state: &mut AppState) -> Command {
+    if state.throughput_state.loading {
+        return Command::None;
+    }
+    state.throughput_state.loading = true;
+    Command::SampleThroughput
+}
@@ -2,7 +2,8 @@
 
 use crate::app::actions::{Action, Command};
 use crate::app::state::{
-    AppState, Level, ModalType, Screen, TopicDetailTab, TopicInfo, TopicSortField,
+    AppState, ConfirmAction, InputAction, Level, ModalType, Screen, TopicCreateFormState, TopicDetailTab, TopicInfo,
+    TopicSortField,
 };
 
 use super::super::update::toast;
@@ -16,9 +17,12 @@ pub fn handle(state: &mut AppState, action: &Action) -> Option<Command> {
         }
 
         Action::TopicsFetched(topics) => {
+            state.topics_state.marked.retain(|name| topics.iter().any(|t| &t.name == name));
             state.topics_state.topics = topics.clone();
             state.topics_state.loading = false;
+            sort_topics(state);
             state.topics_state.selected_index = 0;
+            super::connection::restore_selections(state);
             Some(Command::None)
         }
 
@@ -28,6 +32,34 @@ pub fn handle(state: &mut AppState, action: &Action) -> Option<Command> {
             Some(Command::None)
         }
 
+        Action::LoadTopicMessageCounts => {
+            let names: Vec<String> = state
+                .topics_state
+                .filtered_topics()
+                .iter()
+                .map(|t| t.name.clone())
+                .collect();
+            if names.is_empty() || state.topics_state.counts_loading {
+                return Some(Command::None);
+            }
+            state.topics_state.counts_loading = true;
+            Some(Command::FetchTopicMessageCounts(names))
+        }
+
+        Action::TopicMessageCountsFetched(counts) => {
+            state.topics_state.counts_loading = false;
+            for (name, count) in counts {
+                set_message_count(state, name, *count);
+            }
+            Some(Command::None)
+        }
+
+        Action::TopicMessageCountsFetchFailed(e) => {
+            state.topics_state.counts_loading = false;
+            toast(state, &format!("Failed to load message counts: {}", e), Level::Error);
+            Some(Command::None)
+        }
+
         Action::SelectTopic(i) => {
             if *i < state.topics_state.filtered_topics().len() {
                 state.topics_state.selected_index = *i;
@@ -36,17 +68,31 @@ pub fn handle(state: &mut AppState, action: &Action) -> Option<Command> {
         }
 
         Action::FilterTopics(f) => {
-            state.topics_state.filter = f.clone();
+            state.topics_state.set_filter(f.clone());
             state.topics_state.selected_index = 0;
             Some(Command::None)
         }
 
         Action::ClearTopicFilter => {
-            state.topics_state.filter.clear();
+            state.topics_state.set_filter(String::new());
             state.topics_state.selected_index = 0;
             Some(Command::None)
         }
 
+        Action::ToggleTopicFilterRegex => {
+            state.topics_state.toggle_filter_regex();
+            state.topics_state.selected_index = 0;
+            preview_topic_filter(state);
+            Some(Command::None)
+        }
+
+        Action::ToggleTopicFilterFuzzy => {
+            state.topics_state.toggle_filter_fuzzy();
+            state.topics_state.selected_index = 0;
+            preview_topic_filter(state);
+            Some(Command::None)
+        }
+
         Action::SortTopics(field) => {
             if state.topics_state.sort_by == *field {
                 state.topics_state.sort_ascending = !state.topics_state.sort_ascending;
@@ -58,14 +104,62 @@ pub fn handle(state: &mut AppState, action: &Action) -> Option<Command> {
             Some(Command::None)
         }
 
+        Action::ToggleTopicBookmark => {
+            let Some(name) = state.topics_state.selected_topic().map(|t| t.name.clone()) else {
+                return Some(Command::None);
+            };
+            let ts = &mut state.topics_state;
+            if !ts.bookmarks.remove(&name) {
+                ts.bookmarks.insert(name);
+            }
+            ts.selected_index = ts.selected_index.min(ts.filtered_topics().len().saturating_sub(1));
+            Some(save_bookmarks(state))
+        }
+
+        Action::ToggleTopicBookmarksOnly => {
+            state.topics_state.bookmarks_only = !state.topics_state.bookmarks_only;
+            state.topics_state.selected_index = 0;
+            Some(Command::None)
+        }
+
+        Action::ToggleUnderReplicatedOnly => {
+            state.topics_state.under_replicated_only = !state.topics_state.under_replicated_only;
+            state.topics_state.selected_index = 0;
+            Some(Command::None)
+        }
+
+        Action::CycleTopicSort => {
+            state.topics_state.sort_by = state.topics_state.sort_by.next();
+            state.topics_state.sort_ascending = true;
+            sort_topics(state);
+            Some(Command::None)
+        }
+
+        Action::ReverseTopicSort => {
+            state.topics_state.sort_ascending = !state.topics_state.sort_ascending;
+            sort_topics(state);
+            Some(Command::None)
+        }
+
+        Action::RequestCreateTopic => {
+            let known = &state.brokers_state.brokers;
+            state.ui_state.active_modal = Some(ModalType::TopicCreateForm(TopicCreateFormState {
+                broker_count: (!known.is_empty()).then_some(known.len()),
+                ..Default::default()
+            }));
+            Some(Command::FetchBrokerList)
+        }
+
         Action::CreateTopic {
             name,
             partitions,
             replication_factor,
+            configs,
         } => Some(Command::CreateKafkaTopic {
             name: name.clone(),
             partitions: *partitions,
             replication_factor: *replication_factor,
+            configs: configs.clone(),
         }),
 
         Action::TopicCreated {
@@ -80,7 +174,10 @@ pub fn handle(state: &mut AppState, action: &Action) -> Option<Command> {
                 replication_factor: *replication_factor,
                 message_count: Some(0),
                 is_internal: false,
+                error: None,
+                has_under_replicated: false,
             });
+            sort_topics(state);
             toast(state, &format!("Topic '{}' created", name), Level::Success);
             Some(Command::None)
         }
@@ -90,6 +187,24 @@ pub fn handle(state: &mut AppState, action: &Action) -> Option<Command> {
             Some(Command::None)
         }
 
+        Action::RequestDeleteTopic(name) => {
+            state.ui_state.active_modal = Some(if is_internal_topic(state, name) {
+                confirm_internal_delete(name, String::new())
+            } else {
+                ModalType::Confirm {
+                    title: "Delete Topic".into(),
+                    message: format!("Delete '{}'?", name),
+                    action: ConfirmAction::DeleteTopic(name.clone()),
+                }
+            });
+            Some(Command::None)
+        }
+
+        Action::DeleteTopic(name) if is_internal_topic(state, name) => {
+            state.ui_state.active_modal = Some(confirm_internal_delete(name, String::new()));
+            Some(Command::None)
+        }
+
         Action::DeleteTopic(name) => Some(Command::DeleteKafkaTopic(name.clone())),
 
         Action::TopicDeleted(name) => {
@@ -98,6 +213,63 @@ pub fn handle(state: &mut AppState, action: &Action) -> Option<Command> {
             Some(Command::FetchTopicList)
         }
 
+        Action::ToggleTopicMark => {
+            let ts = &mut state.topics_state;
+            let Some(topic) = ts.selected_topic().cloned() else {
+                return Some(Command::None);
+            };
+            if topic.is_internal {
+                toast(state, "Internal topics can't be deleted in bulk", Level::Warning);
+                return Some(Command::None);
+            }
+            if !ts.marked.remove(&topic.name) {
+                ts.marked.insert(topic.name);
+            }
+            // Move on, so a run of topics is checked by holding Space
+            if ts.selected_index + 1 < ts.filtered_topics().len() {
+                ts.selected_index += 1;
+            }
+            Some(Command::None)
+        }
+
+        Action::RequestDeleteTopics => {
+            if state.topics_state.marked.is_empty() {
+                return match state.topics_state.selected_topic() {
+                    Some(topic) => handle(state, &Action::RequestDeleteTopic(topic.name.clone())),
+                    None => Some(Command::None),
+                };
+            }
+            // Marking refuses internal topics, but a refresh can turn up one by the same name
+            let mut names: Vec<String> =
+                state.topics_state.marked.iter().filter(|n| !is_internal_topic(state, n)).cloned().collect();
+            if names.is_empty() {
+                toast(state, "Only internal topics are marked; nothing to delete", Level::Warning);
+                return Some(Command::None);
+            }
+            names.sort();
+            state.ui_state.active_modal = Some(ModalType::Confirm {
+                title: format!("Delete {} Topics", names.len()),
+                message: format!("Delete these {} topics?\n\n{}", names.len(), names.join(", ")),
+                action: ConfirmAction::DeleteTopics(names),
+            });
+            Some(Command::None)
+        }
+
+        Action::TopicsDeleted { deleted, failed } => {
+            state.ui_state.active_modal = None;
+            for name in deleted {
+                state.topics_state.marked.remove(name);
+            }
+            if failed.is_empty() {
+                toast(state, &format!("Deleted {} topics", deleted.len()), Level::Success);
+            } else {
+                let reasons: Vec<String> = failed.iter().map(|(name, e)| format!("{} ({})", name, e)).collect();
+                let message = format!("Deleted {} topics; failed: {}", deleted.len(), reasons.join(", "));
+                toast(state, &message, Level::Error);
+            }
+            Some(Command::FetchTopicList)
+        }
+
         Action::TopicDeleteFailed(e) => {
             toast(state, &format!("Delete failed: {}", e), Level::Error);
             Some(Command::None)
@@ -131,10 +303,70 @@ pub fn handle(state: &mut AppState, action: &Action) -> Option<Command> {
         }
 
         Action::TopicDetailsFetched(detail) => {
+            // The details fetch already has the watermarks, so keep the list count in sync
+            let count = detail.partitions.iter().map(|p| p.message_count()).sum();
+            set_message_count(state, &detail.name, count);
+            let under_replicated = detail.partitions.iter().any(|p| p.is_under_replicated());
+            if let Some(topic) = state.topics_state.topics.iter_mut().find(|t| t.name == detail.name) {
+                topic.has_under_replicated = under_replicated;
+            }
+            // A refresh keeps the selected partition; another topic starts from the top
+            let ts = &mut state.topics_state;
+            let same_topic = ts.current_detail.as_ref().is_some_and(|d| d.name == detail.name);
+            ts.partition_selected = if same_topic {
+                ts.partition_selected.min(detail.partitions.len().saturating_sub(1))
+            } else {
+                0
+            };
             state.topics_state.current_detail = Some(detail.clone());
+            state.topics_state.config_selected = 0;
             Some(Command::None)
         }
 
+        Action::RefreshTopicWatermarks(topic) => Some(Command::FetchTopicWatermarks(topic.clone())),
+
+        Action::TopicWatermarksFetched { topic, watermarks } => {
+            let count = watermarks.iter().map(|(_, low, high)| high - low).sum();
+            set_message_count(state, topic, count);
+            if let Some(detail) = state.topics_state.current_detail.as_mut().filter(|d| &d.name == topic) {
+                for (id, low, high) in watermarks {
+                    if let Some(p) = detail.partitions.iter_mut().find(|p| p.id == *id) {
+                        p.low_watermark = *low;
+                        p.high_watermark = *high;
+                    }
+                }
+            }
+            Some(Command::None)
+        }
+
+        Action::TopicWatermarksFetchFailed(e) => {
+            toast(state, e, Level::Error);
+            Some(Command::None)
+        }
+
+        Action::CopyTopicConfigValue => Some(
+            state
+                .topics_state
+                .current_detail
+                .as_ref()
+                .and_then(|d| d.config.get(state.topics_state.config_selected))
+                .map(|(_, value)| Command::CopyToClipboard(value.clone()))
+                .unwrap_or(Command::None),
+        ),
+
+        Action::CopyConfigChanges { all } => {
+            let Some(ModalType::AlterConfigForm(form)) = &state.ui_state.active_modal else {
+                return Some(Command::None);
+            };
+            let changes = form.changes();
+            let lines: Vec<String> = match (all, form.review) {
+                (true, _) => changes.iter().map(|c| c.line()).collect(),
+                (false, Some(i)) => changes.get(i).map(|c| c.line()).into_iter().collect(),
+                (false, None) => Vec::new(),
+            };
+            Some(if lines.is_empty() { Command::None } else { Command::CopyToClipboard(lines.join("\n")) })
+        }
+
         Action::TopicDetailsFetchFailed(e) => {
             toast(state, e, Level::Error);
             Some(Command::None)
@@ -149,18 +381,23 @@ pub fn handle(state: &mut AppState, action: &Action) -> Option<Command> {
         }
 
         Action::ViewTopicMessages(name) => {
-            state.screen_history.push(state.active_screen.clone());
-            state.messages_state.current_topic = Some(name.clone());
-            state.messages_state.messages.clear();
-            state.messages_state.selected_index = 0;
-            state.active_screen = Screen::Messages {
-                topic_name: name.clone(),
-            };
+            open_messages(state, name);
             Some(Command::FetchMessages {
                 topic: name.clone(),
                 offset_mode: state.messages_state.offset_mode.clone(),
                 partition: state.messages_state.partition_filter,
-                limit: 100,
+                limit: state.messages_state.fetch_limit,
+            })
+        }
+
+        Action::ViewPartitionMessages { topic, partition } => {
+            open_messages(state, topic);
+            state.messages_state.partition_filter = Some(*partition);
+            Some(Command::FetchMessages {
+                topic: topic.clone(),
+                offset_mode: state.messages_state.offset_mode.clone(),
+                partition: Some(*partition),
+                limit: state.messages_state.fetch_limit,
             })
         }
 
@@ -184,9 +421,10 @@ pub fn handle(state: &mut AppState, action: &Action) -> Option<Command> {
             Some(Command::None)
         }
 
-        Action::AlterTopicConfig { topic, configs } => Some(Command::AlterKafkaTopicConfig {
+        Action::AlterTopicConfig { topic, configs, deletes } => Some(Command::AlterKafkaTopicConfig {
             topic: topic.clone(),
             configs: configs.clone(),
+            deletes: deletes.clone(),
         }),
 
         Action::TopicConfigAltered(topic) => {
@@ -212,6 +450,24 @@ pub fn handle(state: &mut AppState, action: &Action) -> Option<Command> {
             before_offset: *before_offset,
         }),
 
+        Action::PurgeEstimated { topic, before_offset, count } => {
+            if *count == 0 {
+                toast(state, &format!("Nothing to purge in '{}'", topic), Level::Info);
+            } else if *before_offset == i64::MAX {
+                state.ui_state.active_modal = Some(confirm_purge_all(topic, *count, String::new()));
+            } else {
+                state.ui_state.active_modal = Some(ModalType::Confirm {
+                    title: "Purge Topic".into(),
+                    message: format!(
+                        "Delete {} messages before offset {} from '{}'? This cannot be undone.",
+                        count, before_offset, topic
+                    ),
+                    action: ConfirmAction::PurgeTopic { topic: topic.clone(), before_offset: *before_offset },
+                });
+            }
+            Some(Command::None)
+        }
+
         Action::TopicPurged(topic) => {
             state.ui_state.active_modal = None;
             toast(
@@ -252,7 +508,23 @@ pub fn handle(state: &mut AppState, action: &Action) -> Option<Command> {
     }
 }
 
+/// Persist bookmarks for the active profile; `--brokers` sessions keep them in memory only
+pub(super) fn save_bookmarks(state: &AppState) -> Command {
+    match &state.connection.active_profile {
+        Some(p) if !state.connection.ephemeral => Command::SaveBookmarks { profile_id: p.id, bookmarks: state.bookmarks() },
+        _ => Command::None,
+    }
+}
+
+fn set_message_count(state: &mut AppState, name: &str, count: i64) {
+    if let Some(topic) = state.topics_state.topics.iter_mut().find(|t| t.name == name) {
+        topic.message_count = Some(count);
+    }
+}
+
+/// Re-sort the topic list, keeping the selected topic selected within the current filter.
 fn sort_topics(state: &mut AppState) {
+    let selected = state.topics_state.selected_topic().map(|t| t.name.clone());
     let asc = state.topics_state.sort_ascending;
     state.topics_state.topics.sort_by(|a, b| {
         let cmp = match state.topics_state.sort_by {
@@ -266,4 +538,62 @@ fn sort_topics(state: &mut AppState) {
             cmp.reverse()
         }
     });
+
+    let filtered = state.topics_state.filtered_topics();
+    let index = selected
+        .and_then(|name| filtered.iter().position(|t| t.name == name))
+        .unwrap_or(0);
+    state.topics_state.selected_index = index.min(filtered.len().saturating_sub(1));
+}
+
+/// Internal topics (`__consumer_offsets`, `__transaction_state`, ...) need a typed confirmation.
+fn is_internal_topic(state: &AppState, name: &str) -> bool {
+    let ts = &state.topics_state;
+    ts.current_detail.as_ref().filter(|d| d.name == name).map(|d| d.is_internal)
+        .or_else(|| ts.topics.iter().find(|t| t.name == name).map(|t| t.is_internal))
+        .unwrap_or_else(|| name.starts_with("__"))
+}
+
+/// Typed confirmation before deleting every message in a topic.
+pub(super) fn confirm_purge_all(topic: &str, count: i64, value: String) -> ModalType {
+    ModalType::Input {
+        title: format!("Purge all {} messages from '{}'", count, topic),
+        placeholder: "type purge to confirm".into(),
+        value,
+        action: InputAction::ConfirmPurgeAll { topic: topic.to_string(), count },
+    }
+}
+
+/// Switch to the topic's Messages screen with an empty list, ready for a fetch.
+pub(super) fn open_messages(state: &mut AppState, topic: &str) {
+    state.screen_history.push(state.active_screen.clone());
+    state.messages_state.open_topic(topic);
+    state.active_screen = Screen::Messages { topic_name: topic.to_string() };
+}
+
+/// Prompt that only deletes once the exact topic name has been typed.
+pub(super) fn confirm_internal_delete(name: &str, value: String) -> ModalType {
+    ModalType::Input {
+        title: format!("Delete internal topic '{}'", name),
+        placeholder: "type the topic name to confirm".into(),
+        value,
+        action: InputAction::ConfirmDeleteTopic(name.to_string()),
+    }
+}
+
+/// Fuzzy mode filters as the prompt is typed in, fzf-style; other modes wait for Enter.
+pub fn preview_topic_filter(state: &mut AppState) {
+    let Some(ModalType::Input { value, action: InputAction::FilterTopics, .. }) = &state.ui_state.active_modal else {
+        return;
+    };
+    let ts = &mut state.topics_state;
+    let filter = if ts.filter_fuzzy {
+        value.clone()
+    } else {
+        ts.filter_before_prompt.clone().unwrap_or_default()
+    };
+    if filter != ts.filter {
+        ts.set_filter(filter);
+        ts.clamp_selection();
+    }
 }
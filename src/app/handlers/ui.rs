@@ -5,12 +5,29 @@ use uuid::Uuid;
 
 use crate::app::actions::{Action, Command};
 use crate::app::state::{
-    AppState, AuthConfig, AuthType, ConfirmAction, ConnectionProfile, ConnectionStatus,
-    InputAction, Level, ModalType, ToastMessage,
+    AppState, AuthConfig, AuthType, CommandPaletteState, ConfirmAction, ConnectionFormState, ConnectionProfile, ConnectionStatus, HistoryEntry, HistoryKind,
+    InputAction, Level, ModalType, Navigable, OffsetMode, ResetOffsetMode, ProduceFormState, ResetOffsetsFormState, Screen,
+    SeekFormState, ToastMessage,
 };
 use crate::app::validation::{
-    parse_new_partition_count, parse_offset, parse_partitions, parse_replication_factor,
+    parse_new_partition_count, parse_offset, parse_optional_partition, parse_partitions,
+    parse_produce_count, parse_produce_partition, parse_replication_factor, parse_timeout_ms, parse_timestamp, validate_brokers, validate_headers, validate_http_url, validate_readable_file,
+    validate_topic_configs,
 };
+use crate::error::AppError;
+use crate::kafka::scan::ScanPattern;
+use crate::events::key_bindings::palette_commands;
+use crate::storage::connections::expand_home;
+use crate::storage::recent_commands::MAX_RECENT_COMMANDS;
+use crate::ui::table_text;
+
+use super::super::update::update;
+use super::connection::update_active_profile;
+use super::messages::{protobuf_descriptors_prompt, protobuf_type_prompt, start_scan};
+use super::topics::{confirm_internal_delete, confirm_purge_all, open_messages, preview_topic_filter};
+
+/// Produce bursts larger than this ask for confirmation first
+const PRODUCE_CONFIRM_COUNT: usize = 1_000;
 
 /// Handle UI/modal actions.
 pub fn handle(state: &mut AppState, action: &Action) -> Option<Command> {
@@ -26,6 +43,9 @@ pub fn handle(state: &mut AppState, action: &Action) -> Option<Command> {
         }
 
         Action::ShowModal(m) => {
+            if matches!(m, ModalType::Input { action: InputAction::FilterTopics, .. }) {
+                state.topics_state.filter_before_prompt = Some(state.topics_state.filter.clone());
+            }
             state.ui_state.active_modal = Some(m.clone());
             Some(Command::None)
         }
@@ -35,10 +55,51 @@ pub fn handle(state: &mut AppState, action: &Action) -> Option<Command> {
             Some(Command::None)
         }
 
+        Action::OpenCommandPalette => {
+            let commands = palette_commands(&state.active_screen);
+            let palette = CommandPaletteState::new(commands, &state.ui_state.recent_commands);
+            state.ui_state.active_modal = Some(ModalType::CommandPalette(palette));
+            Some(Command::None)
+        }
+
+        Action::ToggleNumberFormat => {
+            let format = state.ui_state.number_format.toggle();
+            state.ui_state.number_format = format;
+            toast(state, &format!("Numbers: {}", format.display_name()), Level::Info);
+            Some(Command::None)
+        }
+
+        Action::UpdateCommandPalette(p) => {
+            if let Some(ModalType::CommandPalette(s)) = &mut state.ui_state.active_modal {
+                *s = p.clone();
+            }
+            Some(Command::None)
+        }
+
+        Action::RunPaletteCommand { label, action } => {
+            state.ui_state.active_modal = None;
+            state.ui_state.sidebar_focused = false;
+            let recent = &mut state.ui_state.recent_commands;
+            recent.retain(|r| r != label);
+            recent.insert(0, label.to_string());
+            recent.truncate(MAX_RECENT_COMMANDS);
+            let save = Command::SaveRecentCommands(recent.clone());
+            Some(Command::Batch(vec![save, update(state, (**action).clone())]))
+        }
+
         Action::ModalConfirm => Some(handle_modal_confirm(state)),
 
         Action::ModalCancel => {
-            state.ui_state.active_modal = None;
+            let modal = state.ui_state.active_modal.take();
+            if let Some(ModalType::Input { action: InputAction::FilterTopics, .. }) = modal {
+                // Undo the fuzzy preview
+                if let Some(filter) = state.topics_state.filter_before_prompt.take() {
+                    if filter != state.topics_state.filter {
+                        state.topics_state.set_filter(filter);
+                        state.topics_state.clamp_selection();
+                    }
+                }
+            }
             Some(Command::None)
         }
 
@@ -46,6 +107,7 @@ pub fn handle(state: &mut AppState, action: &Action) -> Option<Command> {
             if let Some(ModalType::Input { value, .. }) = &mut state.ui_state.active_modal {
                 *value = v.clone();
             }
+            preview_topic_filter(state);
             Some(Command::None)
         }
 
@@ -80,6 +142,14 @@ pub fn handle(state: &mut AppState, action: &Action) -> Option<Command> {
             Some(Command::None)
         }
 
+        Action::CopyView => match table_text::current_view(state) {
+            Some(text) => Some(Command::CopyToClipboard(text)),
+            None => {
+                toast(state, "Nothing to copy on this screen", Level::Info);
+                Some(Command::None)
+            }
+        },
+
         _ => None,
     }
 }
@@ -109,12 +179,18 @@ fn handle_modal_confirm(state: &mut AppState) -> Command {
     match modal {
         ModalType::Confirm { action, .. } => match action {
             ConfirmAction::DeleteTopic(n) => Command::DeleteKafkaTopic(n),
+            ConfirmAction::DeleteTopics(names) => Command::DeleteKafkaTopics(names),
+            ConfirmAction::PurgeTopic { topic, before_offset } => Command::PurgeKafkaTopic { topic, before_offset },
+            ConfirmAction::ProduceBurst { form, count } | ConfirmAction::ProduceToMissingTopic { form, count } => {
+                produce_command(form, count)
+            }
             ConfirmAction::DeleteConnection(id) => Command::DeleteConnectionProfile(id),
             ConfirmAction::DisconnectCluster => Command::DisconnectFromKafka,
         },
         ModalType::Input { action, value, .. } => match action {
             InputAction::FilterTopics => {
-                state.topics_state.filter = value;
+                state.topics_state.filter_before_prompt = None;
+                state.topics_state.set_filter(value);
                 state.topics_state.selected_index = 0;
                 Command::None
             }
@@ -123,19 +199,124 @@ fn handle_modal_confirm(state: &mut AppState) -> Command {
                 state.consumer_groups_state.selected_index = 0;
                 Command::None
             }
+            InputAction::FilterLogs => {
+                state.logs_state.set_filter_text(&value);
+                Command::None
+            }
+            InputAction::SearchMessages => {
+                let ms = &mut state.messages_state;
+                ms.message_search = (!value.is_empty()).then_some(value);
+                if ms.selected_message().is_some_and(|m| !ms.matches_search(m)) {
+                    if let Some(i) = ms.find_match(true) {
+                        ms.set_selected_index(i);
+                    }
+                }
+                Command::None
+            }
+            InputAction::ScanTopic { topic } => match ScanPattern::parse(&value) {
+                Ok(pattern) => start_scan(state, topic, &value, pattern),
+                Err(e) => {
+                    toast(state, &e.to_string(), Level::Error);
+                    Command::None
+                }
+            },
             InputAction::ProduceMessage { topic } => Command::ProduceKafkaMessage {
                 topic,
                 key: None,
                 value,
                 headers: Default::default(),
+                partition: None,
+                count: 1,
             },
             InputAction::CreateTopic => Command::CreateKafkaTopic {
                 name: value,
                 partitions: 1,
                 replication_factor: 1,
+                configs: Vec::new(),
+            },
+            InputAction::ExportProfiles { include_secrets } => Command::ExportConnectionProfiles {
+                path: expand_home(&value),
+                include_secrets,
             },
+            InputAction::ImportProfiles { overwrite } => Command::ImportConnectionProfiles {
+                path: expand_home(&value),
+                overwrite,
+            },
+            InputAction::ConfirmDeleteTopic(name) if value == name => Command::DeleteKafkaTopic(name),
+            InputAction::ConfirmDeleteTopic(name) => {
+                toast(state, "Topic name does not match; nothing was deleted", Level::Warning);
+                state.ui_state.active_modal = Some(confirm_internal_delete(&name, value));
+                Command::None
+            }
+            InputAction::ConfirmPurgeAll { topic, .. } if value == "purge" => Command::PurgeKafkaTopic {
+                topic,
+                before_offset: i64::MAX,
+            },
+            InputAction::ConfirmPurgeAll { topic, count } => {
+                toast(state, "Type 'purge' to confirm; nothing was deleted", Level::Warning);
+                state.ui_state.active_modal = Some(confirm_purge_all(&topic, count, value));
+                Command::None
+            }
+            InputAction::SetProtobufDescriptors { topic } => {
+                let path = value.trim().to_string();
+                if path.is_empty() {
+                    state.connection.protobuf = None;
+                    toast(state, "Protobuf decoding turned off", Level::Info);
+                    return update_active_profile(state, |p| p.protobuf_descriptor_set = None);
+                }
+                let full_path = expand_home(&path);
+                if let Err(e) = validate_readable_file("Descriptor set", &full_path.to_string_lossy()) {
+                    toast(state, &e.to_string(), Level::Error);
+                    state.ui_state.active_modal = Some(protobuf_descriptors_prompt(&topic, value));
+                    return Command::None;
+                }
+                let current = state
+                    .connection
+                    .active_profile
+                    .as_ref()
+                    .and_then(|p| p.protobuf_types.get(&topic).cloned())
+                    .unwrap_or_default();
+                state.ui_state.active_modal = Some(protobuf_type_prompt(&topic, current));
+                let save = update_active_profile(state, |p| p.protobuf_descriptor_set = Some(path));
+                Command::Batch(vec![Command::LoadProtobufDescriptors(full_path), save])
+            }
+            InputAction::SetProtobufType { topic } => {
+                let type_name = value.trim().trim_start_matches('.').to_string();
+                if type_name.is_empty() {
+                    toast(state, &format!("'{}' is shown as text", topic), Level::Info);
+                    return update_active_profile(state, |p| {
+                        p.protobuf_types.remove(&topic);
+                    });
+                }
+                if state.connection.protobuf.as_ref().is_some_and(|d| !d.contains(&type_name)) {
+                    toast(state, &format!("No message type '{}' in the descriptor set", type_name), Level::Error);
+                    state.ui_state.active_modal = Some(protobuf_type_prompt(&topic, value));
+                    return Command::None;
+                }
+                toast(state, &format!("Decoding '{}' as {}", topic, type_name), Level::Success);
+                update_active_profile(state, |p| {
+                    p.protobuf_types.insert(topic, type_name);
+                })
+            }
         },
         ModalType::ConnectionForm(f) => {
+            let timeouts = validate_brokers(&f.brokers)
+                .and_then(|()| validate_tls_files(&f))
+                .and_then(|()| validate_http_url("token_endpoint", &f.token_endpoint))
+                .and_then(|()| {
+                    Ok((
+                        parse_timeout_ms("socket_timeout", &f.socket_timeout)?,
+                        parse_timeout_ms("request_timeout", &f.request_timeout)?,
+                    ))
+                });
+            let (socket_timeout_ms, request_timeout_ms) = match timeouts {
+                Ok(timeouts) => timeouts,
+                Err(e) => {
+                    toast(state, &e.to_string(), Level::Error);
+                    state.ui_state.active_modal = Some(ModalType::ConnectionForm(f));
+                    return Command::None;
+                }
+            };
             let auth = match f.auth_type {
                 AuthType::None => AuthConfig::None,
                 AuthType::SaslPlain => AuthConfig::SaslPlain {
@@ -150,21 +331,47 @@ fn handle_modal_confirm(state: &mut AppState) -> Command {
                     username: f.username,
                     password: f.password,
                 },
+                AuthType::Ssl => AuthConfig::Ssl {
+                    ca_location: non_empty(f.ca_location),
+                    cert_location: non_empty(f.cert_location),
+                    key_location: non_empty(f.key_location),
+                    key_password: non_empty(f.key_password),
+                },
+                AuthType::SaslSsl => AuthConfig::SaslSsl {
+                    mechanism: f.sasl_mechanism,
+                    username: f.username,
+                    password: f.password,
+                    ca_location: non_empty(f.ca_location),
+                },
+                AuthType::OAuthBearer => AuthConfig::OAuthBearer {
+                    token_endpoint: non_empty(f.token_endpoint),
+                    client_id: f.username,
+                    secret: f.password,
+                    scope: non_empty(f.scope),
+                    ca_location: non_empty(f.ca_location),
+                },
             };
-            let consumer_group = if f.consumer_group.is_empty() {
-                None
-            } else {
-                Some(f.consumer_group)
-            };
-            let profile = ConnectionProfile {
+            let consumer_group = non_empty(f.consumer_group);
+            let schema_registry_url = non_empty(f.schema_registry);
+            let mut profile = ConnectionProfile {
                 id: Uuid::new_v4(),
                 name: f.name,
                 brokers: f.brokers,
                 consumer_group,
+                schema_registry_url,
+                schema_registry_credentials: None,
+                protobuf_descriptor_set: None,
+                protobuf_types: Default::default(),
                 auth,
+                secret_storage: f.secret_storage,
+                producer: f.producer,
+                start_offset: f.start_offset,
+                socket_timeout_ms,
+                request_timeout_ms,
                 created_at: Utc::now(),
                 last_used: None,
             };
+            profile.split_registry_credentials();
             state.connection.status = ConnectionStatus::Connecting;
             state.connection.active_profile = Some(profile.clone());
             Command::ConnectToKafka(profile)
@@ -173,24 +380,52 @@ fn handle_modal_confirm(state: &mut AppState) -> Command {
             match (
                 parse_partitions(&f.partitions),
                 parse_replication_factor(&f.replication_factor),
+                validate_topic_configs(&f.configs),
             ) {
-                (Ok(partitions), Ok(replication_factor)) => Command::CreateKafkaTopic {
+                (Ok(partitions), Ok(replication_factor), Ok(())) => Command::CreateKafkaTopic {
                     name: f.name,
                     partitions,
                     replication_factor,
+                    configs: f.configs.into_iter().map(|(k, v)| (k.trim().to_string(), v)).collect(),
                 },
-                (Err(e), _) | (_, Err(e)) => {
+                (Err(e), _, _) | (_, Err(e), _) | (_, _, Err(e)) => {
                     toast(state, &e.to_string(), Level::Error);
                     state.ui_state.active_modal = Some(ModalType::TopicCreateForm(f));
                     Command::None
                 }
             }
         }
-        ModalType::ProduceForm(f) => Command::ProduceKafkaMessage {
-            topic: f.topic,
-            key: if f.key.is_empty() { None } else { Some(f.key) },
-            value: f.value,
-            headers: Default::default(),
+        ModalType::ProduceForm(f) => match validate_headers(&f.headers)
+            .and_then(|()| parse_produce_partition(&f.partition, state.topics_state.partition_count(&f.topic)))
+            .and_then(|_| parse_produce_count(&f.count))
+        {
+            // The send would fail, or silently create the topic where brokers auto-create
+            Ok(count) if state.topics_state.is_missing(&f.topic) => {
+                let what = if count == 1 { "the message".to_string() } else { format!("{} messages", count) };
+                state.ui_state.active_modal = Some(ModalType::Confirm {
+                    title: "Topic Not Found".into(),
+                    message: format!(
+                        "Topic '{}' doesn't exist. Produce {} anyway? Brokers that auto-create topics will create it; others reject the send.",
+                        f.topic, what
+                    ),
+                    action: ConfirmAction::ProduceToMissingTopic { form: f, count },
+                });
+                Command::None
+            }
+            Ok(count) if count > PRODUCE_CONFIRM_COUNT => {
+                state.ui_state.active_modal = Some(ModalType::Confirm {
+                    title: "Produce Messages".into(),
+                    message: format!("Produce {} copies to '{}'?", count, f.topic),
+                    action: ConfirmAction::ProduceBurst { form: f, count },
+                });
+                Command::None
+            }
+            Ok(count) => produce_command(f, count),
+            Err(e) => {
+                toast(state, &e.to_string(), Level::Error);
+                state.ui_state.active_modal = Some(ModalType::ProduceForm(f));
+                Command::None
+            }
         },
         ModalType::AddPartitionsForm(f) => {
             match parse_new_partition_count(&f.new_count, f.current_count) {
@@ -206,25 +441,25 @@ fn handle_modal_confirm(state: &mut AppState) -> Command {
             }
         }
         ModalType::AlterConfigForm(f) => {
-            let configs = f.modified_configs();
-            if configs.is_empty() {
+            if !f.has_changes() {
                 Command::None
             } else {
                 Command::AlterKafkaTopicConfig {
+                    configs: f.modified_configs(),
                     topic: f.topic,
-                    configs,
+                    deletes: f.deleted.into_iter().collect(),
                 }
             }
         }
         ModalType::PurgeTopicForm(f) => {
             if f.purge_all {
-                Command::PurgeKafkaTopic {
+                Command::EstimatePurge {
                     topic: f.topic,
                     before_offset: i64::MAX,
                 }
             } else {
                 match parse_offset(&f.offset) {
-                    Ok(offset) => Command::PurgeKafkaTopic {
+                    Ok(offset) => Command::EstimatePurge {
                         topic: f.topic,
                         before_offset: offset,
                     },
@@ -236,5 +471,111 @@ fn handle_modal_confirm(state: &mut AppState) -> Command {
                 }
             }
         }
+        ModalType::History(h) => match h.selected_entry() {
+            Some(e) if e.kind == HistoryKind::Produced => Command::ProduceKafkaMessage {
+                topic: e.topic.clone(),
+                key: e.key.clone(),
+                value: e.value.clone(),
+                headers: e.headers.clone(),
+                partition: None,
+                count: 1,
+            },
+            Some(_) => {
+                toast(state, "Only produced messages can be re-sent", Level::Warning);
+                state.ui_state.active_modal = Some(ModalType::History(h));
+                Command::None
+            }
+            None => Command::None,
+        },
+        ModalType::LogDetail(_) | ModalType::CommandPalette(_) => Command::None,
+        ModalType::ResetOffsetsForm(f) => match reset_offsets_command(&f) {
+            Ok(cmd) => cmd,
+            Err(e) => {
+                toast(state, &e.to_string(), Level::Error);
+                state.ui_state.active_modal = Some(ModalType::ResetOffsetsForm(f));
+                Command::None
+            }
+        },
+        ModalType::SeekForm(f) => match seek_target(&f) {
+            Ok((offset_mode, partition)) => {
+                // Seeking from the topic details opens the topic's messages
+                if !matches!(&state.active_screen, Screen::Messages { topic_name } if *topic_name == f.topic) {
+                    open_messages(state, &f.topic);
+                }
+                let ms = &mut state.messages_state;
+                ms.loading = true;
+                ms.offset_mode = offset_mode.clone();
+                ms.partition_filter = partition;
+                let entry = HistoryEntry::seek(&f.topic, partition, &offset_mode);
+                Command::Batch(vec![
+                    Command::FetchMessages { topic: f.topic, offset_mode, partition, limit: ms.fetch_limit },
+                    Command::RecordHistory(entry),
+                ])
+            }
+            Err(e) => {
+                toast(state, &e.to_string(), Level::Error);
+                state.ui_state.active_modal = Some(ModalType::SeekForm(f));
+                Command::None
+            }
+        },
+    }
+}
+
+fn produce_command(f: ProduceFormState, count: usize) -> Command {
+    if f.value_from_file {
+        return Command::ProduceFromFile { form: f, count };
+    }
+    Command::ProduceKafkaMessage {
+        partition: f.target_partition(),
+        topic: f.topic,
+        key: non_empty(f.key),
+        value: f.value,
+        headers: f.headers,
+        count,
     }
 }
+
+fn reset_offsets_command(f: &ResetOffsetsFormState) -> Result<Command, AppError> {
+    let topic = f.selected_topic().ok_or_else(|| AppError::Validation {
+        field: "topic".into(),
+        message: "No topic selected".into(),
+    })?;
+    Ok(Command::ResetConsumerGroupOffsets {
+        group_id: f.group_id.clone(),
+        topic: topic.to_string(),
+        partition: parse_optional_partition(&f.partition)?,
+        mode: offset_mode(f.mode, &f.value)?,
+    })
+}
+
+fn seek_target(f: &SeekFormState) -> Result<(OffsetMode, Option<i32>), AppError> {
+    Ok((offset_mode(f.mode, &f.value)?, parse_optional_partition(&f.partition)?))
+}
+
+/// Resolve a form's mode selector and value input into an `OffsetMode`.
+fn offset_mode(mode: ResetOffsetMode, value: &str) -> Result<OffsetMode, AppError> {
+    Ok(match mode {
+        ResetOffsetMode::Earliest => OffsetMode::Earliest,
+        ResetOffsetMode::Latest => OffsetMode::Latest,
+        ResetOffsetMode::Offset => OffsetMode::Specific(parse_offset(value)?),
+        ResetOffsetMode::Timestamp => OffsetMode::Timestamp(parse_timestamp(value)?),
+    })
+}
+
+/// Check the TLS file paths the selected auth type will hand to librdkafka.
+fn validate_tls_files(f: &ConnectionFormState) -> Result<(), AppError> {
+    match f.auth_type {
+        AuthType::Ssl => {
+            validate_readable_file("ca_location", &f.ca_location)?;
+            validate_readable_file("cert_location", &f.cert_location)?;
+            validate_readable_file("key_location", &f.key_location)
+        }
+        AuthType::SaslSsl | AuthType::OAuthBearer => validate_readable_file("ca_location", &f.ca_location),
+        _ => Ok(()),
+    }
+}
+
+/// `None` for an empty optional form input.
+fn non_empty(value: String) -> Option<String> {
+    (!value.is_empty()).then_some(value)
+}
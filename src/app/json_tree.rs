@@ -0,0 +1,121 @@
+//! Collapsible tree rows for JSON message values.
+//!
+//! Nodes are identified by their JSON pointer (`/user/roles/0`), so the set of expanded
+//! paths survives moving between messages that share a shape. Only expanded objects and
+//! arrays contribute their children, so a huge payload costs as many rows as are open.
+
+use std::collections::HashSet;
+
+use serde_json::Value;
+
+/// One visible line of the tree
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TreeRow {
+    pub depth: usize,
+    /// JSON pointer of the node, usable with `Value::pointer`
+    pub path: String,
+    /// Object key or `[index]`
+    pub label: String,
+    pub node: TreeNode,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TreeNode {
+    Object { len: usize, expanded: bool },
+    Array { len: usize, expanded: bool },
+    String(String),
+    /// Number, boolean or null, as JSON text
+    Literal(String),
+}
+
+impl TreeRow {
+    pub fn is_container(&self) -> bool {
+        matches!(self.node, TreeNode::Object { .. } | TreeNode::Array { .. })
+    }
+
+    pub fn is_expanded(&self) -> bool {
+        matches!(self.node, TreeNode::Object { expanded: true, .. } | TreeNode::Array { expanded: true, .. })
+    }
+
+    /// Pointer of the enclosing node; the root's children have the root's, `""`
+    pub fn parent_path(&self) -> &str {
+        self.path.rsplit_once('/').map_or("", |(parent, _)| parent)
+    }
+}
+
+/// The rows of `value` to show, with the root's children at depth 0.
+pub fn tree_rows(value: &Value, expanded: &HashSet<String>) -> Vec<TreeRow> {
+    let mut rows = Vec::new();
+    push_children(value, "", 0, expanded, &mut rows);
+    if rows.is_empty() && !matches!(value, Value::Object(_) | Value::Array(_)) {
+        // A bare scalar payload is a single row
+        rows.push(row(0, String::new(), String::new(), value, expanded));
+    }
+    rows
+}
+
+fn push_children(value: &Value, path: &str, depth: usize, expanded: &HashSet<String>, rows: &mut Vec<TreeRow>) {
+    let children: Vec<(String, &Value)> = match value {
+        Value::Object(map) => map.iter().map(|(k, v)| (k.clone(), v)).collect(),
+        Value::Array(items) => items.iter().enumerate().map(|(i, v)| (format!("[{}]", i), v)).collect(),
+        _ => return,
+    };
+    for (i, (label, child)) in children.into_iter().enumerate() {
+        let segment = match value {
+            Value::Array(_) => i.to_string(),
+            _ => label.replace('~', "~0").replace('/', "~1"),
+        };
+        let child_path = format!("{}/{}", path, segment);
+        let row = row(depth, child_path.clone(), label, child, expanded);
+        let open = row.is_expanded();
+        rows.push(row);
+        if open {
+            push_children(child, &child_path, depth + 1, expanded, rows);
+        }
+    }
+}
+
+fn row(depth: usize, path: String, label: String, value: &Value, expanded: &HashSet<String>) -> TreeRow {
+    let open = expanded.contains(&path);
+    let node = match value {
+        Value::Object(map) => TreeNode::Object { len: map.len(), expanded: open },
+        Value::Array(items) => TreeNode::Array { len: items.len(), expanded: open },
+        Value::String(s) => TreeNode::String(s.clone()),
+        other => TreeNode::Literal(other.to_string()),
+    };
+    TreeRow { depth, path, label, node }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tree_rows_follow_expanded_paths() {
+        let value: Value = serde_json::from_str(r#"{"id": 7, "user": {"name": "ada", "roles": ["admin", "ops"]}, "a/b": null}"#).unwrap();
+
+        let rows = tree_rows(&value, &HashSet::new());
+        let labels: Vec<&str> = rows.iter().map(|r| r.label.as_str()).collect();
+        assert_eq!(labels, ["a/b", "id", "user"]);
+        assert_eq!(rows[0].path, "/a~1b");
+        assert_eq!(rows[2].node, TreeNode::Object { len: 2, expanded: false });
+
+        let expanded: HashSet<String> = ["/user".to_string(), "/user/roles".to_string()].into();
+        let rows = tree_rows(&value, &expanded);
+        let shown: Vec<(usize, &str)> = rows.iter().map(|r| (r.depth, r.path.as_str())).collect();
+        assert_eq!(
+            shown,
+            [(0, "/a~1b"), (0, "/id"), (0, "/user"), (1, "/user/name"), (1, "/user/roles"), (2, "/user/roles/0"), (2, "/user/roles/1")],
+        );
+        assert_eq!(rows[5].label, "[0]");
+        assert_eq!(rows[5].parent_path(), "/user/roles");
+        assert_eq!(value.pointer(&rows[5].path), Some(&Value::String("admin".into())));
+        assert_eq!(value.pointer(&rows[0].path), Some(&Value::Null));
+
+        // Expanded paths under a collapsed parent stay hidden
+        let rows = tree_rows(&value, &["/user/roles".to_string()].into());
+        assert_eq!(rows.len(), 3);
+
+        assert_eq!(tree_rows(&Value::from(42), &HashSet::new())[0].node, TreeNode::Literal("42".into()));
+    }
+}
@@ -1,5 +1,7 @@
 pub mod actions;
+pub mod fuzzy;
 pub mod handlers;
+pub mod json_tree;
 pub mod runner;
 pub mod state;
 pub mod update;
@@ -1,18 +1,27 @@
 use std::io;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
 
 use crossterm::event;
 use ratatui::prelude::*;
 use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
 
 use crate::app::actions::{Action, Command};
-use crate::app::state::{AppState, Level};
-use crate::app::update::update;
+use crate::app::state::{AppState, BrokerDetail, ConnectionProfile, HistoryEntry, Level, NumberFormat, SecretStorage};
+use crate::app::update::{toast, update};
+use crate::config::AppConfig;
+use crate::error::{AppError, AppResult};
 use crate::events::handler::EventHandler;
+use crate::events::keymap::KeyMap;
 use crate::kafka::config::KafkaConfig;
+use crate::kafka::protobuf::ProtoDescriptors;
+use crate::kafka::scan::ScanProgress;
 use crate::kafka::KafkaClient;
-use crate::storage::connections;
+use crate::storage::{bookmarks, connections, export, history, recent_commands, secrets, sessions};
+use crate::ui::clipboard::Clipboard;
 use crate::ui::render::render_app;
 
 pub struct App {
@@ -20,8 +29,25 @@ pub struct App {
     tx: mpsc::UnboundedSender<Action>,
     rx: mpsc::UnboundedReceiver<Action>,
     client: Option<Arc<KafkaClient>>,
+    consumer_cancel: Option<Arc<AtomicBool>>,
+    scan_cancel: Option<Arc<AtomicBool>>,
+    clipboard: Clipboard,
+    /// In-flight connection attempt; its client is adopted once it finishes
+    connect_task: Option<JoinHandle<Option<Arc<KafkaClient>>>>,
+    /// In-flight replacement of a client that lost its connection
+    reconnect_task: Option<JoinHandle<Option<Arc<KafkaClient>>>>,
+    /// Requests to run again once reconnected, with the failure to report otherwise
+    pending_retries: Vec<(Command, Action)>,
+    connect_attempts: u32,
+    legacy_alter_configs: bool,
 }
 
+/// Connection attempts when `connect_attempts` is not configured
+const DEFAULT_CONNECT_ATTEMPTS: u32 = 3;
+
+/// Wait before the first retry; doubles for each later one
+const CONNECT_BACKOFF: Duration = Duration::from_millis(500);
+
 /// Helper function to send an action and log if the channel is closed.
 fn send_action(tx: &mpsc::UnboundedSender<Action>, action: Action) {
     if tx.send(action).is_err() {
@@ -29,10 +55,144 @@ fn send_action(tx: &mpsc::UnboundedSender<Action>, action: Action) {
     }
 }
 
+/// Largest file accepted as a message payload (the broker default `message.max.bytes` is ~1 MiB)
+const MAX_PAYLOAD_FILE_BYTES: u64 = 1024 * 1024;
+
+fn read_payload_file(path: &Path) -> AppResult<String> {
+    let size = std::fs::metadata(path)?.len();
+    if size > MAX_PAYLOAD_FILE_BYTES {
+        return Err(AppError::InvalidInput(format!(
+            "{} is {} bytes; the limit is {}",
+            path.display(),
+            size,
+            MAX_PAYLOAD_FILE_BYTES
+        )));
+    }
+    Ok(std::fs::read_to_string(path)?)
+}
+
+/// Create a client and check it can reach the cluster, retrying transient failures with
+/// exponential backoff. Failures are reported on `tx`; the client is returned on success.
+async fn connect_with_retry(
+    profile: ConnectionProfile,
+    attempts: u32,
+    tx: mpsc::UnboundedSender<Action>,
+) -> Option<Arc<KafkaClient>> {
+    let config = match KafkaConfig::for_profile(profile).await {
+        Ok(config) => config,
+        Err(e) => {
+            send_action(&tx, Action::ConnectionFailed(e.to_string()));
+            return None;
+        }
+    };
+    let mut attempt = 1;
+    loop {
+        let result = match KafkaClient::new(config.clone()).await {
+            Ok(c) => c.test_connection().await.map(|_| c),
+            Err(e) => Err(e),
+        };
+        match result {
+            Ok(c) => return Some(c),
+            Err(e @ AppError::Connection(_)) if attempt < attempts => {
+                attempt += 1;
+                send_action(&tx, Action::ConnectionRetrying { attempt, max: attempts, error: e.to_string() });
+                tokio::time::sleep(CONNECT_BACKOFF * 2u32.pow(attempt - 2)).await;
+            }
+            Err(e) => {
+                send_action(&tx, Action::ConnectionFailed(e.to_string()));
+                return None;
+            }
+        }
+    }
+}
+
+/// Report a failed request, or, when the brokers stopped answering, ask for a reconnect
+/// that runs `retry` again and only reports the failure if it doesn't help.
+fn request_failed(tx: &mpsc::UnboundedSender<Action>, e: AppError, retry: Command, failed: fn(String) -> Action) {
+    let failed = failed(e.to_string());
+    if e.is_connection_lost() {
+        send_action(tx, Action::ConnectionLost { retry: Box::new(retry), failed: Box::new(failed) });
+    } else {
+        send_action(tx, failed);
+    }
+}
+
+/// How long shutdown waits for queued produce requests (the producer's `message.timeout.ms`)
+const SHUTDOWN_FLUSH_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Flush the client's producer before it is dropped, which would discard queued messages.
+async fn flush_producer(client: Arc<KafkaClient>) {
+    match client.flush(SHUTDOWN_FLUSH_TIMEOUT).await {
+        Ok((0, 0)) => {}
+        Ok((flushed, 0)) => tracing::info!(flushed, "Flushed pending messages"),
+        Ok((flushed, dropped)) => tracing::warn!(flushed, dropped, "Pending messages dropped on shutdown"),
+        Err(e) => tracing::warn!(error = %e, "Producer flush failed"),
+    }
+}
+
+/// History is a convenience; a failed write is logged rather than surfaced.
+fn record_history(entry: HistoryEntry) {
+    if let Err(e) = history::record(entry) {
+        tracing::warn!(error = %e, "Failed to record history");
+    }
+}
+
 impl App {
-    pub fn new() -> Self {
+    pub fn new(config: &AppConfig) -> Self {
         let (tx, rx) = mpsc::unbounded_channel();
-        Self { state: AppState::default(), tx, rx, client: None }
+        let mut app = Self {
+            state: AppState::default(),
+            tx,
+            rx,
+            client: None,
+            consumer_cancel: None,
+            scan_cancel: None,
+            clipboard: Clipboard::default(),
+            connect_task: None,
+            reconnect_task: None,
+            pending_retries: Vec::new(),
+            connect_attempts: config.connect_attempts.unwrap_or(DEFAULT_CONNECT_ATTEMPTS).max(1),
+            legacy_alter_configs: config.legacy_alter_configs,
+        };
+
+        app.state.ui_state.remember_session = config.remember_session;
+        if config.exact_numbers {
+            app.state.ui_state.number_format = NumberFormat::Exact;
+        }
+        match recent_commands::load_recent_commands() {
+            Ok(labels) => app.state.ui_state.recent_commands = labels,
+            Err(e) => tracing::warn!(error = %e, "Failed to load recent commands"),
+        }
+        if let Some(max) = config.max_log_entries {
+            app.state.logs_state.set_max_entries(max);
+        }
+
+        let (keymap, warnings) = KeyMap::from_config(&config.keymap);
+        app.state.ui_state.keymap = keymap;
+        for warning in warnings {
+            app.startup_warning(&format!("Keymap: {}", warning));
+        }
+        app
+    }
+
+    /// Surface a problem found while starting up as a warning toast.
+    pub fn startup_warning(&mut self, message: &str) {
+        toast(&mut self.state, message, Level::Warning);
+    }
+
+    /// Connect to the saved profile called `name` as soon as profiles are loaded.
+    pub fn connect_profile_on_start(&mut self, name: &str) {
+        self.state.connection.pending_profile = Some(name.to_string());
+    }
+
+    /// Connect straight to `brokers` with a throwaway profile that is not saved.
+    pub fn connect_brokers_on_start(&mut self, brokers: &str) {
+        self.state.connection.ephemeral = true;
+        self.send(Action::Connect(ConnectionProfile {
+            name: brokers.to_string(),
+            brokers: brokers.to_string(),
+            ..Default::default()
+        }));
     }
 
     /// Send an action to the channel, logging if the send fails.
@@ -42,6 +202,8 @@ impl App {
 
     pub async fn run<B: Backend>(&mut self, terminal: &mut Terminal<B>) -> io::Result<()> {
         self.state.running = true;
+        let size = terminal.size()?;
+        self.state.ui_state.terminal_size = (size.width, size.height);
         self.exec(Command::LoadConnectionProfiles).await;
 
         while self.state.running {
@@ -56,14 +218,56 @@ impl App {
             };
             self.exec(cmd).await;
 
+            self.adopt_connected_client().await;
+            self.adopt_reconnected_client().await;
+
             while let Ok(action) = self.rx.try_recv() {
                 let cmd = update(&mut self.state, action);
                 self.exec(cmd).await;
             }
         }
+
+        // Deliver whatever is still queued before the producer is torn down
+        self.exec(Command::DisconnectFromKafka).await;
         Ok(())
     }
 
+    /// Take over the client from a finished connection attempt and report success.
+    async fn adopt_connected_client(&mut self) {
+        let Some(task) = self.connect_task.take_if(|t| t.is_finished()) else {
+            return;
+        };
+        if let Ok(Some(client)) = task.await {
+            self.client = Some(client);
+            self.send(Action::ConnectionSuccess);
+        }
+    }
+
+    /// Swap in the client from a finished reconnect and run the requests that were waiting
+    /// on it, or report their failures if it couldn't connect.
+    async fn adopt_reconnected_client(&mut self) {
+        let Some(task) = self.reconnect_task.take_if(|t| t.is_finished()) else {
+            return;
+        };
+        let pending = std::mem::take(&mut self.pending_retries);
+        match task.await {
+            Ok(Some(client)) => {
+                if let Some(old) = self.client.replace(client) {
+                    tokio::spawn(flush_producer(old));
+                }
+                self.send(Action::Reconnected);
+                for (retry, _) in pending {
+                    Box::pin(self.exec(retry)).await;
+                }
+            }
+            _ => {
+                for (_, failed) in pending {
+                    self.send(failed);
+                }
+            }
+        }
+    }
+
     async fn exec(&mut self, cmd: Command) {
         match cmd {
             Command::None => {}
@@ -74,28 +278,71 @@ impl App {
             }
 
             Command::ConnectToKafka(profile) => {
-                let config = KafkaConfig::from(profile);
-                match KafkaClient::new(config).await {
-                    Ok(c) => match c.test_connection().await {
-                        Ok(_) => {
-                            self.client = Some(c);
-                            self.send(Action::ConnectionSuccess);
-                        }
-                        Err(e) => { self.send(Action::ConnectionFailed(e.to_string())); }
-                    },
-                    Err(e) => { self.send(Action::ConnectionFailed(e.to_string())); }
+                self.abort_reconnect();
+                if let Some(task) = self.connect_task.take() {
+                    task.abort();
+                }
+                self.connect_task = Some(tokio::spawn(connect_with_retry(profile, self.connect_attempts, self.tx.clone())));
+            }
+
+            Command::Reconnect { profile, retry, failed } => {
+                self.pending_retries.push((*retry, *failed));
+                if self.reconnect_task.is_none() {
+                    let tx = self.tx.clone();
+                    self.reconnect_task = Some(tokio::spawn(async move {
+                        let result = match KafkaConfig::for_profile(profile).await {
+                            Ok(config) => KafkaClient::new(config).await,
+                            Err(e) => Err(e),
+                        };
+                        let result = match result {
+                            Ok(c) => c.test_connection().await.map(|_| c),
+                            Err(e) => Err(e),
+                        };
+                        result.inspect_err(|e| send_action(&tx, Action::ReconnectFailed(e.to_string()))).ok()
+                    }));
                 }
             }
 
             Command::DisconnectFromKafka => {
-                self.client = None;
+                self.abort_reconnect();
+                if let Some(task) = self.connect_task.take() {
+                    task.abort();
+                }
+                self.stop_consumer();
+                self.stop_scan();
+                if let Some(client) = self.client.take() {
+                    flush_producer(client).await;
+                }
+            }
+
+            Command::PingCluster => {
+                self.spawn_kafka(|c, tx| async move {
+                    match c.ping().await {
+                        Ok(latency) => send_action(&tx, Action::ClusterPinged(Some(latency))),
+                        Err(e) => {
+                            tracing::warn!(error = %e, "Health ping failed");
+                            request_failed(&tx, e, Command::PingCluster, |_| Action::ClusterPinged(None));
+                        }
+                    }
+                });
             }
 
             Command::FetchTopicList => {
                 self.spawn_kafka(|c, tx| async move {
                     match c.list_topics().await {
                         Ok(t) => send_action(&tx, Action::TopicsFetched(t)),
-                        Err(e) => send_action(&tx, Action::TopicsFetchFailed(e.to_string())),
+                        Err(e) => request_failed(&tx, e, Command::FetchTopicList, Action::TopicsFetchFailed),
+                    }
+                });
+            }
+
+            Command::FetchTopicMessageCounts(names) => {
+                self.spawn_kafka(move |c, tx| async move {
+                    match c.topic_message_counts(names.clone()).await {
+                        Ok(counts) => send_action(&tx, Action::TopicMessageCountsFetched(counts)),
+                        Err(e) => request_failed(
+                            &tx, e, Command::FetchTopicMessageCounts(names), Action::TopicMessageCountsFetchFailed,
+                        ),
                     }
                 });
             }
@@ -104,14 +351,25 @@ impl App {
                 self.spawn_kafka(move |c, tx| async move {
                     match c.get_topic_details(&name).await {
                         Ok(d) => send_action(&tx, Action::TopicDetailsFetched(d)),
-                        Err(e) => send_action(&tx, Action::TopicDetailsFetchFailed(e.to_string())),
+                        Err(e) => request_failed(&tx, e, Command::FetchTopicDetails(name), Action::TopicDetailsFetchFailed),
+                    }
+                });
+            }
+
+            Command::FetchTopicWatermarks(topic) => {
+                self.spawn_kafka(move |c, tx| async move {
+                    match c.get_topic_watermarks(&topic).await {
+                        Ok(watermarks) => send_action(&tx, Action::TopicWatermarksFetched { topic, watermarks }),
+                        Err(e) => request_failed(
+                            &tx, e, Command::FetchTopicWatermarks(topic), Action::TopicWatermarksFetchFailed,
+                        ),
                     }
                 });
             }
 
-            Command::CreateKafkaTopic { name, partitions, replication_factor } => {
+            Command::CreateKafkaTopic { name, partitions, replication_factor, configs } => {
                 self.spawn_kafka(move |c, tx| async move {
-                    match c.create_topic(&name, partitions, replication_factor).await {
+                    match c.create_topic(&name, partitions, replication_factor, &configs).await {
                         Ok(_) => send_action(&tx, Action::TopicCreated { name, partitions, replication_factor }),
                         Err(e) => send_action(&tx, Action::TopicCreateFailed(e.to_string())),
                     }
@@ -127,31 +385,100 @@ impl App {
                 });
             }
 
+            Command::DeleteKafkaTopics(names) => {
+                self.spawn_kafka(move |c, tx| async move {
+                    match c.delete_topics(&names).await {
+                        Ok(failed) => {
+                            let deleted = names.into_iter().filter(|n| !failed.iter().any(|(f, _)| f == n)).collect();
+                            send_action(&tx, Action::TopicsDeleted { deleted, failed })
+                        }
+                        Err(e) => send_action(&tx, Action::TopicDeleteFailed(e.to_string())),
+                    }
+                });
+            }
+
             Command::FetchMessages { topic, offset_mode, partition, limit } => {
+                // A fetch replaces whatever a running scan would still add
+                self.stop_scan();
                 self.spawn_kafka(move |c, tx| async move {
-                    match c.fetch_messages(&topic, offset_mode, partition, limit).await {
-                        Ok(m) => send_action(&tx, Action::MessagesFetched(m)),
-                        Err(e) => send_action(&tx, Action::MessagesFetchFailed(e.to_string())),
+                    match c.fetch_messages(&topic, offset_mode.clone(), partition, limit).await {
+                        Ok(f) => send_action(&tx, Action::MessagesFetched { messages: f.messages, timed_out: f.timed_out }),
+                        Err(e) => {
+                            let retry = Command::FetchMessages { topic, offset_mode, partition, limit };
+                            request_failed(&tx, e, retry, Action::MessagesFetchFailed)
+                        }
                     }
                 });
             }
 
-            Command::StartMessageConsumer { .. } | Command::StopMessageConsumer => {}
+            Command::StartMessageConsumer { topic, offset_mode, partition } => {
+                self.stop_consumer();
+                let cancel = Arc::new(AtomicBool::new(false));
+                self.consumer_cancel = Some(cancel.clone());
+                self.spawn_kafka(move |c, tx| async move {
+                    let msg_tx = tx.clone();
+                    let on_message = move |m| send_action(&msg_tx, Action::MessageReceived(m));
+                    if let Err(e) = c.stream_messages(&topic, offset_mode, partition, cancel, on_message).await {
+                        send_action(&tx, Action::ConsumerFailed(e.to_string()));
+                    }
+                });
+            }
 
-            Command::ProduceKafkaMessage { topic, key, value, headers } => {
+            Command::StopMessageConsumer => self.stop_consumer(),
+
+            Command::ScanMessages { topic, offset_mode, partition, pattern } => {
+                self.stop_scan();
+                let cancel = Arc::new(AtomicBool::new(false));
+                self.scan_cancel = Some(cancel.clone());
                 self.spawn_kafka(move |c, tx| async move {
-                    match c.produce_message(&topic, key.as_deref(), &value, &headers).await {
-                        Ok(_) => send_action(&tx, Action::MessageProduced),
+                    let progress_tx = tx.clone();
+                    let on_progress = move |p: ScanProgress| {
+                        send_action(&progress_tx, Action::ScanProgress { scanned: p.scanned, matches: p.matches })
+                    };
+                    match c.scan_messages(&topic, offset_mode, partition, pattern, cancel, on_progress).await {
+                        Ok((scanned, stop)) => send_action(&tx, Action::ScanFinished { scanned, stop }),
+                        Err(e) => send_action(&tx, Action::ScanFailed(e.to_string())),
+                    }
+                });
+            }
+
+            Command::CancelScan => self.stop_scan(),
+
+            Command::ProduceKafkaMessage { topic, key, value, headers, partition, count } => {
+                self.spawn_kafka(move |c, tx| async move {
+                    match c.produce_messages(&topic, partition, key.as_deref(), &value, &headers, count).await {
+                        Ok((count, last)) => {
+                            record_history(HistoryEntry::produced(&topic, key.as_deref(), &value, &headers));
+                            send_action(&tx, Action::MessageProduced { count, last });
+                        }
                         Err(e) => send_action(&tx, Action::MessageProduceFailed(e.to_string())),
                     }
                 });
             }
 
+            Command::ProduceFromFile { form, count } => {
+                match read_payload_file(&connections::expand_home(&form.value)) {
+                    Ok(value) => {
+                        let partition = form.target_partition();
+                        Box::pin(self.exec(Command::ProduceKafkaMessage {
+                            topic: form.topic,
+                            key: (!form.key.is_empty()).then_some(form.key),
+                            value,
+                            headers: form.headers,
+                            partition,
+                            count,
+                        }))
+                        .await
+                    }
+                    Err(e) => self.send(Action::ProduceFileReadFailed { form, error: e.to_string() }),
+                }
+            }
+
             Command::FetchConsumerGroupList => {
                 self.spawn_kafka(|c, tx| async move {
                     match c.list_consumer_groups().await {
                         Ok(g) => send_action(&tx, Action::ConsumerGroupsFetched(g)),
-                        Err(e) => send_action(&tx, Action::ConsumerGroupsFetchFailed(e.to_string())),
+                        Err(e) => request_failed(&tx, e, Command::FetchConsumerGroupList, Action::ConsumerGroupsFetchFailed),
                     }
                 });
             }
@@ -160,7 +487,18 @@ impl App {
                 self.spawn_kafka(move |c, tx| async move {
                     match c.get_consumer_group_details(&group_id).await {
                         Ok(d) => send_action(&tx, Action::ConsumerGroupDetailsFetched(d)),
-                        Err(e) => send_action(&tx, Action::ConsumerGroupDetailsFetchFailed(e.to_string())),
+                        Err(e) => request_failed(
+                            &tx, e, Command::FetchConsumerGroupDetails(group_id), Action::ConsumerGroupDetailsFetchFailed,
+                        ),
+                    }
+                });
+            }
+
+            Command::ResetConsumerGroupOffsets { group_id, topic, partition, mode } => {
+                self.spawn_kafka(move |c, tx| async move {
+                    match c.reset_group_offsets(&group_id, &topic, partition, mode).await {
+                        Ok(_) => send_action(&tx, Action::ConsumerGroupOffsetsReset(group_id)),
+                        Err(e) => send_action(&tx, Action::ConsumerGroupOffsetsResetFailed(e.to_string())),
                     }
                 });
             }
@@ -169,11 +507,62 @@ impl App {
                 self.spawn_kafka(|c, tx| async move {
                     match c.list_brokers().await {
                         Ok((brokers, cluster_id)) => send_action(&tx, Action::BrokersFetched { brokers, cluster_id }),
-                        Err(e) => send_action(&tx, Action::BrokersFetchFailed(e.to_string())),
+                        Err(e) => request_failed(&tx, e, Command::FetchBrokerList, Action::BrokersFetchFailed),
                     }
                 });
             }
 
+            Command::FetchBrokerDetails(id) => {
+                self.spawn_kafka(move |c, tx| async move {
+                    match c.get_broker_config(id).await {
+                        Ok(config) => send_action(&tx, Action::BrokerDetailsFetched(BrokerDetail { id, config })),
+                        Err(e) => request_failed(&tx, e, Command::FetchBrokerDetails(id), Action::BrokerDetailsFetchFailed),
+                    }
+                });
+            }
+
+            Command::SampleThroughput => {
+                self.spawn_kafka(|c, tx| async move {
+                    match c.topic_high_watermarks().await {
+                        // Stamped once every watermark is in, so slow fetches don't skew the rate
+                        Ok(high_watermarks) => send_action(
+                            &tx,
+                            Action::ThroughputSampled { at: std::time::Instant::now(), high_watermarks },
+                        ),
+                        Err(e) => send_action(&tx, Action::ThroughputSampleFailed(e.to_string())),
+                    }
+                });
+            }
+
+            Command::ExportMessages { path, format } => {
+                match export::export_messages(&path, format, &self.state.messages_state.messages) {
+                    Ok(_) => self.send(Action::MessagesExported(path)),
+                    Err(e) => self.send(Action::MessagesExportFailed(e.to_string())),
+                }
+            }
+
+            Command::ExportLogs(path) => {
+                let entries: Vec<_> = self.state.logs_state.entries.iter().rev().cloned().collect();
+                match export::export_logs(&path, &entries) {
+                    Ok(_) => self.send(Action::ShowToast {
+                        message: format!("Logs exported to {}", path.display()),
+                        level: Level::Success,
+                    }),
+                    Err(e) => self.send(Action::ShowToast {
+                        message: format!("Log export failed: {}", e),
+                        level: Level::Error,
+                    }),
+                }
+            }
+
+            Command::CopyToClipboard(text) => match self.clipboard.copy(&text) {
+                Ok(()) => self.send(Action::ShowToast {
+                    message: format!("Copied {} bytes to clipboard", text.len()),
+                    level: Level::Success,
+                }),
+                Err(e) => self.send(Action::ShowToast { message: e.to_string(), level: Level::Error }),
+            },
+
             Command::LoadConnectionProfiles => {
                 match connections::load_connections() {
                     Ok(p) => self.send(Action::ConnectionsLoaded(p)),
@@ -184,7 +573,15 @@ impl App {
                 }
             }
 
-            Command::SaveConnectionProfile(p) => {
+            Command::SaveConnectionProfile(mut p) => {
+                p.split_registry_credentials();
+                if let Err(e) = secrets::stash_profile_secret(&mut p) {
+                    p.secret_storage = SecretStorage::Plaintext;
+                    self.send(Action::ShowToast {
+                        message: format!("{} - saving password in plaintext", e),
+                        level: Level::Warning,
+                    });
+                }
                 if let Err(e) = connections::save_connection(&p) {
                     self.send(Action::ShowToast { message: e.to_string(), level: Level::Error });
                 }
@@ -197,6 +594,88 @@ impl App {
                 }
             }
 
+            Command::ExportConnectionProfiles { path, include_secrets } => {
+                let (message, level) = match connections::export_all(&path, include_secrets) {
+                    Ok(n) => (format!("Exported {} connections to {}", n, path.display()), Level::Success),
+                    Err(e) => (format!("Export failed: {}", e), Level::Error),
+                };
+                self.send(Action::ShowToast { message, level });
+            }
+
+            Command::ImportConnectionProfiles { path, overwrite } => {
+                match connections::import(&path, overwrite) {
+                    Ok(s) => {
+                        self.send(Action::ShowToast {
+                            message: format!("Imported {} new, {} updated, {} skipped", s.added, s.updated, s.skipped),
+                            level: Level::Success,
+                        });
+                        self.send(Action::LoadSavedConnections);
+                    }
+                    Err(e) => self.send(Action::ShowToast { message: format!("Import failed: {}", e), level: Level::Error }),
+                }
+            }
+
+            Command::LoadBookmarks(profile_id) => {
+                match bookmarks::load_bookmarks(profile_id) {
+                    Ok(b) => self.send(Action::BookmarksLoaded(b)),
+                    Err(e) => self.send(Action::ShowToast { message: e.to_string(), level: Level::Error }),
+                }
+            }
+
+            Command::SaveBookmarks { profile_id, bookmarks } => {
+                if let Err(e) = bookmarks::save_bookmarks(profile_id, &bookmarks) {
+                    self.send(Action::ShowToast { message: e.to_string(), level: Level::Error });
+                }
+            }
+
+            Command::SaveRecentCommands(labels) => {
+                if let Err(e) = recent_commands::save_recent_commands(&labels) {
+                    tracing::warn!(error = %e, "Failed to save recent commands");
+                }
+            }
+
+            Command::LoadSession(profile_id) => match sessions::load_session(profile_id) {
+                Ok(Some(session)) => self.send(Action::SessionLoaded(session)),
+                Ok(None) => {}
+                // A broken sessions file only costs the restore, not the connection
+                Err(e) => tracing::warn!(error = %e, "Failed to load session"),
+            },
+
+            Command::SaveSession { profile_id, session } => {
+                if let Err(e) = sessions::save_session(profile_id, &session) {
+                    tracing::warn!(error = %e, "Failed to save session");
+                }
+            }
+
+            Command::LoadProtobufDescriptors(path) => {
+                let tx = self.tx.clone();
+                // Large descriptor sets take a moment to read and link
+                tokio::task::spawn_blocking(move || {
+                    let action = match ProtoDescriptors::load(&path) {
+                        Ok(d) => Action::ProtobufDescriptorsLoaded(Arc::new(d)),
+                        Err(e) => Action::ProtobufDescriptorsLoadFailed(e.to_string()),
+                    };
+                    send_action(&tx, action);
+                });
+            }
+
+            Command::LoadHistory => {
+                match history::load_history() {
+                    Ok(entries) => self.send(Action::HistoryLoaded(entries)),
+                    Err(e) => self.send(Action::ShowToast { message: e.to_string(), level: Level::Error }),
+                }
+            }
+
+            Command::RecordHistory(entry) => record_history(entry),
+
+            Command::ClearHistory => {
+                let (message, level) = match history::clear_history() {
+                    Ok(_) => ("History cleared".to_string(), Level::Success),
+                    Err(e) => (e.to_string(), Level::Error),
+                };
+                self.send(Action::ShowToast { message, level });
+            }
+
             Command::AddTopicPartitions { topic, new_count } => {
                 self.spawn_kafka(move |c, tx| async move {
                     match c.add_partitions(&topic, new_count).await {
@@ -206,15 +685,34 @@ impl App {
                 });
             }
 
-            Command::AlterKafkaTopicConfig { topic, configs } => {
+            Command::AlterKafkaTopicConfig { topic, configs, deletes } => {
+                let legacy = self.legacy_alter_configs;
                 self.spawn_kafka(move |c, tx| async move {
-                    match c.alter_topic_config(&topic, &configs).await {
+                    let result = if !legacy {
+                        c.alter_topic_config(&topic, &configs, &deletes).await
+                    } else if deletes.is_empty() {
+                        c.alter_topic_config_legacy(&topic, &configs).await
+                    } else {
+                        Err(AppError::Config(
+                            "resetting to the default needs IncrementalAlterConfigs; unset legacy_alter_configs".into(),
+                        ))
+                    };
+                    match result {
                         Ok(_) => send_action(&tx, Action::TopicConfigAltered(topic)),
                         Err(e) => send_action(&tx, Action::TopicConfigAlterFailed(e.to_string())),
                     }
                 });
             }
 
+            Command::EstimatePurge { topic, before_offset } => {
+                self.spawn_kafka(move |c, tx| async move {
+                    match c.purge_estimate(&topic, before_offset).await {
+                        Ok(count) => send_action(&tx, Action::PurgeEstimated { topic, before_offset, count }),
+                        Err(e) => send_action(&tx, Action::TopicPurgeFailed(e.to_string())),
+                    }
+                });
+            }
+
             Command::PurgeKafkaTopic { topic, before_offset } => {
                 self.spawn_kafka(move |c, tx| async move {
                     match c.delete_records(&topic, before_offset).await {
@@ -226,6 +724,28 @@ impl App {
         }
     }
 
+    /// Signal the live message consumer, if any, to stop polling.
+    fn stop_consumer(&mut self) {
+        if let Some(cancel) = self.consumer_cancel.take() {
+            cancel.store(true, Ordering::Relaxed);
+        }
+    }
+
+    /// Drop an in-flight reconnect along with the requests waiting on it.
+    fn abort_reconnect(&mut self) {
+        if let Some(task) = self.reconnect_task.take() {
+            task.abort();
+        }
+        self.pending_retries.clear();
+    }
+
+    /// Signal the running topic scan, if any, to stop.
+    fn stop_scan(&mut self) {
+        if let Some(cancel) = self.scan_cancel.take() {
+            cancel.store(true, Ordering::Relaxed);
+        }
+    }
+
     fn spawn_kafka<F, Fut>(&self, f: F)
     where
         F: FnOnce(Arc<KafkaClient>, mpsc::UnboundedSender<Action>) -> Fut + Send + 'static,
@@ -248,5 +768,5 @@ impl App {
 }
 
 impl Default for App {
-    fn default() -> Self { Self::new() }
+    fn default() -> Self { Self::new(&AppConfig::default()) }
 }
@@ -48,6 +48,11 @@ pub fn update(state: &mut AppState, action: Action) -> Command {
         return cmd;
     }
 
+    // Throughput actions
+    if let Some(cmd) = handlers::throughput::handle(state, &action) {
+        return cmd;
+    }
+
     // Log actions
     if let Some(cmd) = handlers::logs::handle(state, &action) {
         return cmd;
@@ -68,3 +73,321 @@ pub fn update(state: &mut AppState, action: Action) -> Command {
 pub fn toast(state: &mut AppState, msg: &str, level: crate::app::state::Level) {
     handlers::ui::toast(state, msg, level);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::state::{
+        ConfirmAction, ConnectionFormState, ConnectionProfile, ConnectionStatus, ConsumerGroupInfo, KafkaMessage, Level,
+        ModalType, OffsetMode, PartitionInfo, ProduceFormState, Screen, TopicDetail, TopicInfo,
+    };
+
+    fn topic(name: &str) -> TopicInfo {
+        TopicInfo {
+            name: name.into(),
+            partition_count: 3,
+            replication_factor: 1,
+            message_count: None,
+            is_internal: false,
+            error: None,
+            has_under_replicated: false,
+        }
+    }
+
+    fn connected() -> AppState {
+        let mut state = AppState::default();
+        state.connection.status = ConnectionStatus::Connected;
+        state.connection.active_profile = Some(ConnectionProfile {
+            name: "local".into(),
+            brokers: "localhost:9092".into(),
+            ..Default::default()
+        });
+        state
+    }
+
+    #[test]
+    fn test_topic_fetch_round_trip() {
+        let mut state = connected();
+        assert!(matches!(update(&mut state, Action::FetchTopics), Command::FetchTopicList));
+        assert!(state.topics_state.loading);
+
+        let cmd = update(&mut state, Action::TopicsFetched(vec![topic("payments"), topic("orders")]));
+        assert!(matches!(cmd, Command::None));
+        assert!(!state.topics_state.loading);
+        let names: Vec<&str> = state.topics_state.topics.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, ["orders", "payments"]);
+
+        update(&mut state, Action::FetchTopics);
+        update(&mut state, Action::TopicsFetchFailed("boom".into()));
+        assert!(!state.topics_state.loading);
+        assert_eq!(state.ui_state.toast_messages.last().map(|t| t.level), Some(Level::Error));
+    }
+
+    #[test]
+    fn test_bulk_topic_delete() {
+        let mut state = connected();
+        let internal = TopicInfo { is_internal: true, ..topic("__consumer_offsets") };
+        update(&mut state, Action::TopicsFetched(vec![topic("test.b"), topic("test.a"), internal, topic("orders")]));
+
+        // Internal topics (listed first) are refused; checking moves down
+        update(&mut state, Action::ToggleTopicMark);
+        assert!(state.topics_state.marked.is_empty());
+        assert_eq!(state.ui_state.toast_messages.last().map(|t| t.level), Some(Level::Warning));
+        update(&mut state, Action::SelectTopic(1));
+        update(&mut state, Action::ToggleTopicMark);
+        assert_eq!(state.topics_state.selected_index, 2);
+        update(&mut state, Action::ToggleTopicMark);
+        update(&mut state, Action::ToggleTopicMark);
+        update(&mut state, Action::SelectTopic(2));
+        update(&mut state, Action::ToggleTopicMark);
+
+        update(&mut state, Action::RequestDeleteTopics);
+        let Some(ModalType::Confirm { action: ConfirmAction::DeleteTopics(names), .. }) = &state.ui_state.active_modal else {
+            panic!("expected a bulk delete confirmation, got {:?}", state.ui_state.active_modal)
+        };
+        assert_eq!(names, &["orders", "test.b"]);
+
+        let Command::DeleteKafkaTopics(names) = update(&mut state, Action::ModalConfirm) else { panic!("expected a delete") };
+        assert_eq!(names, ["orders", "test.b"]);
+
+        let failed = vec![("test.b".to_string(), "TopicAuthorizationFailed".to_string())];
+        let cmd = update(&mut state, Action::TopicsDeleted { deleted: vec!["orders".into()], failed });
+        assert!(matches!(cmd, Command::FetchTopicList));
+        assert_eq!(state.topics_state.marked.iter().collect::<Vec<_>>(), ["test.b"]);
+
+        // A mark left only on an internal topic warns instead of asking
+        state.topics_state.marked = ["__consumer_offsets".to_string()].into_iter().collect();
+        update(&mut state, Action::RequestDeleteTopics);
+        assert!(state.ui_state.active_modal.is_none());
+        assert_eq!(state.ui_state.toast_messages.last().map(|t| t.level), Some(Level::Warning));
+    }
+
+    #[test]
+    fn test_partition_filter_stays_with_its_topic() {
+        let mut state = connected();
+        state.active_screen = Screen::Topics;
+        update(&mut state, Action::TopicsFetched(vec![topic("orders"), topic("payments")]));
+        let Command::FetchMessages { partition: None, .. } = update(&mut state, Action::Select) else { panic!("expected a fetch") };
+        state.messages_state.partition_filter = Some(2);
+
+        // Back to the same topic keeps the filter, another topic starts unfiltered
+        update(&mut state, Action::GoBack);
+        let Command::FetchMessages { partition: Some(2), .. } = update(&mut state, Action::Select) else { panic!("expected a fetch") };
+        update(&mut state, Action::GoBack);
+        update(&mut state, Action::SelectTopic(1));
+        let Command::FetchMessages { topic, partition, .. } = update(&mut state, Action::Select) else { panic!("expected a fetch") };
+        assert_eq!((topic.as_str(), partition), ("payments", None));
+    }
+
+    #[test]
+    fn test_live_restart_from_earliest_replaces_loaded_messages() {
+        let mut state = connected();
+        let loaded = vec![KafkaMessage { offset: 0, ..Default::default() }, KafkaMessage { offset: 1, ..Default::default() }];
+        state.messages_state.messages = loaded.clone();
+
+        update(&mut state, Action::StartConsuming { topic: "orders".into() });
+        assert_eq!(state.messages_state.messages.len(), 2, "tailing from latest keeps the loaded messages");
+        update(&mut state, Action::StopConsuming);
+
+        state.messages_state.offset_mode = OffsetMode::Earliest;
+        update(&mut state, Action::StartConsuming { topic: "orders".into() });
+        assert!(state.messages_state.messages.is_empty());
+        for msg in loaded {
+            update(&mut state, Action::MessageReceived(msg));
+        }
+        assert_eq!(state.messages_state.messages.len(), 2);
+    }
+
+    #[test]
+    fn test_watermark_refresh_updates_partitions() {
+        let mut state = connected();
+        update(&mut state, Action::TopicsFetched(vec![topic("orders")]));
+        update(&mut state, Action::ViewTopicDetails("orders".into()));
+        let partition = |id: i32, high: i64| PartitionInfo {
+            id,
+            leader: 1,
+            replicas: vec![1],
+            isr: vec![1],
+            low_watermark: 0,
+            high_watermark: high,
+        };
+        let detail = TopicDetail {
+            name: "orders".into(),
+            partitions: vec![partition(0, 10), partition(1, 20)],
+            config: vec![("cleanup.policy".into(), "delete".into())],
+            is_internal: false,
+        };
+        update(&mut state, Action::TopicDetailsFetched(detail));
+
+        let cmd = update(&mut state, Action::RefreshTopicWatermarks("orders".into()));
+        assert!(matches!(cmd, Command::FetchTopicWatermarks(ref t) if t == "orders"));
+
+        let watermarks = vec![(0, 5, 15), (1, 0, 42)];
+        update(&mut state, Action::TopicWatermarksFetched { topic: "orders".into(), watermarks });
+        let detail = state.topics_state.current_detail.as_ref().unwrap();
+        let marks: Vec<(i64, i64)> = detail.partitions.iter().map(|p| (p.low_watermark, p.high_watermark)).collect();
+        assert_eq!(marks, [(5, 15), (0, 42)]);
+        assert_eq!(detail.config.len(), 1);
+        assert_eq!(state.topics_state.topics[0].message_count, Some(52));
+
+        // A late answer for another topic leaves the details alone
+        update(&mut state, Action::TopicWatermarksFetched { topic: "payments".into(), watermarks: vec![(0, 0, 1)] });
+        assert_eq!(state.topics_state.current_detail.as_ref().unwrap().partitions[0].high_watermark, 15);
+    }
+
+    #[test]
+    fn test_produce_to_missing_topic_asks_first() {
+        let mut state = connected();
+        update(&mut state, Action::TopicsFetched(vec![topic("orders")]));
+
+        let form = |topic: &str| ProduceFormState { topic: topic.into(), value: "{}".into(), ..Default::default() };
+        update(&mut state, Action::ShowModal(ModalType::ProduceForm(form("orders"))));
+        assert!(matches!(update(&mut state, Action::ModalConfirm), Command::ProduceKafkaMessage { .. }));
+
+        update(&mut state, Action::ShowModal(ModalType::ProduceForm(form("ordres"))));
+        assert!(matches!(update(&mut state, Action::ModalConfirm), Command::None));
+        assert!(matches!(
+            state.ui_state.active_modal,
+            Some(ModalType::Confirm { action: ConfirmAction::ProduceToMissingTopic { .. }, .. })
+        ));
+        let Command::ProduceKafkaMessage { topic, .. } = update(&mut state, Action::ModalConfirm) else {
+            panic!("expected a produce")
+        };
+        assert_eq!(topic, "ordres");
+    }
+
+    #[test]
+    fn test_connection_form_confirm_connects() {
+        let mut state = AppState::default();
+        state.ui_state.active_modal = Some(ModalType::ConnectionForm(ConnectionFormState {
+            name: "local".into(),
+            brokers: "localhost:9092".into(),
+            consumer_group: "reader".into(),
+            ..Default::default()
+        }));
+
+        let cmd = update(&mut state, Action::ModalConfirm);
+        let Command::ConnectToKafka(profile) = cmd else { panic!("expected ConnectToKafka, got {:?}", cmd) };
+        assert_eq!(profile.brokers, "localhost:9092");
+        assert_eq!(profile.consumer_group.as_deref(), Some("reader"));
+        assert_eq!(profile.schema_registry_url, None);
+        assert!(state.ui_state.active_modal.is_none());
+        assert_eq!(state.connection.status, ConnectionStatus::Connecting);
+    }
+
+    #[test]
+    fn test_connect_is_not_retriggered_while_connecting() {
+        let mut state = AppState::default();
+        let profile = ConnectionProfile { name: "local".into(), brokers: "localhost:9092".into(), ..Default::default() };
+        state.connection.available_profiles = vec![profile.clone()];
+
+        assert!(matches!(update(&mut state, Action::Select), Command::ConnectToKafka(_)));
+        assert!(state.connection.status.is_connecting());
+        assert!(matches!(update(&mut state, Action::Select), Command::None));
+        assert!(matches!(update(&mut state, Action::Connect(profile)), Command::None));
+
+        update(&mut state, Action::ConnectionFailed("timed out".into()));
+        assert!(matches!(update(&mut state, Action::Select), Command::ConnectToKafka(_)));
+    }
+
+    #[test]
+    fn test_profiles_sorted_by_last_use() {
+        let mut state = AppState::default();
+        let profile = |name: &str, days_ago: Option<i64>| ConnectionProfile {
+            name: name.into(),
+            last_used: days_ago.map(|d| chrono::Utc::now() - chrono::Duration::days(d)),
+            ..Default::default()
+        };
+        update(&mut state, Action::ConnectionsLoaded(vec![
+            profile("never", None),
+            profile("old", Some(30)),
+            profile("recent", Some(1)),
+        ]));
+        let names = |state: &AppState| state.connection.available_profiles.iter().map(|p| p.name.clone()).collect::<Vec<_>>();
+        assert_eq!(names(&state), ["recent", "old", "never"]);
+
+        let never = state.connection.available_profiles[2].clone();
+        update(&mut state, Action::Connect(never));
+        let Command::Batch(cmds) = update(&mut state, Action::ConnectionSuccess) else { panic!("expected a batch") };
+        let saved = cmds.iter().find_map(|c| match c {
+            Command::SaveConnectionProfile(p) => Some(p),
+            _ => None,
+        });
+        assert!(saved.is_some_and(|p| p.name == "never" && p.last_used.is_some()));
+
+        update(&mut state, Action::Disconnect);
+        assert_eq!(names(&state), ["never", "recent", "old"]);
+        assert_eq!(state.connection.selected_index, 0);
+    }
+
+    #[test]
+    fn test_connection_success_and_disconnect() {
+        let mut state = connected();
+        state.connection.ephemeral = true;
+        state.connection.status = ConnectionStatus::Connecting;
+
+        let Command::Batch(cmds) = update(&mut state, Action::ConnectionSuccess) else { panic!("expected a batch") };
+        assert!(matches!(
+            cmds.as_slice(),
+            [Command::FetchTopicList, Command::FetchConsumerGroupList, Command::FetchBrokerList]
+        ));
+        assert_eq!(state.connection.status, ConnectionStatus::Connected);
+        assert_eq!(state.active_screen, Screen::Topics);
+
+        state.topics_state.topics = vec![topic("orders")];
+        assert!(matches!(update(&mut state, Action::Disconnect), Command::DisconnectFromKafka));
+        assert_eq!(state.connection.status, ConnectionStatus::Disconnected);
+        assert!(state.connection.active_profile.is_none());
+        assert!(state.topics_state.topics.is_empty());
+        assert_eq!(state.active_screen, Screen::Welcome);
+    }
+
+    #[test]
+    fn test_consumer_groups_fetched() {
+        let mut state = connected();
+        assert!(matches!(update(&mut state, Action::FetchConsumerGroups), Command::FetchConsumerGroupList));
+        let group = ConsumerGroupInfo {
+            group_id: "billing".into(),
+            state: "Stable".into(),
+            protocol_type: "consumer".into(),
+            members_count: 2,
+            topics: vec!["orders".into()],
+            total_lag: 7,
+        };
+        update(&mut state, Action::ConsumerGroupsFetched(vec![group]));
+        assert!(!state.consumer_groups_state.loading);
+        assert_eq!(state.consumer_groups_state.selected_group().map(|g| g.total_lag), Some(7));
+    }
+
+    #[test]
+    fn test_connection_lost_reconnects_once() {
+        let mut state = connected();
+        let lost = || Action::ConnectionLost {
+            retry: Box::new(Command::FetchTopicList),
+            failed: Box::new(Action::TopicsFetchFailed("AllBrokersDown".into())),
+        };
+
+        let cmd = update(&mut state, lost());
+        assert!(matches!(cmd, Command::Reconnect { ref retry, .. } if matches!(**retry, Command::FetchTopicList)));
+        assert_eq!(state.connection.status, ConnectionStatus::Reconnecting);
+
+        // Requests failing while the reconnect runs wait for it too
+        assert!(matches!(update(&mut state, lost()), Command::Reconnect { .. }));
+
+        update(&mut state, Action::Reconnected);
+        assert_eq!(state.connection.status, ConnectionStatus::Connected);
+
+        // Losing it again straight away is reported rather than retried
+        state.topics_state.loading = true;
+        assert!(matches!(update(&mut state, lost()), Command::None));
+        assert!(!state.topics_state.loading);
+        assert_eq!(state.connection.status, ConnectionStatus::Connected);
+    }
+
+    #[test]
+    fn test_quit_stops_the_loop() {
+        let mut state = AppState { running: true, ..Default::default() };
+        assert!(matches!(update(&mut state, Action::Quit), Command::None));
+        assert!(!state.running);
+    }
+}
@@ -3,6 +3,11 @@
 //! This module provides validation for user input in forms,
 //! returning descriptive errors instead of silently using defaults.
 
+use std::fs::File;
+use std::path::Path;
+
+use chrono::{DateTime, NaiveDateTime, Utc};
+
 use crate::error::AppError;
 
 /// Parse and validate partition count input.
@@ -81,6 +86,237 @@ pub fn parse_offset(input: &str) -> Result<i64, AppError> {
     Ok(value)
 }
 
+/// Parse an optional partition number.
+///
+/// Empty input means "all partitions" and yields `None`.
+pub fn parse_optional_partition(input: &str) -> Result<Option<i32>, AppError> {
+    if input.trim().is_empty() {
+        return Ok(None);
+    }
+
+    let value: i32 = input.trim().parse().map_err(|_| AppError::Validation {
+        field: "partition".into(),
+        message: format!("'{}' is not a valid partition", input),
+    })?;
+
+    if value < 0 {
+        return Err(AppError::Validation {
+            field: "partition".into(),
+            message: "Partition must be non-negative".into(),
+        });
+    }
+
+    Ok(Some(value))
+}
+
+/// Parse a timestamp given as RFC 3339, `YYYY-MM-DD HH:MM:SS` (UTC), or epoch milliseconds.
+pub fn parse_timestamp(input: &str) -> Result<DateTime<Utc>, AppError> {
+    let input = input.trim();
+
+    if let Ok(millis) = input.parse::<i64>() {
+        return DateTime::from_timestamp_millis(millis).ok_or_else(|| AppError::Validation {
+            field: "timestamp".into(),
+            message: format!("'{}' is out of range", input),
+        });
+    }
+
+    DateTime::parse_from_rfc3339(input)
+        .map(|dt| dt.with_timezone(&Utc))
+        .or_else(|_| NaiveDateTime::parse_from_str(input, "%Y-%m-%d %H:%M:%S").map(|dt| dt.and_utc()))
+        .map_err(|_| AppError::Validation {
+            field: "timestamp".into(),
+            message: format!("'{}' is not a valid timestamp (use YYYY-MM-DD HH:MM:SS)", input),
+        })
+}
+
+/// Validate topic config overrides: names must be non-empty and unique.
+pub fn validate_topic_configs(configs: &[(String, String)]) -> Result<(), AppError> {
+    for (i, (name, _)) in configs.iter().enumerate() {
+        if name.trim().is_empty() {
+            return Err(AppError::Validation {
+                field: "configs".into(),
+                message: format!("Config #{} has an empty name", i + 1),
+            });
+        }
+        if configs[..i].iter().any(|(other, _)| other.trim() == name.trim()) {
+            return Err(AppError::Validation {
+                field: "configs".into(),
+                message: format!("'{}' is set more than once", name.trim()),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Largest burst a single produce request may send.
+pub const MAX_PRODUCE_COUNT: usize = 100_000;
+
+/// Parse how many copies of a message to produce.
+///
+/// Empty input means a single message.
+pub fn parse_produce_count(input: &str) -> Result<usize, AppError> {
+    if input.trim().is_empty() {
+        return Ok(1);
+    }
+
+    let value: usize = input.trim().parse().map_err(|_| AppError::Validation {
+        field: "count".into(),
+        message: format!("'{}' is not a valid count", input),
+    })?;
+
+    if value == 0 || value > MAX_PRODUCE_COUNT {
+        return Err(AppError::Validation {
+            field: "count".into(),
+            message: format!("Count must be between 1 and {}", MAX_PRODUCE_COUNT),
+        });
+    }
+
+    Ok(value)
+}
+
+/// Parse the partition to produce to, checked against the topic's partition count when known.
+///
+/// Empty input lets the partitioner choose and yields `None`.
+pub fn parse_produce_partition(input: &str, partition_count: Option<i32>) -> Result<Option<i32>, AppError> {
+    let partition = parse_optional_partition(input)?;
+    match (partition, partition_count) {
+        (Some(p), Some(count)) if p >= count => Err(AppError::Validation {
+            field: "partition".into(),
+            message: format!("Topic has {} partitions (0–{})", count, count - 1),
+        }),
+        _ => Ok(partition),
+    }
+}
+
+/// Validate a comma-separated bootstrap list of `host:port` entries.
+///
+/// Entries may carry a `scheme://` prefix; IPv6 hosts must be bracketed, as in `[::1]:9092`.
+pub fn validate_brokers(input: &str) -> Result<(), AppError> {
+    if input.trim().is_empty() {
+        return Err(AppError::Validation {
+            field: "brokers".into(),
+            message: "At least one broker is required".into(),
+        });
+    }
+
+    for entry in input.split(',').map(str::trim) {
+        let invalid = |reason: &str| AppError::Validation {
+            field: "brokers".into(),
+            message: format!("Invalid broker '{}': {}", entry, reason),
+        };
+        if entry.is_empty() {
+            return Err(invalid("empty entry"));
+        }
+        let address = entry.split_once("://").map_or(entry, |(_, address)| address);
+        let (host, port) = if let Some(rest) = address.strip_prefix('[') {
+            let (host, rest) = rest.split_once(']').ok_or_else(|| invalid("unclosed '['"))?;
+            let port = rest.strip_prefix(':').ok_or_else(|| invalid("missing port"))?;
+            (host, port)
+        } else {
+            let (host, port) = address.rsplit_once(':').ok_or_else(|| invalid("missing port"))?;
+            if host.contains(':') {
+                return Err(invalid("IPv6 addresses need brackets, as in [::1]:9092"));
+            }
+            (host, port)
+        };
+        if host.is_empty() {
+            return Err(invalid("empty host"));
+        }
+        if host.chars().any(char::is_whitespace) {
+            return Err(invalid("host contains spaces"));
+        }
+        if port.is_empty() {
+            return Err(invalid("missing port"));
+        }
+        match port.parse::<u16>() {
+            Ok(p) if p > 0 => {}
+            _ => return Err(invalid("port must be between 1 and 65535")),
+        }
+    }
+
+    Ok(())
+}
+
+/// Shortest client timeout a connection may set, in milliseconds.
+pub const MIN_TIMEOUT_MS: u32 = 1_000;
+
+/// Longest client timeout a connection may set, in milliseconds.
+pub const MAX_TIMEOUT_MS: u32 = 300_000;
+
+/// Parse an optional client timeout in milliseconds.
+///
+/// Empty input means the default and yields `None`.
+pub fn parse_timeout_ms(field: &str, input: &str) -> Result<Option<u32>, AppError> {
+    if input.trim().is_empty() {
+        return Ok(None);
+    }
+
+    let value: u32 = input.trim().parse().map_err(|_| AppError::Validation {
+        field: field.into(),
+        message: format!("'{}' is not a valid number of milliseconds", input),
+    })?;
+
+    if !(MIN_TIMEOUT_MS..=MAX_TIMEOUT_MS).contains(&value) {
+        return Err(AppError::Validation {
+            field: field.into(),
+            message: format!("Timeout must be between {} and {} ms", MIN_TIMEOUT_MS, MAX_TIMEOUT_MS),
+        });
+    }
+
+    Ok(Some(value))
+}
+
+/// Validate produce headers: names must be non-empty, duplicates are allowed.
+pub fn validate_headers(headers: &[(String, String)]) -> Result<(), AppError> {
+    if let Some(i) = headers.iter().position(|(name, _)| name.trim().is_empty()) {
+        return Err(AppError::Validation {
+            field: "headers".into(),
+            message: format!("Header #{} has an empty name", i + 1),
+        });
+    }
+    Ok(())
+}
+
+/// Check that a certificate or key file exists and can be read.
+///
+/// Empty input means the setting is unused and passes.
+pub fn validate_readable_file(field: &str, input: &str) -> Result<(), AppError> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Ok(());
+    }
+
+    let error = |message: String| AppError::Validation { field: field.into(), message };
+    let path = Path::new(input);
+    if !path.exists() {
+        return Err(error(format!("'{}' does not exist", input)));
+    }
+    if !path.is_file() {
+        return Err(error(format!("'{}' is not a file", input)));
+    }
+    File::open(path).map_err(|e| error(format!("'{}' cannot be read: {}", input, e)))?;
+    Ok(())
+}
+
+/// Check that an optional URL is absolute http:// or https://.
+///
+/// Empty input means the setting is unused and passes.
+pub fn validate_http_url(field: &str, input: &str) -> Result<(), AppError> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Ok(());
+    }
+
+    let lower = input.to_ascii_lowercase();
+    match lower.strip_prefix("https://").or_else(|| lower.strip_prefix("http://")) {
+        Some(rest) if !rest.is_empty() && !rest.starts_with('/') => Ok(()),
+        _ => Err(AppError::Validation {
+            field: field.into(),
+            message: format!("'{}' is not an http:// or https:// URL", input),
+        }),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -137,4 +373,121 @@ mod tests {
         assert!(parse_offset("-1").is_err());
         assert!(parse_offset("abc").is_err());
     }
+
+    #[test]
+    fn test_parse_optional_partition() {
+        assert_eq!(parse_optional_partition("").unwrap(), None);
+        assert_eq!(parse_optional_partition(" 2 ").unwrap(), Some(2));
+        assert!(parse_optional_partition("-1").is_err());
+        assert!(parse_optional_partition("abc").is_err());
+    }
+
+    #[test]
+    fn test_parse_timestamp_valid() {
+        let expected = DateTime::from_timestamp_millis(1_700_000_000_000).unwrap();
+        assert_eq!(parse_timestamp("1700000000000").unwrap(), expected);
+        assert_eq!(parse_timestamp("2023-11-14 22:13:20").unwrap(), expected);
+        assert_eq!(parse_timestamp("2023-11-14T22:13:20Z").unwrap(), expected);
+    }
+
+    #[test]
+    fn test_parse_timestamp_invalid() {
+        assert!(parse_timestamp("").is_err());
+        assert!(parse_timestamp("yesterday").is_err());
+        assert!(parse_timestamp("2023-13-01 00:00:00").is_err());
+    }
+
+    #[test]
+    fn test_parse_timeout_ms() {
+        assert_eq!(parse_timeout_ms("socket_timeout", "").unwrap(), None);
+        assert_eq!(parse_timeout_ms("socket_timeout", " 120000 ").unwrap(), Some(120_000));
+        assert!(parse_timeout_ms("socket_timeout", "0").is_err());
+        assert!(parse_timeout_ms("socket_timeout", "999").is_err());
+        assert!(parse_timeout_ms("request_timeout", "300001").is_err());
+        assert!(parse_timeout_ms("request_timeout", "-5").is_err());
+        assert!(parse_timeout_ms("request_timeout", "30s").is_err());
+    }
+
+    #[test]
+    fn test_validate_topic_configs() {
+        let pair = |k: &str, v: &str| (k.to_string(), v.to_string());
+        assert!(validate_topic_configs(&[]).is_ok());
+        assert!(validate_topic_configs(&[pair("cleanup.policy", "compact"), pair("retention.ms", "1000")]).is_ok());
+        assert!(validate_topic_configs(&[pair("", "compact")]).is_err());
+        assert!(validate_topic_configs(&[pair("retention.ms", "1"), pair(" retention.ms", "2")]).is_err());
+    }
+
+    #[test]
+    fn test_parse_produce_count() {
+        assert_eq!(parse_produce_count("").unwrap(), 1);
+        assert_eq!(parse_produce_count(" 10 ").unwrap(), 10);
+        assert!(parse_produce_count("0").is_err());
+        assert!(parse_produce_count("abc").is_err());
+        assert!(parse_produce_count(&(MAX_PRODUCE_COUNT + 1).to_string()).is_err());
+    }
+
+    #[test]
+    fn test_parse_produce_partition() {
+        assert_eq!(parse_produce_partition("", Some(3)).unwrap(), None);
+        assert_eq!(parse_produce_partition("2", Some(3)).unwrap(), Some(2));
+        assert_eq!(parse_produce_partition("7", None).unwrap(), Some(7));
+        assert!(parse_produce_partition("3", Some(3)).is_err());
+        assert!(parse_produce_partition("-1", None).is_err());
+    }
+
+    #[test]
+    fn test_validate_brokers() {
+        assert!(validate_brokers("localhost:9092").is_ok());
+        assert!(validate_brokers("kafka-1:9092, kafka-2:9093").is_ok());
+        assert!(validate_brokers("SASL_SSL://broker.example.com:9094").is_ok());
+        assert!(validate_brokers("[::1]:9092,[2001:db8::7]:9093").is_ok());
+
+        let message = |input| match validate_brokers(input) {
+            Err(AppError::Validation { message, .. }) => message,
+            other => panic!("expected a validation error, got {:?}", other),
+        };
+        assert_eq!(message("localhost:"), "Invalid broker 'localhost:': missing port");
+        assert_eq!(message("localhost"), "Invalid broker 'localhost': missing port");
+        assert_eq!(message(":9092"), "Invalid broker ':9092': empty host");
+        assert_eq!(message("a:9092,"), "Invalid broker '': empty entry");
+        assert!(message("localhost:99999").contains("port must be"));
+        assert!(message("localhost:0").contains("port must be"));
+        assert!(message("::1:9092").contains("brackets"));
+        assert!(message("[::1]").contains("missing port"));
+        assert!(message("[::1:9092").contains("unclosed"));
+        assert!(message("[]:9092").contains("empty host"));
+        assert!(message(" ").contains("At least one broker"));
+    }
+
+    #[test]
+    fn test_validate_headers() {
+        let pair = |k: &str, v: &str| (k.to_string(), v.to_string());
+        assert!(validate_headers(&[]).is_ok());
+        assert!(validate_headers(&[pair("trace", "1"), pair("trace", "2")]).is_ok());
+        assert!(validate_headers(&[pair("trace", "1"), pair(" ", "x")]).is_err());
+    }
+
+    #[test]
+    fn test_validate_readable_file() {
+        let dir = std::env::temp_dir();
+        let file = dir.join(format!("kafka-tui-ca-{}.pem", std::process::id()));
+        std::fs::write(&file, "-----BEGIN CERTIFICATE-----").unwrap();
+
+        assert!(validate_readable_file("ca_location", "").is_ok());
+        assert!(validate_readable_file("ca_location", file.to_str().unwrap()).is_ok());
+        assert!(validate_readable_file("ca_location", dir.to_str().unwrap()).is_err());
+        assert!(validate_readable_file("ca_location", "/nonexistent/ca.pem").is_err());
+
+        std::fs::remove_file(file).unwrap();
+    }
+
+    #[test]
+    fn test_validate_http_url() {
+        assert!(validate_http_url("token_endpoint", "").is_ok());
+        assert!(validate_http_url("token_endpoint", "https://idp.example.com/oauth2/token").is_ok());
+        assert!(validate_http_url("token_endpoint", "HTTP://localhost:8080/token").is_ok());
+        assert!(validate_http_url("token_endpoint", "-o/tmp/token").is_err());
+        assert!(validate_http_url("token_endpoint", "file:///etc/passwd").is_err());
+        assert!(validate_http_url("token_endpoint", "https://").is_err());
+    }
 }
@@ -1,7 +1,10 @@
+use std::collections::HashMap;
 use std::path::PathBuf;
 
 use serde::{Deserialize, Serialize};
 
+use crate::storage::paths;
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct AppConfig {
     /// Default connection profile name
@@ -22,6 +25,50 @@ pub struct AppConfig {
     /// Log level
     #[serde(default = "default_log_level")]
     pub log_level: String,
+
+    /// In-memory log entries kept for the Logs screen (default 1000)
+    #[serde(default)]
+    pub max_log_entries: Option<usize>,
+
+    /// Connection attempts before giving up, with exponential backoff between them (default 3)
+    #[serde(default)]
+    pub connect_attempts: Option<u32>,
+
+    /// Edit topic configs with the legacy full-replace AlterConfigs request, for brokers
+    /// older than Kafka 2.3; unedited overrides may be reset to their defaults
+    #[serde(default)]
+    pub legacy_alter_configs: bool,
+
+    /// Reopen the list screen, selected topic and group, and filters a profile was left
+    /// on when connecting to it again
+    #[serde(default)]
+    pub remember_session: bool,
+
+    /// Show offsets, lag and message counts in full with thousands separators rather than
+    /// abbreviated to K/M/B; `#` switches at runtime
+    #[serde(default)]
+    pub exact_numbers: bool,
+
+    /// Extra key bindings, e.g. `move_down = ["n", "ctrl+n"]` or `quit = "ctrl+x"`
+    #[serde(default)]
+    pub keymap: HashMap<String, KeyChords>,
+}
+
+/// One key chord or a list of them
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum KeyChords {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl KeyChords {
+    pub fn as_slice(&self) -> &[String] {
+        match self {
+            Self::One(chord) => std::slice::from_ref(chord),
+            Self::Many(chords) => chords,
+        }
+    }
 }
 
 fn default_theme() -> String {
@@ -39,8 +86,7 @@ fn default_log_level() -> String {
 impl AppConfig {
     pub fn load(path: Option<PathBuf>) -> anyhow::Result<Self> {
         let config_path = path.unwrap_or_else(|| {
-            let mut p = dirs::config_dir().unwrap_or_else(|| PathBuf::from("."));
-            p.push("kafka-tui");
+            let mut p = paths::config_dir();
             p.push("config.toml");
             p
         });
@@ -56,8 +102,7 @@ impl AppConfig {
 
     pub fn save(&self, path: Option<PathBuf>) -> anyhow::Result<()> {
         let config_path = path.unwrap_or_else(|| {
-            let mut p = dirs::config_dir().unwrap_or_else(|| PathBuf::from("."));
-            p.push("kafka-tui");
+            let mut p = paths::config_dir();
             std::fs::create_dir_all(&p).ok();
             p.push("config.toml");
             p
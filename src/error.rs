@@ -17,6 +17,9 @@ pub enum AppError {
     #[error("Connection error: {0}")]
     Connection(String),
 
+    #[error("Authentication failed: {0}")]
+    Authentication(String),
+
     #[error("Invalid input: {0}")]
     InvalidInput(String),
 
@@ -52,3 +55,32 @@ impl From<toml::de::Error> for AppError {
 }
 
 pub type AppResult<T> = Result<T, AppError>;
+
+impl AppError {
+    /// Whether the brokers stopped answering altogether, as after a laptop sleep or a VPN
+    /// drop, rather than the request itself failing.
+    pub fn is_connection_lost(&self) -> bool {
+        match self {
+            AppError::Connection(_) | AppError::Timeout => true,
+            // rdkafka errors arrive as text; their codes are spelled out in it
+            AppError::Kafka(msg) => ["BrokerTransportFailure", "AllBrokersDown"].iter().any(|code| msg.contains(code)),
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_connection_lost() {
+        assert!(AppError::Connection("no broker reachable".into()).is_connection_lost());
+        assert!(AppError::Kafka(
+            "Metadata fetch failed: Meta data fetch error: AllBrokersDown (Local: All broker connections are down)".into()
+        )
+        .is_connection_lost());
+        assert!(!AppError::Kafka("Topic 'orders' not found".into()).is_connection_lost());
+        assert!(!AppError::Authentication("bad password".into()).is_connection_lost());
+    }
+}
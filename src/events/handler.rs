@@ -1,12 +1,22 @@
-use crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind};
+use crossterm::event::{
+    Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+};
+use ratatui::layout::{Position, Rect};
 
 use crate::app::actions::Action;
 use crate::app::state::{
-    AddPartitionsFormState, AlterConfigFormState, AppState, ModalType, PurgeTopicFormState, Screen,
+    AddPartitionsFormState, AlterConfigFormState, AppState, CommandPaletteState, InputAction, ModalType, PurgeTopicFormState,
+    Screen, SeekFormState, SidebarItem, TopicDetailTab,
 };
 use crate::events::key_bindings::{
     global_key_binding, help_key_binding, modal_key_binding, screen_key_binding,
 };
+use crate::ui::layout::{
+    consumer_groups_layout, messages_layout, messages_layout_collapsed, topics_list_layout, AppLayout,
+};
+use crate::ui::screens::consumer_groups::list::sort_field_at as groups_sort_field_at;
+use crate::ui::screens::topics::list::sort_field_at;
+use crate::ui::widgets::visible_window;
 
 pub struct EventHandler;
 
@@ -16,7 +26,7 @@ impl EventHandler {
         match event {
             Event::Key(key) => Self::handle_key_event(key, state),
             Event::Resize(width, height) => Some(Action::Resize(width, height)),
-            Event::Mouse(_) => None, // Mouse events not handled for now
+            Event::Mouse(mouse) => Self::handle_mouse_event(mouse, state),
             Event::FocusGained | Event::FocusLost => None,
             Event::Paste(_) => None, // Paste not handled for now
         }
@@ -37,24 +47,118 @@ impl EventHandler {
 
         // 2. If a modal is open, handle modal-specific keys
         if let Some(ref modal) = state.ui_state.active_modal {
+            if let (ModalType::CommandPalette(palette), KeyCode::Enter) = (modal, key.code) {
+                return Self::palette_action(palette, state);
+            }
             return modal_key_binding(key, modal);
         }
 
-        // 3. Try global key bindings first
+        // 3. Apply user remappings from the config file
+        let key = state.ui_state.keymap.translate(key)?;
+
+        Self::context_key_action(key, state, state.ui_state.sidebar_focused)
+    }
+
+    /// What `key` does on the current screen once no modal or help is in the way.
+    fn context_key_action(key: KeyEvent, state: &AppState, sidebar_focused: bool) -> Option<Action> {
+        // 4. The JSON tree takes the arrow keys and Esc before anything else
+        if let Some(action) = Self::json_tree_keys(key, state, sidebar_focused) {
+            return Some(action);
+        }
+
+        // 5. Try global key bindings
         if let Some(action) = global_key_binding(key) {
             return Some(action);
         }
 
-        // 4. Handle state-dependent keys for TopicDetails
-        if let Some(action) = Self::topic_details_keys(key, state) {
+        // 6. Handle state-dependent keys for TopicDetails
+        if let Some(action) = Self::topic_details_keys(key, state, sidebar_focused) {
+            return Some(action);
+        }
+
+        // 7. Handle state-dependent keys for Messages
+        if let Some(action) = Self::messages_keys(key, state, sidebar_focused) {
             return Some(action);
         }
 
-        // 5. Try screen-specific key bindings
-        screen_key_binding(&state.active_screen, key, state.ui_state.sidebar_focused)
+        // 8. Try screen-specific key bindings
+        screen_key_binding(&state.active_screen, key, sidebar_focused)
+    }
+
+    /// Run the selected palette command as its key would on the content pane.
+    fn palette_action(palette: &CommandPaletteState, state: &AppState) -> Option<Action> {
+        let command = palette.selected_command()?;
+        let action = Self::context_key_action(command.key, state, false)?;
+        Some(Action::RunPaletteCommand { label: command.label, action: Box::new(action) })
+    }
+
+    /// Handle mouse events: wheel scrolls the focused list, left-click selects rows and sidebar items
+    fn handle_mouse_event(mouse: MouseEvent, state: &AppState) -> Option<Action> {
+        if state.ui_state.show_help || state.ui_state.active_modal.is_some() {
+            return None;
+        }
+
+        match mouse.kind {
+            MouseEventKind::ScrollUp => Some(Action::MoveUp),
+            MouseEventKind::ScrollDown => Some(Action::MoveDown),
+            MouseEventKind::Down(MouseButton::Left) => {
+                Self::click_action(Position::new(mouse.column, mouse.row), state)
+            }
+            _ => None,
+        }
+    }
+
+    /// Map a click to an action by recomputing the layout the renderer used
+    fn click_action(pos: Position, state: &AppState) -> Option<Action> {
+        if state.active_screen == Screen::Welcome {
+            return None;
+        }
+
+        let (width, height) = state.ui_state.terminal_size;
+        let layout = AppLayout::new(Rect::new(0, 0, width, height));
+
+        if layout.sidebar.contains(pos) {
+            // First item sits just below the top border
+            let index = pos.y.checked_sub(layout.sidebar.y + 1)? as usize;
+            return SidebarItem::ALL.get(index).map(|item| Action::SelectSidebarItem(item.clone()));
+        }
+
+        if !layout.content.contains(pos) {
+            return None;
+        }
+
+        match &state.active_screen {
+            Screen::Topics => {
+                let list = topics_list_layout(layout.content).list;
+                let ts = &state.topics_state;
+                if pos.y == list.y + 1 {
+                    return sort_field_at(list, pos.x, ts).map(Action::SortTopics);
+                }
+                table_row_at(list, pos, ts.filtered_topics().len(), ts.selected_index).map(Action::SelectTopic)
+            }
+            Screen::Messages { .. } => {
+                let ms = &state.messages_state;
+                let list = if ms.detail_expanded {
+                    messages_layout(layout.content).list
+                } else {
+                    messages_layout_collapsed(layout.content).list
+                };
+                table_row_at(list, pos, ms.messages.len(), ms.selected_index).map(Action::SelectMessage)
+            }
+            Screen::ConsumerGroups => {
+                let list = consumer_groups_layout(layout.content).list;
+                let cs = &state.consumer_groups_state;
+                if pos.y == list.y + 1 {
+                    return groups_sort_field_at(list, pos.x).map(Action::SortConsumerGroups);
+                }
+                table_row_at(list, pos, cs.filtered_groups().len(), cs.selected_index)
+                    .map(Action::SelectConsumerGroup)
+            }
+            _ => None,
+        }
     }
 
-    fn topic_details_keys(key: KeyEvent, state: &AppState) -> Option<Action> {
+    fn topic_details_keys(key: KeyEvent, state: &AppState, sidebar_focused: bool) -> Option<Action> {
         let Screen::TopicDetails { topic_name } = &state.active_screen else {
             return None;
         };
@@ -86,14 +190,96 @@ impl EventHandler {
                     PurgeTopicFormState::new(topic_name.clone())
                 )))
             }
+            // Messages of the selected partition, as they are or from a chosen offset
+            KeyCode::Enter | KeyCode::Char('o')
+                if !sidebar_focused && state.topics_state.detail_tab == TopicDetailTab::Partitions =>
+            {
+                let partition = state.topics_state.current_detail
+                    .as_ref()
+                    .and_then(|d| d.partitions.get(state.topics_state.partition_selected))?
+                    .id;
+                Some(if key.code == KeyCode::Enter {
+                    Action::ViewPartitionMessages { topic: topic_name.clone(), partition }
+                } else {
+                    Action::ShowModal(ModalType::SeekForm(SeekFormState::new(
+                        topic_name.clone(),
+                        &state.messages_state.offset_mode,
+                        Some(partition),
+                    )))
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// Navigation inside the JSON tree of the message detail; other keys fall through
+    fn json_tree_keys(key: KeyEvent, state: &AppState, sidebar_focused: bool) -> Option<Action> {
+        if sidebar_focused || !state.messages_state.json_tree || !matches!(state.active_screen, Screen::Messages { .. }) {
+            return None;
+        }
+
+        match (key.modifiers, key.code) {
+            (_, KeyCode::Up) | (KeyModifiers::NONE, KeyCode::Char('k')) => Some(Action::MoveJsonTreeCursor(-1)),
+            (_, KeyCode::Down) | (KeyModifiers::NONE, KeyCode::Char('j')) => Some(Action::MoveJsonTreeCursor(1)),
+            (_, KeyCode::PageUp) => Some(Action::MoveJsonTreeCursor(-10)),
+            (_, KeyCode::PageDown) => Some(Action::MoveJsonTreeCursor(10)),
+            (_, KeyCode::Home) | (KeyModifiers::NONE, KeyCode::Char('g')) => Some(Action::MoveJsonTreeCursor(isize::MIN)),
+            (_, KeyCode::End) | (KeyModifiers::SHIFT, KeyCode::Char('G')) => Some(Action::MoveJsonTreeCursor(isize::MAX)),
+            (_, KeyCode::Right) | (KeyModifiers::NONE, KeyCode::Char('l')) => Some(Action::ExpandJsonNode),
+            (_, KeyCode::Left) | (KeyModifiers::NONE, KeyCode::Char('h')) => Some(Action::CollapseJsonNode),
+            (_, KeyCode::Enter) | (KeyModifiers::NONE, KeyCode::Char(' ')) => Some(Action::ToggleJsonNode),
+            (KeyModifiers::NONE, KeyCode::Char('y')) => Some(Action::CopyJsonNode),
+            (_, KeyCode::Esc) | (KeyModifiers::SHIFT, KeyCode::Char('V')) => Some(Action::ToggleJsonTree),
+            _ => None,
+        }
+    }
+
+    fn messages_keys(key: KeyEvent, state: &AppState, sidebar_focused: bool) -> Option<Action> {
+        let Screen::Messages { topic_name } = &state.active_screen else {
+            return None;
+        };
+        if sidebar_focused {
+            return None;
+        }
+
+        match (key.modifiers, key.code) {
+            (_, KeyCode::Char('c')) if state.messages_state.consumer_running => Some(Action::StopConsuming),
+            (_, KeyCode::Char('c')) => Some(Action::StartConsuming { topic: topic_name.clone() }),
+            (KeyModifiers::NONE, KeyCode::Char('f')) if state.messages_state.scan_running() => Some(Action::CancelScan),
+            (KeyModifiers::NONE, KeyCode::Char('f')) => Some(Action::ShowModal(ModalType::Input {
+                title: "Find in topic".into(),
+                placeholder: "key or value text, or /regex/".into(),
+                value: String::new(),
+                action: InputAction::ScanTopic { topic: topic_name.clone() },
+            })),
+            (KeyModifiers::CONTROL, KeyCode::Char('r')) | (_, KeyCode::F(5)) => Some(Action::FetchMessages {
+                topic: topic_name.clone(),
+                offset_mode: state.messages_state.offset_mode.clone(),
+                partition: state.messages_state.partition_filter,
+                limit: state.messages_state.fetch_limit,
+            }),
             _ => None,
         }
     }
 }
 
+/// Item index under `pos` in a bordered table with a one-line header.
+fn table_row_at(area: Rect, pos: Position, len: usize, selected: usize) -> Option<usize> {
+    let first_row = area.y + 2;
+    let rows = area.height.saturating_sub(3);
+    if !area.contains(pos) || pos.y < first_row || pos.y >= first_row + rows {
+        return None;
+    }
+    let window = visible_window(len, selected, rows as usize);
+    let index = window.start + (pos.y - first_row) as usize;
+    window.contains(&index).then_some(index)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::app::state::{ProduceFormField, ProduceFormState};
+    use crate::events::key_bindings::{every_screen, palette_commands};
     use crossterm::event::{KeyCode, KeyModifiers};
 
     fn make_key_event(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
@@ -156,4 +342,104 @@ mod tests {
         );
         assert!(matches!(action, Some(Action::GoBack)));
     }
+
+    #[test]
+    fn test_produce_value_enter_inserts_newline() {
+        let mut state = AppState::default();
+        let form = ProduceFormState { value: "{".into(), focused_field: ProduceFormField::Value, ..Default::default() };
+        state.ui_state.active_modal = Some(ModalType::ProduceForm(form));
+
+        let action = EventHandler::handle_key_event(make_key_event(KeyCode::Enter, KeyModifiers::NONE), &state);
+        assert!(matches!(action, Some(Action::UpdateProduceForm(f)) if f.value == "{\n"));
+
+        let action = EventHandler::handle_key_event(make_key_event(KeyCode::Char('s'), KeyModifiers::CONTROL), &state);
+        assert!(matches!(action, Some(Action::ModalConfirm)));
+    }
+
+    #[test]
+    fn test_command_palette_runs_bound_action() {
+        let mut state = AppState { active_screen: Screen::Topics, ..Default::default() };
+        state.ui_state.sidebar_focused = true;
+        let action = EventHandler::handle_key_event(make_key_event(KeyCode::Char(':'), KeyModifiers::NONE), &state);
+        assert!(matches!(action, Some(Action::OpenCommandPalette)));
+
+        // Recently run commands come first until something is typed
+        let recent = vec!["Create topic".to_string()];
+        let mut palette = CommandPaletteState::new(palette_commands(&state.active_screen), &recent);
+        assert_eq!(palette.selected_command().unwrap().label, "Create topic");
+
+        palette.query = "reftop".into();
+        assert_eq!(palette.selected_command().unwrap().label, "Refresh topics");
+        state.ui_state.active_modal = Some(ModalType::CommandPalette(palette));
+
+        // Runs on the content pane even though the sidebar has focus
+        let action = EventHandler::handle_key_event(make_key_event(KeyCode::Enter, KeyModifiers::NONE), &state);
+        assert!(matches!(
+            action,
+            Some(Action::RunPaletteCommand { label: "Refresh topics", action }) if matches!(*action, Action::FetchTopics)
+        ));
+    }
+
+    #[test]
+    fn test_every_palette_command_is_bound() {
+        for (name, screen) in every_screen() {
+            let state = AppState { active_screen: screen.clone(), ..Default::default() };
+            for command in palette_commands(&screen) {
+                assert!(
+                    EventHandler::context_key_action(command.key, &state, false).is_some(),
+                    "'{}' on {} has no binding for {:?}",
+                    command.label,
+                    name,
+                    command.key
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_table_row_at() {
+        // Border at y=10, header at y=11, rows from y=12 through y=18
+        let area = Rect::new(0, 10, 40, 10);
+        assert_eq!(table_row_at(area, Position::new(5, 11), 100, 0), None);
+        assert_eq!(table_row_at(area, Position::new(5, 12), 100, 0), Some(0));
+        assert_eq!(table_row_at(area, Position::new(5, 18), 100, 0), Some(6));
+        assert_eq!(table_row_at(area, Position::new(5, 19), 100, 0), None);
+        // Scrolled so that item 50 is the last visible row
+        assert_eq!(table_row_at(area, Position::new(5, 12), 100, 50), Some(44));
+        // Click below the last item of a short list
+        assert_eq!(table_row_at(area, Position::new(5, 15), 2, 0), None);
+    }
+
+    #[test]
+    fn test_click_uses_last_terminal_size() {
+        let mut state = AppState { active_screen: Screen::Topics, ..Default::default() };
+        let click = |state: &AppState, pos: Position| {
+            let kind = MouseEventKind::Down(MouseButton::Left);
+            let mouse = MouseEvent { kind, column: pos.x, row: pos.y, modifiers: KeyModifiers::NONE };
+            EventHandler::handle_mouse_event(mouse, state)
+        };
+
+        state.ui_state.terminal_size = (120, 40);
+        let sidebar = AppLayout::new(Rect::new(0, 0, 120, 40)).sidebar;
+        let first_item = Position::new(sidebar.x + 1, sidebar.y + 1);
+        assert!(matches!(click(&state, first_item), Some(Action::SelectSidebarItem(_))));
+
+        // Before any size is known nothing is clickable
+        state.ui_state.terminal_size = (0, 0);
+        assert!(click(&state, first_item).is_none());
+    }
+
+    #[test]
+    fn test_mouse_wheel_moves_selection() {
+        let state = AppState::default();
+        let scroll = |kind| MouseEvent { kind, column: 0, row: 0, modifiers: KeyModifiers::NONE };
+        assert!(matches!(
+            EventHandler::handle_event(Event::Mouse(scroll(MouseEventKind::ScrollDown)), &state),
+            Some(Action::MoveDown)
+        ));
+        assert!(matches!(
+            EventHandler::handle_event(Event::Mouse(scroll(MouseEventKind::ScrollUp)), &state),
+            Some(Action::MoveUp)
+        ));
+    }
 }
@@ -2,23 +2,40 @@ use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
 use crate::app::actions::Action;
 use crate::app::state::*;
+use crate::storage::connections::default_transfer_path;
 
 pub fn global_key_binding(key: KeyEvent) -> Option<Action> {
     match (key.modifiers, key.code) {
+        (KeyModifiers::NONE | KeyModifiers::SHIFT, KeyCode::Char(':')) | (KeyModifiers::CONTROL, KeyCode::Char('p')) => {
+            Some(Action::OpenCommandPalette)
+        }
         (KeyModifiers::CONTROL, KeyCode::Char('c' | 'q')) => Some(Action::Quit),
         (KeyModifiers::NONE, KeyCode::Char('q')) => Some(Action::Quit),
         (KeyModifiers::NONE, KeyCode::Char('?')) | (_, KeyCode::F(1)) => Some(Action::ShowHelp),
         (KeyModifiers::NONE, KeyCode::Tab) => Some(Action::FocusContent),
         (KeyModifiers::SHIFT, KeyCode::BackTab) => Some(Action::FocusSidebar),
         (KeyModifiers::NONE, KeyCode::Esc) => Some(Action::GoBack),
+        (KeyModifiers::CONTROL, KeyCode::Char('y')) => Some(Action::CopyView),
+        (KeyModifiers::NONE | KeyModifiers::SHIFT, KeyCode::Char('#')) => Some(Action::ToggleNumberFormat),
         (KeyModifiers::NONE, KeyCode::Char('1')) => Some(Action::SelectSidebarItem(SidebarItem::Topics)),
         (KeyModifiers::NONE, KeyCode::Char('2')) => Some(Action::SelectSidebarItem(SidebarItem::ConsumerGroups)),
         (KeyModifiers::NONE, KeyCode::Char('3')) => Some(Action::SelectSidebarItem(SidebarItem::Brokers)),
         (KeyModifiers::NONE, KeyCode::Char('4')) => Some(Action::SelectSidebarItem(SidebarItem::Logs)),
+        (KeyModifiers::NONE, KeyCode::Char('5')) => Some(Action::SelectSidebarItem(SidebarItem::Throughput)),
         _ => None,
     }
 }
 
+/// File path prompt for connection import/export, prefilled with the default location
+fn profiles_path_input(title: &str, action: InputAction) -> Action {
+    Action::ShowModal(ModalType::Input {
+        title: title.into(),
+        placeholder: "file path".into(),
+        value: default_transfer_path().display().to_string(),
+        action,
+    })
+}
+
 pub fn help_key_binding(key: KeyEvent) -> Option<Action> {
     matches!(key.code, KeyCode::Esc | KeyCode::Char('?' | 'q') | KeyCode::Enter)
         .then_some(Action::HideHelp)
@@ -31,7 +48,13 @@ pub fn modal_key_binding(key: KeyEvent, modal: &ModalType) -> Option<Action> {
             KeyCode::Esc | KeyCode::Char('n' | 'N') => Some(Action::ModalCancel),
             _ => None,
         },
-        ModalType::Input { value, .. } => match key.code {
+        ModalType::Input { value, action, .. } => match key.code {
+            KeyCode::Char('r') if key.modifiers == KeyModifiers::CONTROL && matches!(action, InputAction::FilterTopics) => {
+                Some(Action::ToggleTopicFilterRegex)
+            }
+            KeyCode::Char('f') if key.modifiers == KeyModifiers::CONTROL && matches!(action, InputAction::FilterTopics) => {
+                Some(Action::ToggleTopicFilterFuzzy)
+            }
             KeyCode::Enter => Some(Action::ModalConfirm),
             KeyCode::Esc => Some(Action::ModalCancel),
             KeyCode::Char(c) => Some(Action::UpdateModalInput(format!("{}{}", value, c))),
@@ -44,126 +67,263 @@ pub fn modal_key_binding(key: KeyEvent, modal: &ModalType) -> Option<Action> {
         ModalType::AddPartitionsForm(f) => add_partitions_form_key(key, f),
         ModalType::AlterConfigForm(f) => alter_config_form_key(key, f),
         ModalType::PurgeTopicForm(f) => purge_topic_form_key(key, f),
+        ModalType::ResetOffsetsForm(f) => reset_offsets_form_key(key, f),
+        ModalType::SeekForm(f) => seek_form_key(key, f),
+        ModalType::History(h) => history_key(key, h),
+        ModalType::LogDetail(d) => log_detail_key(key, d),
+        ModalType::CommandPalette(p) => command_palette_key(key, p),
     }
 }
 
+fn log_detail_key(key: KeyEvent, d: &LogDetailState) -> Option<Action> {
+    let mut s = d.clone();
+    match key.code {
+        KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => return Some(Action::ModalCancel),
+        KeyCode::Char('y') => return Some(Action::CopyLogEntry),
+        KeyCode::Up | KeyCode::Char('k') => s.scroll = d.scroll.saturating_sub(1),
+        KeyCode::Down | KeyCode::Char('j') => s.scroll = d.scroll.saturating_add(1).min(d.max_scroll.get()),
+        KeyCode::Home | KeyCode::Char('g') => s.scroll = 0,
+        _ => return None,
+    }
+    Some(Action::UpdateLogDetail(s))
+}
+
+fn history_key(key: KeyEvent, h: &HistoryBrowserState) -> Option<Action> {
+    let mut s = h.clone();
+    match (key.modifiers, key.code) {
+        (_, KeyCode::Esc) => return Some(Action::ModalCancel),
+        (_, KeyCode::Enter) => return Some(Action::ModalConfirm),
+        (KeyModifiers::CONTROL, KeyCode::Char('l')) => return Some(Action::ClearHistory),
+        (_, KeyCode::Up | KeyCode::Char('k')) => s.selected = h.selected.saturating_sub(1),
+        (_, KeyCode::Down | KeyCode::Char('j')) if h.selected + 1 < h.entries.len() => s.selected += 1,
+        _ => return None,
+    }
+    Some(Action::UpdateHistoryBrowser(s))
+}
+
+/// Enter is resolved by the event handler, which needs the state to run the chosen key.
+fn command_palette_key(key: KeyEvent, p: &CommandPaletteState) -> Option<Action> {
+    let mut s = p.clone();
+    match (key.modifiers, key.code) {
+        (_, KeyCode::Esc) => return Some(Action::ModalCancel),
+        (_, KeyCode::Up) | (KeyModifiers::CONTROL, KeyCode::Char('p' | 'k')) => s.selected = p.selected.saturating_sub(1),
+        (_, KeyCode::Down) | (KeyModifiers::CONTROL, KeyCode::Char('n' | 'j')) => {
+            s.selected = (p.selected + 1).min(p.matches().len().saturating_sub(1));
+        }
+        (KeyModifiers::NONE | KeyModifiers::SHIFT, KeyCode::Char(c)) => {
+            s.query.push(c);
+            s.selected = 0;
+        }
+        (_, KeyCode::Backspace) => {
+            s.query.pop();
+            s.selected = 0;
+        }
+        _ => return None,
+    }
+    Some(Action::UpdateCommandPalette(s))
+}
+
 fn connection_form_key(key: KeyEvent, f: &ConnectionFormState) -> Option<Action> {
     let mut s = f.clone();
     match key.code {
         KeyCode::Esc => return Some(Action::ModalCancel),
         KeyCode::Enter => {
-            let ok = !f.name.is_empty() && !f.brokers.is_empty()
-                && (!f.auth_type.requires_credentials() || (!f.username.is_empty() && !f.password.is_empty()));
+            let ok = !f.name.is_empty() && !f.brokers.is_empty() && f.has_credentials();
             return ok.then_some(Action::ModalConfirm);
         }
-        KeyCode::Tab | KeyCode::Down => s.focused_field = conn_next(&f.focused_field, &f.auth_type),
-        KeyCode::BackTab | KeyCode::Up => s.focused_field = conn_prev(&f.focused_field, &f.auth_type),
+        KeyCode::Tab | KeyCode::Down => s.focused_field = f.focused_field.next(&f.auth_type),
+        KeyCode::BackTab | KeyCode::Up => s.focused_field = f.focused_field.prev(&f.auth_type),
         KeyCode::Left if f.focused_field == ConnectionFormField::AuthType => {
             s.auth_type = f.auth_type.prev();
-            if !s.auth_type.requires_credentials() { s.username.clear(); s.password.clear(); }
+            s.clear_unused_fields();
         }
         KeyCode::Right if f.focused_field == ConnectionFormField::AuthType => {
             s.auth_type = f.auth_type.next();
-            if !s.auth_type.requires_credentials() { s.username.clear(); s.password.clear(); }
+            s.clear_unused_fields();
+        }
+        KeyCode::Left if f.focused_field == ConnectionFormField::SaslMechanism => {
+            s.sasl_mechanism = f.sasl_mechanism.prev();
+        }
+        KeyCode::Right if f.focused_field == ConnectionFormField::SaslMechanism => {
+            s.sasl_mechanism = f.sasl_mechanism.next();
+        }
+        KeyCode::Left | KeyCode::Right if f.focused_field == ConnectionFormField::SecretStorage => {
+            s.secret_storage = f.secret_storage.toggle();
+        }
+        KeyCode::Left | KeyCode::Right if f.focused_field == ConnectionFormField::StartOffset => {
+            s.start_offset = f.start_offset.toggle();
+        }
+        KeyCode::Left if f.focused_field == ConnectionFormField::Acks => s.producer.set_acks(f.producer.acks.prev()),
+        KeyCode::Right if f.focused_field == ConnectionFormField::Acks => s.producer.set_acks(f.producer.acks.next()),
+        KeyCode::Left | KeyCode::Right if f.focused_field == ConnectionFormField::Idempotence => {
+            s.producer.toggle_idempotence();
+        }
+        KeyCode::Left if f.focused_field == ConnectionFormField::Compression => {
+            s.producer.compression = f.producer.compression.prev();
+        }
+        KeyCode::Right if f.focused_field == ConnectionFormField::Compression => {
+            s.producer.compression = f.producer.compression.next();
+        }
+        _ => {
+            let mut cursor = f.cursor;
+            if !edit_text(key, s.field_value_mut(&f.focused_field)?, &mut cursor, |_| true) {
+                return None;
+            }
+            s.cursor = cursor;
         }
-        KeyCode::Char(c) => match f.focused_field {
-            ConnectionFormField::Name => s.name.push(c),
-            ConnectionFormField::Brokers => s.brokers.push(c),
-            ConnectionFormField::ConsumerGroup => s.consumer_group.push(c),
-            ConnectionFormField::Username => s.username.push(c),
-            ConnectionFormField::Password => s.password.push(c),
-            _ => return None,
-        },
-        KeyCode::Backspace => match f.focused_field {
-            ConnectionFormField::Name => { s.name.pop(); }
-            ConnectionFormField::Brokers => { s.brokers.pop(); }
-            ConnectionFormField::ConsumerGroup => { s.consumer_group.pop(); }
-            ConnectionFormField::Username => { s.username.pop(); }
-            ConnectionFormField::Password => { s.password.pop(); }
-            _ => return None,
-        },
-        _ => return None,
-    }
-    Some(Action::UpdateConnectionForm(s))
-}
-
-fn conn_next(f: &ConnectionFormField, auth: &AuthType) -> ConnectionFormField {
-    match f {
-        ConnectionFormField::Name => ConnectionFormField::Brokers,
-        ConnectionFormField::Brokers => ConnectionFormField::ConsumerGroup,
-        ConnectionFormField::ConsumerGroup => ConnectionFormField::AuthType,
-        ConnectionFormField::AuthType => if auth.requires_credentials() { ConnectionFormField::Username } else { ConnectionFormField::Name },
-        ConnectionFormField::Username => ConnectionFormField::Password,
-        ConnectionFormField::Password => ConnectionFormField::Name,
     }
-}
-
-fn conn_prev(f: &ConnectionFormField, auth: &AuthType) -> ConnectionFormField {
-    match f {
-        ConnectionFormField::Name => if auth.requires_credentials() { ConnectionFormField::Password } else { ConnectionFormField::AuthType },
-        ConnectionFormField::Brokers => ConnectionFormField::Name,
-        ConnectionFormField::ConsumerGroup => ConnectionFormField::Brokers,
-        ConnectionFormField::AuthType => ConnectionFormField::ConsumerGroup,
-        ConnectionFormField::Username => ConnectionFormField::AuthType,
-        ConnectionFormField::Password => ConnectionFormField::Username,
+    if s.focused_field != f.focused_field {
+        s.cursor = TextCursor::default();
     }
+    Some(Action::UpdateConnectionForm(s))
 }
 
 fn topic_form_key(key: KeyEvent, f: &TopicCreateFormState) -> Option<Action> {
     let mut s = f.clone();
+    if f.focused_field == TopicCreateFormField::Configs
+        && edit_pairs(key, &mut s.configs, &mut s.config_index, &mut s.config_value_focused)
+    {
+        return Some(Action::UpdateTopicCreateForm(s));
+    }
     match key.code {
         KeyCode::Esc => return Some(Action::ModalCancel),
         KeyCode::Enter => return (!f.name.is_empty()).then_some(Action::ModalConfirm),
         KeyCode::Tab | KeyCode::Down => s.focused_field = match f.focused_field {
             TopicCreateFormField::Name => TopicCreateFormField::Partitions,
             TopicCreateFormField::Partitions => TopicCreateFormField::ReplicationFactor,
-            TopicCreateFormField::ReplicationFactor => TopicCreateFormField::Name,
+            TopicCreateFormField::ReplicationFactor => TopicCreateFormField::Configs,
+            TopicCreateFormField::Configs => TopicCreateFormField::Name,
         },
         KeyCode::BackTab | KeyCode::Up => s.focused_field = match f.focused_field {
-            TopicCreateFormField::Name => TopicCreateFormField::ReplicationFactor,
+            TopicCreateFormField::Name => TopicCreateFormField::Configs,
             TopicCreateFormField::Partitions => TopicCreateFormField::Name,
             TopicCreateFormField::ReplicationFactor => TopicCreateFormField::Partitions,
+            TopicCreateFormField::Configs => TopicCreateFormField::ReplicationFactor,
         },
-        KeyCode::Char(c) => match f.focused_field {
-            TopicCreateFormField::Name => s.name.push(c),
-            TopicCreateFormField::Partitions if c.is_ascii_digit() => s.partitions.push(c),
-            TopicCreateFormField::ReplicationFactor if c.is_ascii_digit() => s.replication_factor.push(c),
-            _ => return None,
-        },
-        KeyCode::Backspace => match f.focused_field {
-            TopicCreateFormField::Name => { s.name.pop(); }
-            TopicCreateFormField::Partitions => { s.partitions.pop(); }
-            TopicCreateFormField::ReplicationFactor => { s.replication_factor.pop(); }
-        },
-        _ => return None,
+        _ => {
+            let edited = match f.focused_field {
+                TopicCreateFormField::Name => edit_text(key, &mut s.name, &mut s.cursor, |_| true),
+                TopicCreateFormField::Partitions => {
+                    edit_text(key, &mut s.partitions, &mut s.cursor, |c| c.is_ascii_digit())
+                }
+                TopicCreateFormField::ReplicationFactor => {
+                    edit_text(key, &mut s.replication_factor, &mut s.cursor, |c| c.is_ascii_digit())
+                }
+                TopicCreateFormField::Configs => false,
+            };
+            if !edited {
+                return None;
+            }
+        }
+    }
+    if s.focused_field != f.focused_field {
+        s.cursor = TextCursor::default();
     }
     Some(Action::UpdateTopicCreateForm(s))
 }
 
 fn produce_form_key(key: KeyEvent, f: &ProduceFormState) -> Option<Action> {
     let mut s = f.clone();
-    match key.code {
-        KeyCode::Esc => return Some(Action::ModalCancel),
-        KeyCode::Enter => return (!f.value.is_empty()).then_some(Action::ModalConfirm),
-        KeyCode::Tab | KeyCode::Down | KeyCode::Up | KeyCode::BackTab => {
-            s.focused_field = match f.focused_field {
-                ProduceFormField::Key => ProduceFormField::Value,
-                ProduceFormField::Value => ProduceFormField::Key,
+    if f.focused_field == ProduceFormField::Headers
+        && edit_pairs(key, &mut s.headers, &mut s.header_index, &mut s.header_value_focused)
+    {
+        return Some(Action::UpdateProduceForm(s));
+    }
+    match (key.modifiers, key.code) {
+        (_, KeyCode::Esc) => return Some(Action::ModalCancel),
+        // The typed value is multi-line: plain Enter starts a new line, Ctrl+S (or Ctrl/Alt+Enter) sends
+        (KeyModifiers::NONE | KeyModifiers::SHIFT, KeyCode::Enter)
+            if f.focused_field == ProduceFormField::Value && !f.value_from_file =>
+        {
+            s.cursor.insert(&mut s.value, '\n')
+        }
+        (_, KeyCode::Enter) | (KeyModifiers::CONTROL, KeyCode::Char('s')) => {
+            return (!f.value.is_empty()).then_some(Action::ModalConfirm)
+        }
+        (_, KeyCode::Tab) => s.focused_field = f.focused_field.next(),
+        (_, KeyCode::BackTab) => s.focused_field = f.focused_field.prev(),
+        (_, KeyCode::Up) => s.focused_field = f.focused_field.prev(),
+        (_, KeyCode::Down) => s.focused_field = f.focused_field.next(),
+        (KeyModifiers::CONTROL, KeyCode::Char('f')) if f.focused_field == ProduceFormField::Value => {
+            s.value_from_file = !f.value_from_file;
+        }
+        _ => {
+            let edited = match f.focused_field {
+                ProduceFormField::Key => edit_text(key, &mut s.key, &mut s.cursor, |_| true),
+                ProduceFormField::Value => edit_text(key, &mut s.value, &mut s.cursor, |_| true),
+                ProduceFormField::Partition => edit_text(key, &mut s.partition, &mut s.cursor, |c| c.is_ascii_digit()),
+                ProduceFormField::Count => edit_text(key, &mut s.count, &mut s.cursor, |c| c.is_ascii_digit()),
+                ProduceFormField::Headers => false,
             };
+            if !edited {
+                return None;
+            }
         }
-        KeyCode::Char(c) => match f.focused_field {
-            ProduceFormField::Key => s.key.push(c),
-            ProduceFormField::Value => s.value.push(c),
-        },
-        KeyCode::Backspace => match f.focused_field {
-            ProduceFormField::Key => { s.key.pop(); }
-            ProduceFormField::Value => { s.value.pop(); }
-        },
-        _ => return None,
+    }
+    if s.focused_field != f.focused_field {
+        s.cursor = TextCursor::default();
     }
     Some(Action::UpdateProduceForm(s))
 }
 
+/// Editing keys for a single text field: typing and Backspace/Delete act at the cursor, which
+/// ←/→/Home/End move. Chars rejected by `accept` are ignored. Returns `false` for other keys.
+fn edit_text(key: KeyEvent, text: &mut String, cursor: &mut TextCursor, accept: impl Fn(char) -> bool) -> bool {
+    match key.code {
+        KeyCode::Char(c) if accept(c) => cursor.insert(text, c),
+        KeyCode::Backspace => cursor.backspace(text),
+        KeyCode::Delete => cursor.delete(text),
+        KeyCode::Left => cursor.left(text),
+        KeyCode::Right => cursor.right(text),
+        KeyCode::Home => cursor.home(text),
+        KeyCode::End => cursor.end(),
+        _ => return false,
+    }
+    true
+}
+
+/// Editing keys for a name/value pair list (produce headers, topic configs).
+///
+/// ↑/↓ select a row, ←/→ switch between name and value, Ctrl+N adds a row, Ctrl+D
+/// removes one, and typing into an empty list starts a row. Returns `false` for keys
+/// that aren't edits (such as ↑ on the first row), so the form can move focus instead.
+fn edit_pairs(key: KeyEvent, pairs: &mut Vec<(String, String)>, index: &mut usize, value_focused: &mut bool) -> bool {
+    match (key.modifiers, key.code) {
+        (_, KeyCode::Up) if *index > 0 => *index -= 1,
+        (_, KeyCode::Down) if *index + 1 < pairs.len() => *index += 1,
+        (_, KeyCode::Left | KeyCode::Right) => *value_focused = !*value_focused,
+        (KeyModifiers::CONTROL, KeyCode::Char('n')) => {
+            pairs.push((String::new(), String::new()));
+            *index = pairs.len() - 1;
+            *value_focused = false;
+        }
+        (KeyModifiers::CONTROL, KeyCode::Char('d')) => {
+            if !pairs.is_empty() {
+                pairs.remove(*index);
+                *index = (*index).min(pairs.len().saturating_sub(1));
+            }
+        }
+        (_, KeyCode::Char(c)) => {
+            if pairs.is_empty() {
+                pairs.push((String::new(), String::new()));
+                *index = 0;
+            }
+            match pairs.get_mut(*index) {
+                Some((_, value)) if *value_focused => value.push(c),
+                Some((name, _)) => name.push(c),
+                None => return false,
+            }
+        }
+        (_, KeyCode::Backspace) => match pairs.get_mut(*index) {
+            Some((_, value)) if *value_focused => { value.pop(); }
+            Some((name, _)) => { name.pop(); }
+            None => return false,
+        },
+        _ => return false,
+    }
+    true
+}
+
 fn add_partitions_form_key(key: KeyEvent, f: &AddPartitionsFormState) -> Option<Action> {
     let mut s = f.clone();
     match key.code {
@@ -182,12 +342,24 @@ fn add_partitions_form_key(key: KeyEvent, f: &AddPartitionsFormState) -> Option<
 fn alter_config_form_key(key: KeyEvent, f: &AlterConfigFormState) -> Option<Action> {
     let mut s = f.clone();
 
-    if s.editing {
+    if let Some(selected) = s.review {
+        match key.code {
+            KeyCode::Enter => return Some(Action::ModalConfirm),
+            // Back to the editor, edits intact
+            KeyCode::Esc => s.review = None,
+            KeyCode::Up | KeyCode::Char('k') => s.review = Some(selected.saturating_sub(1)),
+            KeyCode::Down | KeyCode::Char('j') => s.review = Some((selected + 1).min(f.changes().len().saturating_sub(1))),
+            KeyCode::Char('y') => return Some(Action::CopyConfigChanges { all: false }),
+            KeyCode::Char('Y') => return Some(Action::CopyConfigChanges { all: true }),
+            _ => return None,
+        }
+    } else if s.editing {
         match key.code {
             KeyCode::Enter => {
-                if let Some((_, v, m)) = s.configs.get_mut(s.selected_index) {
+                if let Some((k, v, m)) = s.configs.get_mut(s.selected_index) {
                     *v = std::mem::take(&mut s.edit_value);
                     *m = true;
+                    s.deleted.remove(k.as_str());
                 }
                 s.editing = false;
             }
@@ -199,12 +371,7 @@ fn alter_config_form_key(key: KeyEvent, f: &AlterConfigFormState) -> Option<Acti
     } else {
         match key.code {
             KeyCode::Esc => return Some(Action::ModalCancel),
-            KeyCode::Enter => {
-                if s.configs.iter().any(|(_, _, m)| *m) {
-                    return Some(Action::ModalConfirm);
-                }
-                return None;
-            }
+            KeyCode::Enter if f.has_changes() => s.review = Some(0),
             KeyCode::Up | KeyCode::Char('k') => s.selected_index = s.selected_index.saturating_sub(1),
             KeyCode::Down | KeyCode::Char('j') => {
                 if s.selected_index + 1 < s.configs.len() { s.selected_index += 1; }
@@ -215,6 +382,7 @@ fn alter_config_form_key(key: KeyEvent, f: &AlterConfigFormState) -> Option<Acti
                     s.edit_value = v.clone();
                 }
             }
+            KeyCode::Char('d') => s.toggle_deleted(),
             _ => return None,
         }
     }
@@ -234,6 +402,108 @@ fn purge_topic_form_key(key: KeyEvent, f: &PurgeTopicFormState) -> Option<Action
     Some(Action::UpdatePurgeTopicForm(s))
 }
 
+fn reset_offsets_form_key(key: KeyEvent, f: &ResetOffsetsFormState) -> Option<Action> {
+    let mut s = f.clone();
+    match key.code {
+        KeyCode::Esc => return Some(Action::ModalCancel),
+        KeyCode::Enter => return Some(Action::ModalConfirm),
+        KeyCode::Tab | KeyCode::Down => s.focused_field = match f.focused_field {
+            ResetOffsetsFormField::Topic => ResetOffsetsFormField::Partition,
+            ResetOffsetsFormField::Partition => ResetOffsetsFormField::Mode,
+            ResetOffsetsFormField::Mode if f.mode.requires_value() => ResetOffsetsFormField::Value,
+            ResetOffsetsFormField::Mode | ResetOffsetsFormField::Value => ResetOffsetsFormField::Topic,
+        },
+        KeyCode::BackTab | KeyCode::Up => s.focused_field = match f.focused_field {
+            ResetOffsetsFormField::Topic if f.mode.requires_value() => ResetOffsetsFormField::Value,
+            ResetOffsetsFormField::Topic => ResetOffsetsFormField::Mode,
+            ResetOffsetsFormField::Partition => ResetOffsetsFormField::Topic,
+            ResetOffsetsFormField::Mode => ResetOffsetsFormField::Partition,
+            ResetOffsetsFormField::Value => ResetOffsetsFormField::Mode,
+        },
+        KeyCode::Left if f.focused_field == ResetOffsetsFormField::Topic && !f.topics.is_empty() => {
+            s.topic_index = (f.topic_index + f.topics.len() - 1) % f.topics.len();
+        }
+        KeyCode::Right if f.focused_field == ResetOffsetsFormField::Topic && !f.topics.is_empty() => {
+            s.topic_index = (f.topic_index + 1) % f.topics.len();
+        }
+        KeyCode::Left if f.focused_field == ResetOffsetsFormField::Mode => {
+            s.mode = f.mode.prev();
+            s.value.clear();
+        }
+        KeyCode::Right if f.focused_field == ResetOffsetsFormField::Mode => {
+            s.mode = f.mode.next();
+            s.value.clear();
+        }
+        KeyCode::Char(c) => match f.focused_field {
+            ResetOffsetsFormField::Partition if c.is_ascii_digit() => s.partition.push(c),
+            ResetOffsetsFormField::Value => s.value.push(c),
+            _ => return None,
+        },
+        KeyCode::Backspace => match f.focused_field {
+            ResetOffsetsFormField::Partition => { s.partition.pop(); }
+            ResetOffsetsFormField::Value => { s.value.pop(); }
+            _ => return None,
+        },
+        _ => return None,
+    }
+    Some(Action::UpdateResetOffsetsForm(s))
+}
+
+fn seek_form_key(key: KeyEvent, f: &SeekFormState) -> Option<Action> {
+    let mut s = f.clone();
+    match key.code {
+        KeyCode::Esc => return Some(Action::ModalCancel),
+        KeyCode::Enter => return Some(Action::ModalConfirm),
+        KeyCode::Tab | KeyCode::Down => s.focused_field = match f.focused_field {
+            SeekFormField::Mode if f.mode.requires_value() => SeekFormField::Value,
+            SeekFormField::Mode | SeekFormField::Value => SeekFormField::Partition,
+            SeekFormField::Partition => SeekFormField::Mode,
+        },
+        KeyCode::BackTab | KeyCode::Up => s.focused_field = match f.focused_field {
+            SeekFormField::Mode => SeekFormField::Partition,
+            SeekFormField::Value => SeekFormField::Mode,
+            SeekFormField::Partition if f.mode.requires_value() => SeekFormField::Value,
+            SeekFormField::Partition => SeekFormField::Mode,
+        },
+        KeyCode::Left if f.focused_field == SeekFormField::Mode => {
+            s.mode = f.mode.prev();
+            s.value.clear();
+        }
+        KeyCode::Right if f.focused_field == SeekFormField::Mode => {
+            s.mode = f.mode.next();
+            s.value.clear();
+        }
+        KeyCode::Char(c) => match f.focused_field {
+            SeekFormField::Partition if c.is_ascii_digit() => s.partition.push(c),
+            SeekFormField::Value => s.value.push(c),
+            _ => return None,
+        },
+        KeyCode::Backspace => match f.focused_field {
+            SeekFormField::Partition => { s.partition.pop(); }
+            SeekFormField::Value => { s.value.pop(); }
+            _ => return None,
+        },
+        _ => return None,
+    }
+    Some(Action::UpdateSeekForm(s))
+}
+
+/// One of each screen with its name, for checks that cover every screen's bindings
+pub fn every_screen() -> [(&'static str, Screen); 10] {
+    [
+        ("Welcome", Screen::Welcome),
+        ("Topics", Screen::Topics),
+        ("Topic Details", Screen::TopicDetails { topic_name: String::new() }),
+        ("Messages", Screen::Messages { topic_name: String::new() }),
+        ("Consumer Groups", Screen::ConsumerGroups),
+        ("Group Details", Screen::ConsumerGroupDetails { group_id: String::new() }),
+        ("Brokers", Screen::Brokers),
+        ("Broker Details", Screen::BrokerDetails { broker_id: 0 }),
+        ("Throughput", Screen::Throughput),
+        ("Logs", Screen::Logs),
+    ]
+}
+
 pub fn screen_key_binding(screen: &Screen, key: KeyEvent, sidebar_focused: bool) -> Option<Action> {
     if sidebar_focused {
         return match key.code {
@@ -261,15 +531,27 @@ pub fn screen_key_binding(screen: &Screen, key: KeyEvent, sidebar_focused: bool)
             KeyCode::Enter => Some(Action::Select),
             KeyCode::Char('n') => Some(Action::ShowModal(ModalType::ConnectionForm(Default::default()))),
             KeyCode::Char('d') => Some(Action::RequestDeleteConnection),
+            KeyCode::Char('x') => Some(profiles_path_input("Export Connections", InputAction::ExportProfiles { include_secrets: false })),
+            KeyCode::Char('X') => Some(profiles_path_input("Export Connections (with passwords)", InputAction::ExportProfiles { include_secrets: true })),
+            KeyCode::Char('i') => Some(profiles_path_input("Import Connections", InputAction::ImportProfiles { overwrite: false })),
+            KeyCode::Char('I') => Some(profiles_path_input("Import Connections (overwrite)", InputAction::ImportProfiles { overwrite: true })),
             _ => None,
         },
         Screen::Topics => match (key.modifiers, key.code) {
             (KeyModifiers::NONE, KeyCode::Enter | KeyCode::Char('m')) => Some(Action::Select),
             (KeyModifiers::NONE, KeyCode::Char('i')) => Some(Action::RequestViewTopicDetails),
-            (KeyModifiers::NONE, KeyCode::Char('n')) => Some(Action::ShowModal(ModalType::TopicCreateForm(Default::default()))),
+            (KeyModifiers::NONE, KeyCode::Char('n')) => Some(Action::RequestCreateTopic),
             (KeyModifiers::NONE, KeyCode::Char('/')) => Some(Action::ShowModal(ModalType::Input {
                 title: "Filter".into(), placeholder: "".into(), value: String::new(), action: InputAction::FilterTopics,
             })),
+            (KeyModifiers::NONE, KeyCode::Char('c')) => Some(Action::LoadTopicMessageCounts),
+            (KeyModifiers::NONE, KeyCode::Char(' ')) => Some(Action::ToggleTopicMark),
+            (KeyModifiers::NONE, KeyCode::Char('d')) => Some(Action::RequestDeleteTopics),
+            (KeyModifiers::NONE, KeyCode::Char('b')) => Some(Action::ToggleTopicBookmark),
+            (KeyModifiers::SHIFT, KeyCode::Char('B')) => Some(Action::ToggleTopicBookmarksOnly),
+            (KeyModifiers::NONE, KeyCode::Char('u')) => Some(Action::ToggleUnderReplicatedOnly),
+            (KeyModifiers::NONE, KeyCode::Char('s')) => Some(Action::CycleTopicSort),
+            (KeyModifiers::SHIFT, KeyCode::Char('S')) => Some(Action::ReverseTopicSort),
             (KeyModifiers::CONTROL, KeyCode::Char('l')) => Some(Action::ClearTopicFilter),
             (KeyModifiers::CONTROL, KeyCode::Char('r')) | (_, KeyCode::F(5)) => Some(Action::FetchTopics),
             _ => None,
@@ -279,27 +561,46 @@ pub fn screen_key_binding(screen: &Screen, key: KeyEvent, sidebar_focused: bool)
             match key.code {
                 KeyCode::Tab | KeyCode::Left | KeyCode::Char('h') | KeyCode::Right | KeyCode::Char('l') => Some(Action::SwitchTopicDetailTab),
                 KeyCode::Char('m') => Some(Action::ViewTopicMessages(topic_name.clone())),
-                KeyCode::Char('d') => Some(Action::ShowModal(ModalType::Confirm {
-                    title: "Delete Topic".into(),
-                    message: format!("Delete '{}'?", topic_name),
-                    action: ConfirmAction::DeleteTopic(topic_name.clone()),
-                })),
+                KeyCode::Char('d') => Some(Action::RequestDeleteTopic(topic_name.clone())),
                 // 'p' - add partitions (handled in handler with state access)
                 // 'e' - edit config (handled in handler with state access)
                 // 'x' - purge (handled in handler with state access)
+                KeyCode::Char('y') => Some(Action::CopyTopicConfigValue),
+                KeyCode::Char('w') => Some(Action::RefreshTopicWatermarks(topic_name.clone())),
                 KeyCode::F(5) => Some(Action::ViewTopicDetails(topic_name.clone())),
                 _ => None,
             }
         }
         Screen::Messages { topic_name } => match (key.modifiers, key.code) {
             (KeyModifiers::NONE, KeyCode::Char('v') | KeyCode::Enter) => Some(Action::ToggleMessageDetail),
+            (KeyModifiers::SHIFT, KeyCode::Char('V')) => Some(Action::ToggleJsonTree),
+            (KeyModifiers::SHIFT, KeyCode::Char('J')) => Some(Action::TogglePrettyJson),
+            (KeyModifiers::NONE, KeyCode::Char('y')) => Some(Action::CopyMessageValue),
+            (KeyModifiers::SHIFT, KeyCode::Char('Y')) => Some(Action::CopyMessageKey),
+            (KeyModifiers::NONE, KeyCode::Char('x')) => Some(Action::CyclePayloadView),
+            (KeyModifiers::NONE, KeyCode::Char('z')) => Some(Action::ToggleDecompress),
+            (KeyModifiers::NONE, KeyCode::Char('s')) => Some(Action::ToggleMessageOrder),
+            (KeyModifiers::NONE, KeyCode::Char('t')) => Some(Action::CycleTimestampFormat),
+            (KeyModifiers::SHIFT, KeyCode::Char('T')) => Some(Action::RequestProtobufType),
+            (KeyModifiers::NONE, KeyCode::Char('/')) => Some(Action::ShowModal(ModalType::Input {
+                title: "Search".into(), placeholder: "key or value".into(), value: String::new(), action: InputAction::SearchMessages,
+            })),
+            (KeyModifiers::NONE, KeyCode::Char('n')) => Some(Action::NextSearchMatch),
+            (KeyModifiers::SHIFT, KeyCode::Char('N')) => Some(Action::PrevSearchMatch),
+            (KeyModifiers::CONTROL, KeyCode::Char('j')) => Some(Action::ScrollMessageDetailDown),
+            (KeyModifiers::CONTROL, KeyCode::Char('k')) => Some(Action::ScrollMessageDetailUp),
             (KeyModifiers::NONE, KeyCode::Char('p')) => Some(Action::ShowModal(ModalType::ProduceForm(ProduceFormState {
                 topic: topic_name.clone(), ..Default::default()
             }))),
-            (KeyModifiers::CONTROL, KeyCode::Char('r')) | (_, KeyCode::F(5)) => Some(Action::FetchMessages {
-                topic: topic_name.clone(), offset_mode: OffsetMode::Latest, partition: None,
-            }),
+            // Ctrl+R / F5 - refresh (handled in handler with state access)
+            (KeyModifiers::NONE, KeyCode::Char('o')) => Some(Action::RequestSeek),
+            (KeyModifiers::SHIFT, KeyCode::Char('L')) => Some(Action::CycleFetchLimit),
+            (_, KeyCode::Char('[')) => Some(Action::PageMessages { topic: topic_name.clone(), older: true }),
+            (_, KeyCode::Char(']')) => Some(Action::PageMessages { topic: topic_name.clone(), older: false }),
+            (KeyModifiers::SHIFT, KeyCode::Char('H')) => Some(Action::ShowHistory),
             (KeyModifiers::CONTROL, KeyCode::Char('l')) => Some(Action::ClearMessages),
+            (KeyModifiers::NONE, KeyCode::Char('e')) => Some(Action::ExportMessages(ExportFormat::Json)),
+            (KeyModifiers::SHIFT, KeyCode::Char('E')) => Some(Action::ExportMessages(ExportFormat::Csv)),
             _ => None,
         },
         Screen::ConsumerGroups => match (key.modifiers, key.code) {
@@ -307,38 +608,195 @@ pub fn screen_key_binding(screen: &Screen, key: KeyEvent, sidebar_focused: bool)
             (KeyModifiers::NONE, KeyCode::Char('/')) => Some(Action::ShowModal(ModalType::Input {
                 title: "Filter".into(), placeholder: "".into(), value: String::new(), action: InputAction::FilterConsumerGroups,
             })),
+            (KeyModifiers::NONE, KeyCode::Char('b')) => Some(Action::ToggleGroupBookmark),
+            (KeyModifiers::SHIFT, KeyCode::Char('B')) => Some(Action::ToggleGroupBookmarksOnly),
+            (KeyModifiers::NONE, KeyCode::Char('c')) => Some(Action::ToggleGroupConsumersOnly),
+            (KeyModifiers::NONE, KeyCode::Char('s')) => Some(Action::CycleConsumerGroupSort),
+            (KeyModifiers::SHIFT, KeyCode::Char('S')) => Some(Action::ReverseConsumerGroupSort),
             (KeyModifiers::CONTROL, KeyCode::Char('l')) => Some(Action::ClearConsumerGroupFilter),
             (KeyModifiers::CONTROL, KeyCode::Char('r')) | (_, KeyCode::F(5)) => Some(Action::FetchConsumerGroups),
             _ => None,
         },
         Screen::ConsumerGroupDetails { group_id } => match key.code {
             KeyCode::Tab | KeyCode::Left | KeyCode::Char('h') | KeyCode::Right | KeyCode::Char('l') => Some(Action::SwitchConsumerGroupDetailTab),
+            KeyCode::Char('r') => Some(Action::RequestResetOffsets),
             KeyCode::F(5) => Some(Action::ViewConsumerGroupDetails(group_id.clone())),
             _ => None,
         },
         Screen::Brokers => match (key.modifiers, key.code) {
             (KeyModifiers::CONTROL, KeyCode::Char('r')) | (_, KeyCode::F(5)) => Some(Action::FetchBrokers),
+            (KeyModifiers::NONE, KeyCode::Char('y')) => Some(Action::CopyBrokerAddress),
+            _ => None,
+        },
+        Screen::BrokerDetails { broker_id } => match (key.modifiers, key.code) {
+            (KeyModifiers::CONTROL, KeyCode::Char('r')) | (_, KeyCode::F(5)) => {
+                Some(Action::FetchBrokerDetails(*broker_id))
+            }
+            _ => None,
+        },
+        Screen::Throughput => match (key.modifiers, key.code) {
+            (KeyModifiers::CONTROL, KeyCode::Char('r')) | (_, KeyCode::F(5)) => Some(Action::RefreshThroughput),
             _ => None,
         },
         Screen::Logs => match (key.modifiers, key.code) {
             (KeyModifiers::NONE, KeyCode::Char('c')) => Some(Action::ClearLogs),
-            (KeyModifiers::NONE, KeyCode::Char('f') | KeyCode::Char('/')) => Some(Action::CycleLogFilter),
+            (KeyModifiers::NONE, KeyCode::Char('e')) => Some(Action::ExportLogs),
+            (KeyModifiers::NONE, KeyCode::Char('v')) => Some(Action::ViewLogEntry),
+            (KeyModifiers::NONE, KeyCode::Char('y')) => Some(Action::CopyLogEntry),
+            (KeyModifiers::NONE, KeyCode::Char('f')) => Some(Action::CycleLogFilter),
+            (KeyModifiers::NONE, KeyCode::Char('/')) => Some(Action::ShowModal(ModalType::Input {
+                title: "Filter logs".into(), placeholder: "text in the message".into(), value: String::new(), action: InputAction::FilterLogs,
+            })),
+            (KeyModifiers::CONTROL, KeyCode::Char('l')) => Some(Action::FilterLogs(String::new())),
             _ => None,
         },
     }
 }
 
+/// Palette entry running whatever `key` is bound to, with SHIFT set for uppercase chars
+/// the way terminals report them.
+fn command(label: &'static str, code: KeyCode) -> PaletteCommand {
+    let modifiers = match code {
+        KeyCode::Char(c) if c.is_uppercase() => KeyModifiers::SHIFT,
+        _ => KeyModifiers::NONE,
+    };
+    PaletteCommand { label, key: KeyEvent::new(code, modifiers) }
+}
+
+fn ctrl(label: &'static str, c: char) -> PaletteCommand {
+    PaletteCommand { label, key: KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL) }
+}
+
+/// Commands offered by the command palette on `screen`. Each runs through the same
+/// bindings as its key, so the palette can't drift from what the keys do.
+pub fn palette_commands(screen: &Screen) -> Vec<PaletteCommand> {
+    use KeyCode::{Char, Enter, Right, F};
+
+    let mut commands = match screen {
+        Screen::Welcome => vec![
+            command("Connect to selected profile", Enter),
+            command("New connection", Char('n')),
+            command("Delete connection", Char('d')),
+            command("Export connections", Char('x')),
+            command("Export connections with passwords", Char('X')),
+            command("Import connections", Char('i')),
+            command("Import connections, overwriting", Char('I')),
+        ],
+        Screen::Topics => vec![
+            command("Open topic messages", Char('m')),
+            command("Topic details", Char('i')),
+            command("Create topic", Char('n')),
+            command("Filter topics", Char('/')),
+            ctrl("Clear topic filter", 'l'),
+            command("Load message counts", Char('c')),
+            command("Check topic for bulk delete", Char(' ')),
+            command("Delete checked or selected topics", Char('d')),
+            command("Bookmark topic", Char('b')),
+            command("Show only bookmarked topics", Char('B')),
+            command("Show only under-replicated topics", Char('u')),
+            command("Cycle sort column", Char('s')),
+            command("Reverse sort", Char('S')),
+            command("Refresh topics", F(5)),
+        ],
+        Screen::TopicDetails { .. } => vec![
+            command("Switch tab", Right),
+            command("Open topic messages", Char('m')),
+            command("Delete topic", Char('d')),
+            command("Add partitions", Char('p')),
+            command("Edit configuration", Char('e')),
+            command("Purge topic", Char('x')),
+            command("Copy config value", Char('y')),
+            command("Refresh watermarks", Char('w')),
+            command("Refresh topic details", F(5)),
+        ],
+        Screen::Messages { .. } => vec![
+            command("Toggle message detail", Char('v')),
+            command("Toggle pretty JSON", Char('J')),
+            command("Browse value as JSON tree", Char('V')),
+            command("Cycle payload view", Char('x')),
+            command("Toggle decompression", Char('z')),
+            command("Set protobuf type", Char('T')),
+            command("Copy message value", Char('y')),
+            command("Copy message key", Char('Y')),
+            command("Search loaded messages", Char('/')),
+            command("Next search match", Char('n')),
+            command("Previous search match", Char('N')),
+            command("Find in topic", Char('f')),
+            command("Reverse message order", Char('s')),
+            command("Cycle timestamp format", Char('t')),
+            command("Seek to offset or time", Char('o')),
+            command("Produce message", Char('p')),
+            command("Start or stop live consuming", Char('c')),
+            command("Export messages as JSON", Char('e')),
+            command("Export messages as CSV", Char('E')),
+            command("Cycle fetch limit", Char('L')),
+            command("Fetch older page", Char('[')),
+            command("Fetch newer page", Char(']')),
+            command("Show history", Char('H')),
+            ctrl("Clear messages", 'l'),
+            command("Refresh messages", F(5)),
+        ],
+        Screen::ConsumerGroups => vec![
+            command("Consumer group details", Enter),
+            command("Filter consumer groups", Char('/')),
+            ctrl("Clear group filter", 'l'),
+            command("Bookmark consumer group", Char('b')),
+            command("Show only bookmarked groups", Char('B')),
+            command("Show only consumer groups", Char('c')),
+            command("Cycle sort column", Char('s')),
+            command("Reverse sort", Char('S')),
+            command("Refresh consumer groups", F(5)),
+        ],
+        Screen::ConsumerGroupDetails { .. } => vec![
+            command("Switch tab", Right),
+            command("Reset offsets", Char('r')),
+            command("Refresh group details", F(5)),
+        ],
+        Screen::Brokers => vec![
+            command("Copy broker address", Char('y')),
+            command("Refresh brokers", F(5)),
+        ],
+        Screen::BrokerDetails { .. } => vec![command("Refresh broker configuration", F(5))],
+        Screen::Throughput => vec![command("Sample throughput now", F(5))],
+        Screen::Logs => vec![
+            command("View log entry", Char('v')),
+            command("Copy log entry", Char('y')),
+            command("Cycle log level filter", Char('f')),
+            command("Filter logs by text", Char('/')),
+            ctrl("Clear log text filter", 'l'),
+            command("Export logs", Char('e')),
+            command("Clear logs", Char('c')),
+        ],
+    };
+
+    if *screen != Screen::Welcome {
+        commands.extend([
+            command("Go to topics", Char('1')),
+            command("Go to consumer groups", Char('2')),
+            command("Go to brokers", Char('3')),
+            command("Go to logs", Char('4')),
+            command("Go to throughput", Char('5')),
+            ctrl("Copy table as Markdown", 'y'),
+            command("Toggle exact numbers", Char('#')),
+        ]);
+    }
+    commands.extend([command("Show help", Char('?')), command("Quit", Char('q'))]);
+    commands
+}
+
 pub fn get_help_text(screen: &Screen) -> Vec<(&'static str, &'static str)> {
-    let mut h = vec![("q", "Quit"), ("?", "Help"), ("Tab", "Switch"), ("Esc", "Back")];
+    let mut h = vec![("q", "Quit"), ("?", "Help"), (":", "Commands"), ("Tab", "Switch"), ("Esc", "Back")];
     h.extend(match screen {
-        Screen::Welcome => vec![("Enter", "Connect"), ("n", "New"), ("d", "Delete")],
-        Screen::Topics => vec![("j/k", "Nav"), ("m", "Messages"), ("i", "Details"), ("n", "New"), ("/", "Filter")],
-        Screen::Messages { .. } => vec![("j/k", "Nav"), ("v", "Detail"), ("p", "Produce"), ("F5", "Refresh")],
-        Screen::ConsumerGroups => vec![("j/k", "Nav"), ("Enter", "Details"), ("/", "Filter"), ("F5", "Refresh")],
-        Screen::TopicDetails { .. } => vec![("Tab", "Switch"), ("m", "Messages"), ("d", "Delete"), ("p", "Add Parts"), ("e", "Config"), ("x", "Purge")],
-        Screen::ConsumerGroupDetails { .. } => vec![("Tab", "Switch"), ("F5", "Refresh")],
-        Screen::Brokers => vec![("F5", "Refresh")],
-        Screen::Logs => vec![("j/k", "Nav"), ("c", "Clear"), ("f", "Filter")],
+        Screen::Welcome => vec![("Enter", "Connect"), ("n", "New"), ("d", "Delete"), ("x/X", "Export"), ("i/I", "Import")],
+        Screen::Topics => vec![("j/k", "Nav"), ("m", "Messages"), ("i", "Details"), ("n", "New"), ("Space", "Check"), ("d", "Delete"), ("c", "Counts"), ("s/S", "Sort"), ("b/B", "Bookmark"), ("u", "Under-replicated"), ("/", "Filter")],
+        Screen::Messages { .. } => vec![("j/k", "Nav"), ("v", "Detail"), ("J", "Pretty JSON"), ("V", "JSON tree"), ("x", "Hex/Raw"), ("z", "Decompress"), ("T", "Protobuf"), ("y/Y", "Copy"), ("/", "Search"), ("n/N", "Next/Prev"), ("f", "Find"), ("s", "Sort"), ("t", "Time format"), ("o", "Seek"), ("p", "Produce"), ("c", "Live"), ("e/E", "Export"), ("L", "Limit"), ("[/]", "Older/Newer"), ("H", "History"), ("F5", "Refresh")],
+        Screen::ConsumerGroups => vec![("j/k", "Nav"), ("Enter", "Details"), ("s/S", "Sort"), ("b/B", "Bookmark"), ("c", "Consumers only"), ("/", "Filter"), ("F5", "Refresh")],
+        Screen::TopicDetails { .. } => vec![("Tab", "Switch"), ("m", "Messages"), ("Enter/o", "Partition"), ("d", "Delete"), ("p", "Add Parts"), ("e", "Config"), ("x", "Purge"), ("w", "Watermarks")],
+        Screen::ConsumerGroupDetails { .. } => vec![("Tab", "Switch"), ("r", "Reset Offsets"), ("F5", "Refresh")],
+        Screen::Brokers => vec![("Enter", "Config"), ("y", "Copy Address"), ("F5", "Refresh")],
+        Screen::BrokerDetails { .. } => vec![("j/k", "Nav"), ("Esc", "Back"), ("F5", "Refresh")],
+        Screen::Throughput => vec![("j/k", "Nav"), ("F5", "Sample Now")],
+        Screen::Logs => vec![("j/k", "Nav"), ("c", "Clear"), ("f", "Level"), ("/", "Filter"), ("Enter", "View"), ("y", "Copy"), ("e", "Export")],
     });
     h
 }
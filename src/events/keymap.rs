@@ -0,0 +1,279 @@
+//! User-configurable key remapping.
+//!
+//! The `[keymap]` section of the config file binds extra key chords to a fixed
+//! set of actions. A remapped chord is translated into the action's default key
+//! before the normal bindings run, so screen-specific behavior (e.g. what
+//! "refresh" means on each screen) stays in one place. A chord set to `"none"`
+//! (e.g. `"x" = "none"`) is unbound and does nothing.
+
+use std::collections::HashMap;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::config::app_config::KeyChords;
+use crate::events::key_bindings::{every_screen, global_key_binding, screen_key_binding};
+
+/// Remappable actions, by config name, with the default key each one stands for
+const REMAPPABLE: &[(&str, KeyModifiers, KeyCode)] = &[
+    ("quit", KeyModifiers::NONE, KeyCode::Char('q')),
+    ("help", KeyModifiers::NONE, KeyCode::Char('?')),
+    ("back", KeyModifiers::NONE, KeyCode::Esc),
+    ("select", KeyModifiers::NONE, KeyCode::Enter),
+    ("move_up", KeyModifiers::NONE, KeyCode::Up),
+    ("move_down", KeyModifiers::NONE, KeyCode::Down),
+    ("page_up", KeyModifiers::NONE, KeyCode::PageUp),
+    ("page_down", KeyModifiers::NONE, KeyCode::PageDown),
+    ("scroll_to_top", KeyModifiers::NONE, KeyCode::Home),
+    ("scroll_to_bottom", KeyModifiers::NONE, KeyCode::End),
+    ("refresh", KeyModifiers::NONE, KeyCode::F(5)),
+    ("filter", KeyModifiers::NONE, KeyCode::Char('/')),
+];
+
+/// Config value that unbinds the chord it is set for
+const UNBOUND: &str = "none";
+
+type Chord = (KeyModifiers, KeyCode);
+
+#[derive(Debug, Clone, Default)]
+pub struct KeyMap {
+    /// Remapped chord to the default key it stands for; `None` when unbound
+    bindings: HashMap<Chord, Option<Chord>>,
+}
+
+impl KeyMap {
+    /// Build a keymap from the config section, returning warnings for entries that were skipped.
+    pub fn from_config(config: &HashMap<String, KeyChords>) -> (Self, Vec<String>) {
+        let mut bindings: HashMap<Chord, Option<Chord>> = HashMap::new();
+        let mut bound_to: HashMap<Chord, &str> = HashMap::new();
+        let mut warnings = Vec::new();
+
+        // Sort for deterministic duplicate reporting
+        let mut entries: Vec<_> = config.iter().collect();
+        entries.sort_by(|a, b| a.0.cmp(b.0));
+
+        for (name, chords) in entries {
+            let Some(&(_, modifiers, code)) = REMAPPABLE.iter().find(|(n, _, _)| n == name) else {
+                match (chords.as_slice(), parse_chord(name)) {
+                    ([value], Some(chord)) if value.eq_ignore_ascii_case(UNBOUND) => {
+                        match bound_to.get(&chord) {
+                            Some(other) => warnings.push(format!("Key '{}' bound to both '{}' and '{}'", name, other, UNBOUND)),
+                            None => {
+                                bound_to.insert(chord, UNBOUND);
+                                bindings.insert(chord, None);
+                            }
+                        }
+                    }
+                    _ => warnings.push(format!("Unknown keymap action '{}'", name)),
+                }
+                continue;
+            };
+
+            for chord_str in chords.as_slice() {
+                let Some(chord) = parse_chord(chord_str) else {
+                    warnings.push(format!("Invalid key '{}' for '{}'", chord_str, name));
+                    continue;
+                };
+                if let Some(other) = bound_to.get(&chord) {
+                    if *other != name.as_str() {
+                        warnings.push(format!("Key '{}' bound to both '{}' and '{}'", chord_str, other, name));
+                    }
+                    continue;
+                }
+                if chord != (modifiers, code) {
+                    if let Some(screens) = built_in_use(chord) {
+                        warnings.push(format!("Key '{}' for '{}' replaces its built-in binding on {}", chord_str, name, screens));
+                    }
+                }
+                bound_to.insert(chord, name);
+                bindings.insert(chord, Some((modifiers, code)));
+            }
+        }
+
+        (Self { bindings }, warnings)
+    }
+
+    /// Translate a remapped key into the default key of its action; `None` for an unbound key.
+    pub fn translate(&self, key: KeyEvent) -> Option<KeyEvent> {
+        match self.bindings.get(&normalize(key.modifiers, key.code)) {
+            Some(Some((modifiers, code))) => Some(KeyEvent::new_with_kind(*code, *modifiers, key.kind)),
+            Some(None) => None,
+            None => Some(key),
+        }
+    }
+}
+
+/// Screens where `chord` already does something, or `None` if it is free everywhere.
+fn built_in_use(chord: Chord) -> Option<String> {
+    // Chords are stored without SHIFT, but uppercase bindings match with it
+    let shift = match chord.1 {
+        KeyCode::Char(c) if c.is_uppercase() => KeyModifiers::SHIFT,
+        _ => KeyModifiers::NONE,
+    };
+    let key = KeyEvent::new(chord.1, chord.0 | shift);
+    if global_key_binding(key).is_some() {
+        return Some("every screen".into());
+    }
+    let screens: Vec<&str> = every_screen()
+        .into_iter()
+        .filter(|(_, screen)| screen_key_binding(screen, key, false).is_some())
+        .map(|(name, _)| name)
+        .collect();
+    (!screens.is_empty()).then(|| screens.join(", "))
+}
+
+/// Terminals report uppercase letters with SHIFT set; the char already carries the case.
+fn normalize(modifiers: KeyModifiers, code: KeyCode) -> Chord {
+    match code {
+        KeyCode::Char(_) => (modifiers - KeyModifiers::SHIFT, code),
+        _ => (modifiers, code),
+    }
+}
+
+/// Parse a chord such as `q`, `G`, `ctrl+r`, `alt+j`, `f5`, or `pagedown`.
+fn parse_chord(input: &str) -> Option<Chord> {
+    let mut parts: Vec<&str> = input.split('+').collect();
+    // A trailing "+" means the plus key itself, e.g. "ctrl++"
+    let key = if input.ends_with("++") || input == "+" {
+        parts.truncate(parts.len().saturating_sub(2));
+        "+"
+    } else {
+        parts.pop()?
+    };
+
+    let mut modifiers = KeyModifiers::NONE;
+    for m in parts {
+        modifiers |= match m.to_ascii_lowercase().as_str() {
+            "ctrl" | "control" => KeyModifiers::CONTROL,
+            "alt" => KeyModifiers::ALT,
+            "shift" => KeyModifiers::SHIFT,
+            _ => return None,
+        };
+    }
+
+    let code = match key.to_ascii_lowercase().as_str() {
+        "esc" | "escape" => KeyCode::Esc,
+        "enter" | "return" => KeyCode::Enter,
+        "tab" => KeyCode::Tab,
+        "backtab" => KeyCode::BackTab,
+        "backspace" => KeyCode::Backspace,
+        "space" => KeyCode::Char(' '),
+        "up" => KeyCode::Up,
+        "down" => KeyCode::Down,
+        "left" => KeyCode::Left,
+        "right" => KeyCode::Right,
+        "pageup" => KeyCode::PageUp,
+        "pagedown" => KeyCode::PageDown,
+        "home" => KeyCode::Home,
+        "end" => KeyCode::End,
+        k if k.len() > 1 && k.starts_with('f') => KeyCode::F(k[1..].parse().ok()?),
+        _ => {
+            let mut chars = key.chars();
+            let c = chars.next()?;
+            if chars.next().is_some() {
+                return None;
+            }
+            KeyCode::Char(c)
+        }
+    };
+
+    Some(normalize(modifiers, code))
+}
+
+/// A key as shown to the user, e.g. `q`, `G`, `Ctrl+R`, `F5` or `Enter`.
+pub fn key_label(key: KeyEvent) -> String {
+    let (modifiers, code) = normalize(key.modifiers, key.code);
+    let name = match code {
+        KeyCode::Char(' ') => "Space".to_string(),
+        KeyCode::Char(c) if modifiers.contains(KeyModifiers::CONTROL) => c.to_ascii_uppercase().to_string(),
+        KeyCode::Char(c) => c.to_string(),
+        KeyCode::F(n) => format!("F{}", n),
+        KeyCode::Esc => "Esc".to_string(),
+        KeyCode::Enter => "Enter".to_string(),
+        KeyCode::Tab => "Tab".to_string(),
+        KeyCode::Left => "←".to_string(),
+        KeyCode::Right => "→".to_string(),
+        other => format!("{:?}", other),
+    };
+    let mut label = String::new();
+    if modifiers.contains(KeyModifiers::CONTROL) {
+        label.push_str("Ctrl+");
+    }
+    if modifiers.contains(KeyModifiers::ALT) {
+        label.push_str("Alt+");
+    }
+    label + &name
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keymap(entries: &[(&str, &[&str])]) -> (KeyMap, Vec<String>) {
+        let config = entries
+            .iter()
+            .map(|(name, keys)| {
+                (name.to_string(), KeyChords::Many(keys.iter().map(|k| k.to_string()).collect()))
+            })
+            .collect();
+        KeyMap::from_config(&config)
+    }
+
+    #[test]
+    fn test_parse_chord() {
+        assert_eq!(parse_chord("q"), Some((KeyModifiers::NONE, KeyCode::Char('q'))));
+        assert_eq!(parse_chord("ctrl+r"), Some((KeyModifiers::CONTROL, KeyCode::Char('r'))));
+        assert_eq!(parse_chord("f5"), Some((KeyModifiers::NONE, KeyCode::F(5))));
+        assert_eq!(parse_chord("PageDown"), Some((KeyModifiers::NONE, KeyCode::PageDown)));
+        assert_eq!(parse_chord("ctrl++"), Some((KeyModifiers::CONTROL, KeyCode::Char('+'))));
+        assert_eq!(parse_chord("hyper+x"), None);
+        assert_eq!(parse_chord("jk"), None);
+    }
+
+    #[test]
+    fn test_key_label() {
+        assert_eq!(key_label(KeyEvent::new(KeyCode::Char('r'), KeyModifiers::CONTROL)), "Ctrl+R");
+        assert_eq!(key_label(KeyEvent::new(KeyCode::Char('B'), KeyModifiers::SHIFT)), "B");
+        assert_eq!(key_label(KeyEvent::new(KeyCode::F(5), KeyModifiers::NONE)), "F5");
+        assert_eq!(key_label(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE)), "Enter");
+    }
+
+    #[test]
+    fn test_translate_remapped_key() {
+        let (map, warnings) = keymap(&[("move_down", &["alt+z"]), ("quit", &["alt+v"])]);
+        assert!(warnings.is_empty());
+
+        let key = map.translate(KeyEvent::new(KeyCode::Char('z'), KeyModifiers::ALT));
+        assert_eq!(key.map(|k| k.code), Some(KeyCode::Down));
+
+        let key = map.translate(KeyEvent::new(KeyCode::Char('v'), KeyModifiers::ALT));
+        assert_eq!(key.map(|k| k.code), Some(KeyCode::Char('q')));
+
+        // Unmapped keys pass through
+        let key = map.translate(KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE));
+        assert_eq!(key.map(|k| k.code), Some(KeyCode::Char('j')));
+    }
+
+    #[test]
+    fn test_unbind_default_key() {
+        let (map, warnings) = keymap(&[("x", &["none"]), ("ctrl+y", &["None"])]);
+        assert!(warnings.is_empty(), "{:?}", warnings);
+        assert_eq!(map.translate(KeyEvent::new(KeyCode::Char('x'), KeyModifiers::NONE)), None);
+        assert_eq!(map.translate(KeyEvent::new(KeyCode::Char('y'), KeyModifiers::CONTROL)), None);
+        assert!(map.translate(KeyEvent::new(KeyCode::Char('d'), KeyModifiers::NONE)).is_some());
+    }
+
+    #[test]
+    fn test_keymap_warnings() {
+        let (_, warnings) = keymap(&[("jump", &["x"]), ("move_up", &["alt+u"]), ("page_up", &["alt+u"])]);
+        assert_eq!(warnings.len(), 2);
+        assert!(warnings[0].contains("jump"));
+        assert!(warnings[1].contains("bound to both"));
+
+        // Shadowing a built-in key is allowed but reported
+        let (map, warnings) = keymap(&[("refresh", &["d", "f5"])]);
+        assert_eq!(warnings.len(), 1, "{:?}", warnings);
+        assert!(warnings[0].contains("Key 'd' for 'refresh' replaces its built-in binding on"));
+        assert!(warnings[0].contains("Topics"));
+        assert_eq!(map.translate(KeyEvent::new(KeyCode::Char('d'), KeyModifiers::NONE)).map(|k| k.code), Some(KeyCode::F(5)));
+    }
+}
@@ -1,4 +1,5 @@
 pub mod handler;
 pub mod key_bindings;
+pub mod keymap;
 
 pub use handler::EventHandler;
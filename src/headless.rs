@@ -0,0 +1,142 @@
+//! Non-interactive consume mode: print messages to stdout as NDJSON without starting the TUI.
+
+use std::io::{self, Write};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use crate::app::state::{ConnectionProfile, KafkaMessage, OffsetMode};
+use crate::error::{AppError, AppResult};
+use crate::kafka::config::KafkaConfig;
+use crate::kafka::KafkaClient;
+use crate::storage::{connections, export};
+
+/// Messages printed by a one-shot consume when `--limit` is not given
+pub const DEFAULT_LIMIT: usize = 100;
+
+/// What to consume and when to stop
+#[derive(Debug, Clone)]
+pub struct ConsumeOptions {
+    pub topic: String,
+    pub offset_mode: OffsetMode,
+    pub partition: Option<i32>,
+    /// Stop after this many messages; a one-shot consume defaults to [`DEFAULT_LIMIT`]
+    pub limit: Option<usize>,
+    /// Keep streaming new messages until interrupted (or `limit` is reached)
+    pub follow: bool,
+}
+
+/// Pick the profile to connect with: a saved one by name, or an unsaved one for `brokers`.
+pub fn resolve_profile(name: Option<&str>, brokers: Option<&str>) -> AppResult<ConnectionProfile> {
+    if let Some(name) = name {
+        return connections::load_connections()?
+            .into_iter()
+            .find(|p| p.name == name)
+            .ok_or_else(|| AppError::Config(format!("No saved connection named '{}'", name)));
+    }
+    match brokers {
+        Some(brokers) => Ok(ConnectionProfile {
+            name: brokers.to_string(),
+            brokers: brokers.to_string(),
+            ..Default::default()
+        }),
+        None => Err(AppError::Config("--consume needs --profile or --brokers".into())),
+    }
+}
+
+/// Connect, print matching messages one JSON object per line and return how many were printed.
+pub async fn consume(profile: ConnectionProfile, opts: ConsumeOptions) -> AppResult<usize> {
+    let client = KafkaClient::new(KafkaConfig::for_profile(profile).await?).await?;
+    client.test_connection().await?;
+
+    if !opts.follow {
+        let limit = opts.limit.unwrap_or(DEFAULT_LIMIT);
+        let fetched = client.fetch_messages(&opts.topic, opts.offset_mode, opts.partition, limit).await?;
+        if fetched.timed_out {
+            eprintln!("Fetched {}/{} messages (timed out)", fetched.messages.len(), limit);
+        }
+        let mut out = io::stdout().lock();
+        for (printed, msg) in fetched.messages.iter().enumerate() {
+            if let Err(e) = write_line(&mut out, msg) {
+                return closed_stdout(e).map(|_| printed);
+            }
+        }
+        return Ok(fetched.messages.len());
+    }
+
+    let cancel = Arc::new(AtomicBool::new(false));
+    let interrupted = cancel.clone();
+    tokio::spawn(async move {
+        if tokio::signal::ctrl_c().await.is_ok() {
+            interrupted.store(true, Ordering::Relaxed);
+        }
+    });
+
+    let printed = Arc::new(AtomicUsize::new(0));
+    let (stop, count, limit) = (cancel.clone(), printed.clone(), opts.limit);
+    let on_message = move |msg: KafkaMessage| {
+        if stop.load(Ordering::Relaxed) {
+            return;
+        }
+        if let Err(e) = write_line(&mut io::stdout().lock(), &msg) {
+            // A closed pipe (`| head`) ends the stream quietly
+            if e.kind() != io::ErrorKind::BrokenPipe {
+                tracing::warn!(error = %e, "Failed to write message to stdout");
+            }
+            stop.store(true, Ordering::Relaxed);
+            return;
+        }
+        let n = count.fetch_add(1, Ordering::Relaxed) + 1;
+        if limit.is_some_and(|limit| n >= limit) {
+            stop.store(true, Ordering::Relaxed);
+        }
+    };
+    client.stream_messages(&opts.topic, opts.offset_mode, opts.partition, cancel, on_message).await?;
+    Ok(printed.load(Ordering::Relaxed))
+}
+
+/// Write one message as a single JSON line and flush so downstream tools see it immediately.
+fn write_line(out: &mut impl Write, msg: &KafkaMessage) -> io::Result<()> {
+    serde_json::to_writer(&mut *out, &export::message_to_json(msg))?;
+    out.write_all(b"\n")?;
+    out.flush()
+}
+
+/// A reader that went away is a normal way for a pipeline to end; anything else is an error.
+fn closed_stdout(e: io::Error) -> AppResult<()> {
+    if e.kind() == io::ErrorKind::BrokenPipe {
+        Ok(())
+    } else {
+        Err(e.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_line_is_single_json_object() {
+        let msg = KafkaMessage {
+            partition: 2,
+            offset: 42,
+            timestamp: None,
+            timestamp_type: Default::default(),
+            key: Some("k".into()),
+            value: "line one\nline two".into(),
+            headers: Default::default(),
+            key_size: 1,
+            value_size: 17,
+            raw_value: b"line one\nline two".to_vec(),
+            schema_id: None,
+        };
+        let mut out = Vec::new();
+        write_line(&mut out, &msg).unwrap();
+
+        let text = String::from_utf8(out).unwrap();
+        assert_eq!(text.matches('\n').count(), 1);
+        let json: serde_json::Value = serde_json::from_str(text.trim_end()).unwrap();
+        assert_eq!(json["partition"], 2);
+        assert_eq!(json["offset"], 42);
+        assert_eq!(json["value"], "line one\nline two");
+    }
+}
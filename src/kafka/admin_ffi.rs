@@ -1,7 +1,15 @@
 //! Low-level FFI bindings for rdkafka admin operations not exposed by the safe API.
 //!
 //! This module contains unsafe code for admin operations that are not available
-//! through rdkafka's safe Rust API, specifically the DeleteRecords operation.
+//! through rdkafka's safe Rust API: the DeleteRecords, IncrementalAlterConfigs,
+//! ListConsumerGroupOffsets and DescribeCluster operations, the cluster id lookup, the
+//! consumer group coordinator lookup and handing an OAUTHBEARER token to the admin client.
+//!
+//! Partition reassignment status (`ListPartitionReassignments`) is not available here:
+//! librdkafka (2.10) has no admin API for it, only the protocol constant. Until it does,
+//! the topic details mark a partition as reassigning from metadata alone, which lists the
+//! adding replicas before they join the ISR (`PartitionInfo::reassignment`). DescribeLogDirs is missing
+//! as well, so topic sizes on disk aren't shown.
 //!
 //! # Safety
 //!
@@ -9,12 +17,198 @@
 //! rdkafka client objects. The caller is responsible for ensuring the underlying
 //! Kafka client remains valid for the duration of these calls.
 
-use std::ffi::CStr;
+use std::collections::HashMap;
+use std::ffi::{CStr, CString};
 
 use rdkafka::bindings as rdsys;
 use rdkafka::TopicPartitionList;
 
+use crate::app::state::BrokerInfo;
 use crate::error::{AppError, AppResult};
+use crate::kafka::oauth::BearerToken;
+
+/// Fetch the cluster id reported by the brokers.
+///
+/// Returns `None` if the cluster id is unavailable within the timeout (e.g. brokers
+/// older than Kafka 0.10 that don't report one).
+///
+/// # Safety
+///
+/// This function is safe to call if `client_ptr` is a valid pointer to an rd_kafka_t
+/// obtained from a live consumer that outlives this call.
+pub fn cluster_id(client_ptr: usize, timeout_ms: i32) -> Option<String> {
+    let client_ptr = client_ptr as *mut rdsys::rd_kafka_t;
+    let raw = unsafe { rdsys::rd_kafka_clusterid(client_ptr, timeout_ms) };
+    if raw.is_null() {
+        return None;
+    }
+    let id = unsafe { CStr::from_ptr(raw) }.to_string_lossy().into_owned();
+    // The string is allocated by librdkafka and must be released through it
+    unsafe { rdsys::rd_kafka_mem_free(client_ptr, raw.cast()) };
+    Some(id)
+}
+
+/// Find the broker acting as coordinator for `group_id`.
+///
+/// The safe `GroupInfo` wrapper drops the broker that answered the group listing,
+/// which is the group's coordinator, so the list is fetched through the C API here.
+/// Returns `None` if the group is unknown or the lookup fails.
+///
+/// # Safety
+///
+/// This function is safe to call if `client_ptr` is a valid pointer to an rd_kafka_t
+/// obtained from a live consumer that outlives this call.
+pub fn group_coordinator(client_ptr: usize, group_id: &str, timeout_ms: i32) -> Option<BrokerInfo> {
+    let client_ptr = client_ptr as *mut rdsys::rd_kafka_t;
+    let group = CString::new(group_id).ok()?;
+    let mut list: *const rdsys::rd_kafka_group_list = std::ptr::null();
+
+    let err = unsafe { rdsys::rd_kafka_list_groups(client_ptr, group.as_ptr(), &mut list, timeout_ms) };
+    if err != rdsys::rd_kafka_resp_err_t::RD_KAFKA_RESP_ERR_NO_ERROR || list.is_null() {
+        return None;
+    }
+
+    let groups = unsafe { std::slice::from_raw_parts((*list).groups, (*list).group_cnt.max(0) as usize) };
+    let coordinator = groups
+        .iter()
+        .find(|g| !g.group.is_null() && unsafe { CStr::from_ptr(g.group) }.to_bytes() == group_id.as_bytes())
+        .filter(|g| !g.broker.host.is_null())
+        .map(|g| BrokerInfo {
+            id: g.broker.id,
+            host: unsafe { CStr::from_ptr(g.broker.host) }.to_string_lossy().into_owned(),
+            port: g.broker.port,
+            rack: None,
+            is_controller: false,
+        });
+
+    unsafe { rdsys::rd_kafka_group_list_destroy(list) };
+    coordinator
+}
+
+/// Controller and broker racks from a DescribeCluster request
+#[derive(Debug, Default)]
+pub struct ClusterNodes {
+    pub controller: Option<i32>,
+    /// Rack per broker id, for brokers that set `broker.rack`
+    pub racks: HashMap<i32, String>,
+}
+
+/// Describe the cluster (Kafka 2.8+), which unlike metadata names the active controller
+/// and each broker's rack.
+///
+/// Returns `None` if the brokers don't support the request or it fails.
+///
+/// # Safety
+///
+/// This function is safe to call if `client_ptr` is a valid pointer to an rd_kafka_t
+/// obtained from a live consumer that outlives this call.
+pub fn describe_cluster(client_ptr: usize, timeout_ms: i32) -> Option<ClusterNodes> {
+    // SAFETY: caller guarantees client_ptr is valid for the duration of this call
+    unsafe { describe_cluster_inner(client_ptr as *mut rdsys::rd_kafka_t, timeout_ms) }
+}
+
+/// Inner implementation of describe_cluster with the actual unsafe operations.
+unsafe fn describe_cluster_inner(client_ptr: *mut rdsys::rd_kafka_t, timeout_ms: i32) -> Option<ClusterNodes> {
+    let queue = unsafe { rdsys::rd_kafka_queue_new(client_ptr) };
+    if queue.is_null() {
+        return None;
+    }
+
+    let opts = unsafe {
+        rdsys::rd_kafka_AdminOptions_new(client_ptr, rdsys::rd_kafka_admin_op_t::RD_KAFKA_ADMIN_OP_DESCRIBECLUSTER)
+    };
+    if opts.is_null() {
+        unsafe { rdsys::rd_kafka_queue_destroy(queue) };
+        return None;
+    }
+
+    let mut errstr = [0i8; 512];
+    unsafe {
+        rdsys::rd_kafka_AdminOptions_set_request_timeout(opts, timeout_ms, errstr.as_mut_ptr(), errstr.len());
+        rdsys::rd_kafka_DescribeCluster(client_ptr, opts, queue);
+        rdsys::rd_kafka_AdminOptions_destroy(opts);
+    }
+
+    let event = unsafe { rdsys::rd_kafka_queue_poll(queue, timeout_ms) };
+    let nodes = unsafe { read_cluster_nodes(event) };
+    unsafe {
+        if !event.is_null() {
+            rdsys::rd_kafka_event_destroy(event);
+        }
+        rdsys::rd_kafka_queue_destroy(queue);
+    }
+    nodes
+}
+
+/// Read the controller and racks out of a DescribeCluster result event.
+unsafe fn read_cluster_nodes(event: *mut rdsys::rd_kafka_event_t) -> Option<ClusterNodes> {
+    if event.is_null()
+        || unsafe { rdsys::rd_kafka_event_error(event) } != rdsys::rd_kafka_resp_err_t::RD_KAFKA_RESP_ERR_NO_ERROR
+    {
+        return None;
+    }
+    let result = unsafe { rdsys::rd_kafka_event_DescribeCluster_result(event) };
+    if result.is_null() {
+        return None;
+    }
+
+    let controller = unsafe { rdsys::rd_kafka_DescribeCluster_result_controller(result) };
+    let mut cnt = 0usize;
+    let nodes = unsafe { rdsys::rd_kafka_DescribeCluster_result_nodes(result, &mut cnt) };
+    let nodes = if nodes.is_null() { &[][..] } else { unsafe { std::slice::from_raw_parts(nodes, cnt) } };
+
+    let racks = nodes
+        .iter()
+        .filter_map(|&node| {
+            let rack = unsafe { rdsys::rd_kafka_Node_rack(node) };
+            if rack.is_null() {
+                return None;
+            }
+            let rack = unsafe { CStr::from_ptr(rack) }.to_string_lossy().into_owned();
+            Some((unsafe { rdsys::rd_kafka_Node_id(node) }, rack))
+        })
+        .collect();
+    Some(ClusterNodes {
+        controller: (!controller.is_null()).then(|| unsafe { rdsys::rd_kafka_Node_id(controller) }),
+        racks,
+    })
+}
+
+/// Set the OAUTHBEARER token a client authenticates with.
+///
+/// rdkafka's admin client never polls its main queue, so it can't answer librdkafka's
+/// token refresh requests through the context callback; the token is pushed to it instead.
+///
+/// # Safety
+///
+/// This function is safe to call if `client_ptr` is a valid pointer to an rd_kafka_t
+/// obtained from a live client that outlives this call.
+pub fn set_oauth_token(client_ptr: usize, token: &BearerToken) -> AppResult<()> {
+    let client_ptr = client_ptr as *mut rdsys::rd_kafka_t;
+    let value = CString::new(token.value.as_str())
+        .map_err(|_| AppError::Authentication("Token contains a NUL byte".into()))?;
+    let principal = CString::new(token.principal.as_str())
+        .map_err(|_| AppError::Authentication("Token principal contains a NUL byte".into()))?;
+    let mut errstr = [0 as std::ffi::c_char; 512];
+
+    let err = unsafe {
+        rdsys::rd_kafka_oauthbearer_set_token(
+            client_ptr,
+            value.as_ptr(),
+            token.expires_at_ms,
+            principal.as_ptr(),
+            std::ptr::null_mut(),
+            0,
+            errstr.as_mut_ptr(),
+            errstr.len(),
+        )
+    };
+    if err != rdsys::rd_kafka_resp_err_t::RD_KAFKA_RESP_ERR_NO_ERROR {
+        let msg = unsafe { CStr::from_ptr(errstr.as_ptr()) }.to_string_lossy().into_owned();
+        return Err(AppError::Authentication(msg));
+    }
+    Ok(())
+}
 
 /// Delete records from topic partitions up to the specified offsets.
 ///
@@ -193,6 +387,279 @@ unsafe fn check_partition_results(
     Ok(Ok(()))
 }
 
+/// Set topic config entries and remove overrides with IncrementalAlterConfigs (Kafka 2.3+).
+///
+/// Unlike the legacy AlterConfigs request used by the safe API, which replaces the whole
+/// topic config, entries that aren't listed keep their current values. Keys in `deletes`
+/// revert to the broker default.
+///
+/// # Safety
+///
+/// This function is safe to call if `client_ptr` is a valid pointer to an rd_kafka_t
+/// obtained from a live consumer that outlives this call.
+pub fn incremental_alter_topic_config(
+    client_ptr: usize,
+    topic: &str,
+    configs: &[(String, String)],
+    deletes: &[String],
+    timeout_ms: i32,
+) -> AppResult<()> {
+    let c_string = |s: &str| {
+        CString::new(s).map_err(|_| AppError::Kafka(format!("'{}' contains a NUL byte", s.escape_default())))
+    };
+    let topic = c_string(topic)?;
+    let sets = configs.iter().map(|(name, value)| Ok((c_string(name)?, Some(c_string(value)?))));
+    let deletes = deletes.iter().map(|name| Ok((c_string(name)?, None)));
+    let entries = sets.chain(deletes).collect::<AppResult<Vec<_>>>()?;
+
+    // SAFETY: caller guarantees client_ptr is valid for the duration of this call
+    unsafe { incremental_alter_inner(client_ptr as *mut rdsys::rd_kafka_t, &topic, &entries, timeout_ms) }
+}
+
+/// Inner implementation of incremental_alter_topic_config with the actual unsafe operations.
+unsafe fn incremental_alter_inner(
+    client_ptr: *mut rdsys::rd_kafka_t,
+    topic: &CStr,
+    entries: &[(CString, Option<CString>)],
+    timeout_ms: i32,
+) -> AppResult<()> {
+    let resource = unsafe {
+        rdsys::rd_kafka_ConfigResource_new(rdsys::rd_kafka_ResourceType_t::RD_KAFKA_RESOURCE_TOPIC, topic.as_ptr())
+    };
+    if resource.is_null() {
+        return Err(AppError::Kafka("Failed to create config resource".into()));
+    }
+
+    for (name, value) in entries {
+        // A missing value removes the override
+        let (op_type, value_ptr) = match value {
+            Some(value) => (rdsys::rd_kafka_AlterConfigOpType_t::RD_KAFKA_ALTER_CONFIG_OP_TYPE_SET, value.as_ptr()),
+            None => (rdsys::rd_kafka_AlterConfigOpType_t::RD_KAFKA_ALTER_CONFIG_OP_TYPE_DELETE, std::ptr::null()),
+        };
+        let error = unsafe {
+            rdsys::rd_kafka_ConfigResource_add_incremental_config(resource, name.as_ptr(), op_type, value_ptr)
+        };
+        if !error.is_null() {
+            let msg = unsafe { CStr::from_ptr(rdsys::rd_kafka_error_string(error)) }.to_string_lossy().into_owned();
+            unsafe {
+                rdsys::rd_kafka_error_destroy(error);
+                rdsys::rd_kafka_ConfigResource_destroy(resource);
+            }
+            return Err(AppError::Kafka(format!("Invalid config '{}': {}", name.to_string_lossy(), msg)));
+        }
+    }
+
+    let queue = unsafe { rdsys::rd_kafka_queue_new(client_ptr) };
+    if queue.is_null() {
+        unsafe { rdsys::rd_kafka_ConfigResource_destroy(resource) };
+        return Err(AppError::Kafka("Failed to create admin result queue".into()));
+    }
+
+    let opts = unsafe {
+        rdsys::rd_kafka_AdminOptions_new(
+            client_ptr,
+            rdsys::rd_kafka_admin_op_t::RD_KAFKA_ADMIN_OP_INCREMENTALALTERCONFIGS,
+        )
+    };
+    if opts.is_null() {
+        unsafe {
+            rdsys::rd_kafka_ConfigResource_destroy(resource);
+            rdsys::rd_kafka_queue_destroy(queue);
+        }
+        return Err(AppError::Kafka("Failed to create admin options".into()));
+    }
+
+    let mut errstr = [0i8; 512];
+    let timeout_result = unsafe {
+        rdsys::rd_kafka_AdminOptions_set_request_timeout(opts, timeout_ms, errstr.as_mut_ptr(), errstr.len())
+    };
+    if timeout_result != rdsys::rd_kafka_resp_err_t::RD_KAFKA_RESP_ERR_NO_ERROR {
+        let msg = unsafe { CStr::from_ptr(errstr.as_ptr()) }.to_string_lossy().into_owned();
+        unsafe {
+            rdsys::rd_kafka_ConfigResource_destroy(resource);
+            rdsys::rd_kafka_AdminOptions_destroy(opts);
+            rdsys::rd_kafka_queue_destroy(queue);
+        }
+        return Err(AppError::Kafka(format!("Failed to set timeout: {}", msg)));
+    }
+
+    let mut resources = [resource];
+    unsafe {
+        rdsys::rd_kafka_IncrementalAlterConfigs(client_ptr, resources.as_mut_ptr(), resources.len(), opts, queue);
+        // The request holds its own copies once queued
+        rdsys::rd_kafka_ConfigResource_destroy(resource);
+        rdsys::rd_kafka_AdminOptions_destroy(opts);
+    }
+
+    let event = unsafe { rdsys::rd_kafka_queue_poll(queue, timeout_ms) };
+    let outcome = unsafe { check_alter_configs_event(event) };
+    unsafe {
+        if !event.is_null() {
+            rdsys::rd_kafka_event_destroy(event);
+        }
+        rdsys::rd_kafka_queue_destroy(queue);
+    }
+    outcome
+}
+
+/// Check the request-level and per-resource errors of an IncrementalAlterConfigs result.
+unsafe fn check_alter_configs_event(event: *mut rdsys::rd_kafka_event_t) -> AppResult<()> {
+    if event.is_null() {
+        return Err(AppError::Kafka("IncrementalAlterConfigs timed out".into()));
+    }
+
+    let err = unsafe { rdsys::rd_kafka_event_error(event) };
+    if err != rdsys::rd_kafka_resp_err_t::RD_KAFKA_RESP_ERR_NO_ERROR {
+        let c_msg = unsafe { rdsys::rd_kafka_event_error_string(event) };
+        return Err(AppError::Kafka(if c_msg.is_null() {
+            "IncrementalAlterConfigs failed".to_string()
+        } else {
+            unsafe { CStr::from_ptr(c_msg) }.to_string_lossy().into_owned()
+        }));
+    }
+
+    let result = unsafe { rdsys::rd_kafka_event_IncrementalAlterConfigs_result(event) };
+    if result.is_null() {
+        return Err(AppError::Kafka("IncrementalAlterConfigs returned unexpected result".into()));
+    }
+
+    let mut count = 0;
+    let resources = unsafe { rdsys::rd_kafka_IncrementalAlterConfigs_result_resources(result, &mut count) };
+    for i in 0..count {
+        let resource = unsafe { *resources.add(i) };
+        let err = unsafe { rdsys::rd_kafka_ConfigResource_error(resource) };
+        if err != rdsys::rd_kafka_resp_err_t::RD_KAFKA_RESP_ERR_NO_ERROR {
+            let c_msg = unsafe { rdsys::rd_kafka_ConfigResource_error_string(resource) };
+            let msg = if c_msg.is_null() {
+                format!("{:?}", err)
+            } else {
+                unsafe { CStr::from_ptr(c_msg) }.to_string_lossy().into_owned()
+            };
+            return Err(AppError::Kafka(format!("Alter config failed: {}", msg)));
+        }
+    }
+    Ok(())
+}
+
+/// Committed offsets of `group_id` as (topic, partition, offset), via ListConsumerGroupOffsets.
+///
+/// Unlike `committed()` on a consumer configured with the group's id, this is a plain
+/// OffsetFetch to the coordinator, so the tool never takes part in the group it inspects.
+/// Partitions without a commit are left out.
+///
+/// # Safety
+///
+/// This function is safe to call if `client_ptr` is a valid pointer to an rd_kafka_t
+/// obtained from a live consumer that outlives this call.
+pub fn list_group_offsets(client_ptr: usize, group_id: &str, timeout_ms: i32) -> AppResult<Vec<(String, i32, i64)>> {
+    let group_id = CString::new(group_id)
+        .map_err(|_| AppError::Kafka(format!("'{}' contains a NUL byte", group_id.escape_default())))?;
+    // SAFETY: caller guarantees client_ptr is valid for the duration of this call
+    unsafe { list_group_offsets_inner(client_ptr as *mut rdsys::rd_kafka_t, &group_id, timeout_ms) }
+}
+
+/// Inner implementation of list_group_offsets with the actual unsafe operations.
+unsafe fn list_group_offsets_inner(
+    client_ptr: *mut rdsys::rd_kafka_t,
+    group_id: &CStr,
+    timeout_ms: i32,
+) -> AppResult<Vec<(String, i32, i64)>> {
+    let queue = unsafe { rdsys::rd_kafka_queue_new(client_ptr) };
+    if queue.is_null() {
+        return Err(AppError::Kafka("Failed to create admin result queue".into()));
+    }
+
+    let opts = unsafe {
+        rdsys::rd_kafka_AdminOptions_new(
+            client_ptr,
+            rdsys::rd_kafka_admin_op_t::RD_KAFKA_ADMIN_OP_LISTCONSUMERGROUPOFFSETS,
+        )
+    };
+    if opts.is_null() {
+        unsafe { rdsys::rd_kafka_queue_destroy(queue) };
+        return Err(AppError::Kafka("Failed to create admin options".into()));
+    }
+
+    let mut errstr = [0i8; 512];
+    // A null partition list asks for every partition the group has committed
+    let request = unsafe { rdsys::rd_kafka_ListConsumerGroupOffsets_new(group_id.as_ptr(), std::ptr::null()) };
+    if request.is_null() {
+        unsafe {
+            rdsys::rd_kafka_AdminOptions_destroy(opts);
+            rdsys::rd_kafka_queue_destroy(queue);
+        }
+        return Err(AppError::Kafka("Failed to create ListConsumerGroupOffsets request".into()));
+    }
+
+    let mut requests = [request];
+    unsafe {
+        rdsys::rd_kafka_AdminOptions_set_request_timeout(opts, timeout_ms, errstr.as_mut_ptr(), errstr.len());
+        rdsys::rd_kafka_ListConsumerGroupOffsets(client_ptr, requests.as_mut_ptr(), requests.len(), opts, queue);
+        // The request holds its own copies once queued
+        rdsys::rd_kafka_ListConsumerGroupOffsets_destroy(request);
+        rdsys::rd_kafka_AdminOptions_destroy(opts);
+    }
+
+    let event = unsafe { rdsys::rd_kafka_queue_poll(queue, timeout_ms) };
+    let outcome = unsafe { read_group_offsets(event) };
+    unsafe {
+        if !event.is_null() {
+            rdsys::rd_kafka_event_destroy(event);
+        }
+        rdsys::rd_kafka_queue_destroy(queue);
+    }
+    outcome
+}
+
+/// Read the committed offsets out of a ListConsumerGroupOffsets result event.
+unsafe fn read_group_offsets(event: *mut rdsys::rd_kafka_event_t) -> AppResult<Vec<(String, i32, i64)>> {
+    if event.is_null() {
+        return Err(AppError::Kafka("ListConsumerGroupOffsets timed out".into()));
+    }
+
+    let err = unsafe { rdsys::rd_kafka_event_error(event) };
+    if err != rdsys::rd_kafka_resp_err_t::RD_KAFKA_RESP_ERR_NO_ERROR {
+        let c_msg = unsafe { rdsys::rd_kafka_event_error_string(event) };
+        return Err(AppError::Kafka(if c_msg.is_null() {
+            "ListConsumerGroupOffsets failed".to_string()
+        } else {
+            unsafe { CStr::from_ptr(c_msg) }.to_string_lossy().into_owned()
+        }));
+    }
+
+    let result = unsafe { rdsys::rd_kafka_event_ListConsumerGroupOffsets_result(event) };
+    if result.is_null() {
+        return Err(AppError::Kafka("ListConsumerGroupOffsets returned unexpected result".into()));
+    }
+
+    let mut count = 0;
+    let groups = unsafe { rdsys::rd_kafka_ListConsumerGroupOffsets_result_groups(result, &mut count) };
+    let mut offsets = Vec::new();
+    for i in 0..count {
+        let group = unsafe { *groups.add(i) };
+        let error = unsafe { rdsys::rd_kafka_group_result_error(group) };
+        if !error.is_null() {
+            let msg = unsafe { CStr::from_ptr(rdsys::rd_kafka_error_string(error)) }.to_string_lossy().into_owned();
+            return Err(AppError::Kafka(format!("List group offsets: {}", msg)));
+        }
+
+        let partitions = unsafe { rdsys::rd_kafka_group_result_partitions(group) };
+        if partitions.is_null() {
+            continue;
+        }
+        let partitions = unsafe { &*partitions };
+        for j in 0..(partitions.cnt as usize) {
+            let elem = unsafe { &*partitions.elems.add(j) };
+            if elem.err != rdsys::rd_kafka_resp_err_t::RD_KAFKA_RESP_ERR_NO_ERROR || elem.offset < 0 || elem.topic.is_null() {
+                continue;
+            }
+            let topic = unsafe { CStr::from_ptr(elem.topic) }.to_string_lossy().into_owned();
+            offsets.push((topic, elem.partition, elem.offset));
+        }
+    }
+    Ok(offsets)
+}
+
 #[cfg(test)]
 mod tests {
     // Note: These tests would require a running Kafka cluster and are therefore
@@ -0,0 +1,352 @@
+//! Avro binary decoding for display.
+//!
+//! Covers the types the Confluent serializers write: primitives, records, enums, arrays,
+//! maps, unions and fixed, with named types referenced by name (including recursively).
+//! Logical types are shown as their underlying value, and bytes/fixed follow the Avro JSON
+//! encoding (one char per byte).
+
+use std::collections::HashMap;
+
+use serde_json::{Map, Number, Value};
+
+/// Deepest nesting decoded; recursive named types could otherwise recurse without bound
+const MAX_DEPTH: usize = 64;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Schema {
+    Null,
+    Boolean,
+    Int,
+    Long,
+    Float,
+    Double,
+    Bytes,
+    String,
+    Record(Vec<(String, Schema)>),
+    Enum(Vec<String>),
+    Array(Box<Schema>),
+    Map(Box<Schema>),
+    Union(Vec<Schema>),
+    Fixed(usize),
+    /// A named type defined elsewhere in the schema, by full name
+    Named(String),
+}
+
+/// A parsed writer schema
+#[derive(Debug, Clone)]
+pub struct AvroSchema {
+    root: Schema,
+    named: HashMap<String, Schema>,
+}
+
+impl AvroSchema {
+    /// Parse a schema from its JSON definition.
+    pub fn parse(definition: &str) -> Result<Self, String> {
+        let json: Value = serde_json::from_str(definition).map_err(|e| format!("invalid schema JSON: {}", e))?;
+        let mut named = HashMap::new();
+        let root = parse_schema(&json, None, &mut named)?;
+        Ok(Self { root, named })
+    }
+
+    /// Decode one datum; the whole of `bytes` must be consumed.
+    pub fn decode(&self, bytes: &[u8]) -> Result<Value, String> {
+        let mut reader = Reader { bytes, pos: 0, depth: 0 };
+        let value = reader.read(&self.root, &self.named)?;
+        if reader.pos != bytes.len() {
+            return Err(format!("{} bytes left after decoding", bytes.len() - reader.pos));
+        }
+        Ok(value)
+    }
+}
+
+fn parse_schema(json: &Value, namespace: Option<&str>, named: &mut HashMap<String, Schema>) -> Result<Schema, String> {
+    match json {
+        Value::String(name) => primitive(name).map_or_else(|| resolve_name(name, namespace, named), Ok),
+        Value::Array(branches) => branches
+            .iter()
+            .map(|b| parse_schema(b, namespace, named))
+            .collect::<Result<_, _>>()
+            .map(Schema::Union),
+        Value::Object(obj) => {
+            let kind = obj.get("type").ok_or("schema object without \"type\"")?;
+            match kind.as_str() {
+                Some(kind @ ("record" | "error" | "enum" | "fixed")) => {
+                    let (full_name, namespace) = full_name(obj, namespace)?;
+                    let schema = match kind {
+                        "enum" => Schema::Enum(
+                            obj.get("symbols")
+                                .and_then(Value::as_array)
+                                .ok_or("enum without symbols")?
+                                .iter()
+                                .map(|s| s.as_str().map(String::from).ok_or("enum symbol is not a string"))
+                                .collect::<Result<_, _>>()?,
+                        ),
+                        "fixed" => Schema::Fixed(
+                            obj.get("size").and_then(Value::as_u64).ok_or("fixed without size")? as usize,
+                        ),
+                        _ => {
+                            // Registered before the fields so they can refer back to the record
+                            named.insert(full_name.clone(), Schema::Null);
+                            let fields = obj.get("fields").and_then(Value::as_array).ok_or("record without fields")?;
+                            Schema::Record(
+                                fields
+                                    .iter()
+                                    .map(|f| {
+                                        let name = f.get("name").and_then(Value::as_str).ok_or("field without name")?;
+                                        let ty = f.get("type").ok_or("field without type")?;
+                                        Ok((name.to_string(), parse_schema(ty, namespace.as_deref(), named)?))
+                                    })
+                                    .collect::<Result<_, String>>()?,
+                            )
+                        }
+                    };
+                    named.insert(full_name.clone(), schema);
+                    Ok(Schema::Named(full_name))
+                }
+                Some("array") => Ok(Schema::Array(Box::new(parse_schema(
+                    obj.get("items").ok_or("array without items")?,
+                    namespace,
+                    named,
+                )?))),
+                Some("map") => Ok(Schema::Map(Box::new(parse_schema(
+                    obj.get("values").ok_or("map without values")?,
+                    namespace,
+                    named,
+                )?))),
+                // A primitive with attributes, such as a logical type
+                _ => parse_schema(kind, namespace, named),
+            }
+        }
+        other => Err(format!("unexpected schema {}", other)),
+    }
+}
+
+fn primitive(name: &str) -> Option<Schema> {
+    Some(match name {
+        "null" => Schema::Null,
+        "boolean" => Schema::Boolean,
+        "int" => Schema::Int,
+        "long" => Schema::Long,
+        "float" => Schema::Float,
+        "double" => Schema::Double,
+        "bytes" => Schema::Bytes,
+        "string" => Schema::String,
+        _ => return None,
+    })
+}
+
+/// Full name of a named type and the namespace its fields inherit
+fn full_name(obj: &Map<String, Value>, namespace: Option<&str>) -> Result<(String, Option<String>), String> {
+    let name = obj.get("name").and_then(Value::as_str).ok_or("named type without name")?;
+    if let Some((ns, _)) = name.rsplit_once('.') {
+        return Ok((name.to_string(), Some(ns.to_string())));
+    }
+    let namespace = obj.get("namespace").and_then(Value::as_str).or(namespace).filter(|ns| !ns.is_empty());
+    Ok(match namespace {
+        Some(ns) => (format!("{}.{}", ns, name), Some(ns.to_string())),
+        None => (name.to_string(), None),
+    })
+}
+
+fn resolve_name(name: &str, namespace: Option<&str>, named: &HashMap<String, Schema>) -> Result<Schema, String> {
+    let qualified = namespace.filter(|_| !name.contains('.')).map(|ns| format!("{}.{}", ns, name));
+    qualified
+        .into_iter()
+        .chain([name.to_string()])
+        .find(|n| named.contains_key(n))
+        .map(Schema::Named)
+        .ok_or_else(|| format!("unknown type '{}'", name))
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+    /// Values being read around the current one
+    depth: usize,
+}
+
+impl Reader<'_> {
+    fn read(&mut self, schema: &Schema, named: &HashMap<String, Schema>) -> Result<Value, String> {
+        if self.depth == MAX_DEPTH {
+            return Err(format!("nested deeper than {} levels", MAX_DEPTH));
+        }
+        self.depth += 1;
+        let value = self.read_value(schema, named);
+        self.depth -= 1;
+        value
+    }
+
+    fn read_value(&mut self, schema: &Schema, named: &HashMap<String, Schema>) -> Result<Value, String> {
+        Ok(match schema {
+            Schema::Null => Value::Null,
+            Schema::Boolean => Value::Bool(self.take(1)?[0] != 0),
+            Schema::Int => {
+                let n = self.long()?;
+                i32::try_from(n).map_err(|_| format!("int out of range: {}", n))?.into()
+            }
+            Schema::Long => self.long()?.into(),
+            Schema::Float => {
+                let b = self.take(4)?;
+                float(f32::from_le_bytes([b[0], b[1], b[2], b[3]]).into())
+            }
+            Schema::Double => {
+                let mut b = [0u8; 8];
+                b.copy_from_slice(self.take(8)?);
+                float(f64::from_le_bytes(b))
+            }
+            Schema::Bytes => {
+                let len = self.len()?;
+                Value::String(self.take(len)?.iter().map(|&b| char::from(b)).collect())
+            }
+            Schema::String => {
+                let len = self.len()?;
+                let s = std::str::from_utf8(self.take(len)?).map_err(|_| "string is not valid UTF-8")?;
+                Value::String(s.to_string())
+            }
+            Schema::Fixed(size) => Value::String(self.take(*size)?.iter().map(|&b| char::from(b)).collect()),
+            Schema::Record(fields) => {
+                let mut obj = Map::new();
+                for (name, field) in fields {
+                    obj.insert(name.clone(), self.read(field, named)?);
+                }
+                Value::Object(obj)
+            }
+            Schema::Enum(symbols) => {
+                let i = self.long()?;
+                let symbol = usize::try_from(i).ok().and_then(|i| symbols.get(i));
+                Value::String(symbol.ok_or_else(|| format!("enum index {} out of range", i))?.clone())
+            }
+            Schema::Union(branches) => {
+                let i = self.long()?;
+                let branch = usize::try_from(i).ok().and_then(|i| branches.get(i));
+                self.read(branch.ok_or_else(|| format!("union index {} out of range", i))?, named)?
+            }
+            Schema::Array(items) => {
+                let mut values = Vec::new();
+                self.blocks(|r| {
+                    values.push(r.read(items, named)?);
+                    Ok(())
+                })?;
+                Value::Array(values)
+            }
+            Schema::Map(values) => {
+                let mut obj = Map::new();
+                self.blocks(|r| {
+                    let len = r.len()?;
+                    let key = String::from_utf8_lossy(r.take(len)?).into_owned();
+                    obj.insert(key, r.read(values, named)?);
+                    Ok(())
+                })?;
+                Value::Object(obj)
+            }
+            Schema::Named(name) => self.read(named.get(name).ok_or_else(|| format!("unknown type '{}'", name))?, named)?,
+        })
+    }
+
+    /// Read array/map blocks, calling `item` once per entry
+    fn blocks(&mut self, mut item: impl FnMut(&mut Self) -> Result<(), String>) -> Result<(), String> {
+        loop {
+            let count = self.long()?;
+            if count == 0 {
+                return Ok(());
+            }
+            if count < 0 {
+                // A negative count is followed by the block's size in bytes
+                self.long()?;
+            }
+            let count = count.unsigned_abs();
+            // Every entry takes at least one byte, except nulls nobody writes in bulk
+            if count > (self.bytes.len() - self.pos) as u64 {
+                return Err(format!("block of {} entries overruns the payload", count));
+            }
+            for _ in 0..count {
+                item(self)?;
+            }
+        }
+    }
+
+    /// Zig-zag encoded variable-length long
+    fn long(&mut self) -> Result<i64, String> {
+        let mut value: u64 = 0;
+        for shift in (0..64).step_by(7) {
+            let byte = self.take(1)?[0];
+            value |= u64::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                return Ok((value >> 1) as i64 ^ -((value & 1) as i64));
+            }
+        }
+        Err("varint longer than 10 bytes".into())
+    }
+
+    fn len(&mut self) -> Result<usize, String> {
+        let n = self.long()?;
+        usize::try_from(n).map_err(|_| format!("negative length {}", n))
+    }
+
+    fn take(&mut self, n: usize) -> Result<&[u8], String> {
+        let end = self.pos.checked_add(n).filter(|&end| end <= self.bytes.len()).ok_or("unexpected end of payload")?;
+        let slice = &self.bytes[self.pos..end];
+        self.pos = end;
+        Ok(slice)
+    }
+}
+
+/// JSON has no NaN or infinity; show those as strings
+pub(super) fn float(f: f64) -> Value {
+    Number::from_f64(f).map_or_else(|| Value::String(f.to_string()), Value::Number)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_record_with_union_array_and_recursion() {
+        let schema = AvroSchema::parse(
+            r#"{
+                "type": "record", "name": "Node", "namespace": "com.example",
+                "fields": [
+                    {"name": "id", "type": "long"},
+                    {"name": "label", "type": ["null", "string"]},
+                    {"name": "color", "type": {"type": "enum", "name": "Color", "symbols": ["RED", "GREEN"]}},
+                    {"name": "tags", "type": {"type": "array", "items": "string"}},
+                    {"name": "next", "type": ["null", "Node"]}
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        // id=-2, label="ab", color=GREEN, tags=["x"], next={id=1, label=null, color=RED, tags=[], next=null}
+        let bytes = [
+            0x03, 0x02, 0x04, b'a', b'b', 0x02, 0x02, 0x02, b'x', 0x00, 0x02, //
+            0x02, 0x00, 0x00, 0x00, 0x00,
+        ];
+        let value = schema.decode(&bytes).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "id": -2, "label": "ab", "color": "GREEN", "tags": ["x"],
+                "next": {"id": 1, "label": null, "color": "RED", "tags": [], "next": null}
+            })
+        );
+
+        assert!(schema.decode(&bytes[..5]).is_err());
+        assert!(AvroSchema::parse(r#"{"type": "record", "name": "A", "fields": [{"name": "b", "type": "B"}]}"#).is_err());
+    }
+
+    #[test]
+    fn test_decode_limits() {
+        // A record that contains itself can never end; neither can a payload nesting too deep
+        let endless = AvroSchema::parse(r#"{"type": "record", "name": "A", "fields": [{"name": "a", "type": "A"}]}"#).unwrap();
+        assert!(endless.decode(&[]).unwrap_err().contains("nested deeper"));
+        let list = AvroSchema::parse(r#"{"type": "record", "name": "L", "fields": [{"name": "next", "type": ["null", "L"]}]}"#)
+            .unwrap();
+        assert!(list.decode(&[0x02; 100_000]).unwrap_err().contains("nested deeper"));
+        assert!(list.decode(&[0x02, 0x02, 0x00]).is_ok());
+
+        // A block count of i64::MIN, zig-zag encoded
+        let ints = AvroSchema::parse(r#"{"type": "array", "items": "int"}"#).unwrap();
+        let min_count = [0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01, 0x00];
+        assert!(ints.decode(&min_count).unwrap_err().contains("overruns"));
+    }
+}
@@ -1,27 +1,158 @@
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
 
+use futures::StreamExt;
 use rdkafka::admin::{AdminClient, AdminOptions, AlterConfig, NewPartitions, NewTopic, ResourceSpecifier, TopicReplication};
-use rdkafka::client::ClientContext;
+use rdkafka::client::{ClientContext, OAuthToken};
 use rdkafka::config::{ClientConfig, RDKafkaLogLevel};
-use rdkafka::consumer::{BaseConsumer, Consumer, ConsumerContext};
+use rdkafka::consumer::{BaseConsumer, CommitMode, Consumer, ConsumerContext};
+use rdkafka::error::RDKafkaErrorCode;
 use rdkafka::message::{Headers, Message};
-use rdkafka::producer::{FutureProducer, FutureRecord, ProducerContext};
+use rdkafka::producer::{FutureProducer, FutureRecord, Producer, ProducerContext};
 use rdkafka::TopicPartitionList;
 
 use crate::app::state::{
     BrokerInfo, ConsumerGroupDetail, ConsumerGroupInfo, GroupMember, KafkaMessage, OffsetMode,
-    PartitionInfo, PartitionOffset, TopicDetail, TopicInfo, TopicPartition,
+    PartitionInfo, PartitionOffset, TimestampType, TopicDetail, TopicInfo, TopicPartition,
 };
 use crate::error::{AppError, AppResult};
+use crate::kafka::admin_ffi::ClusterNodes;
 use crate::kafka::config::{KafkaConfig, KafkaSaslMechanism, SecurityConfig};
+use crate::kafka::oauth::{OAuthSource, TOKEN_RETRY};
+use crate::kafka::scan::{ScanPattern, ScanProgress, ScanStop, SCAN_MAX_MATCHES, SCAN_MAX_SCANNED};
+use crate::kafka::schema_registry::SchemaRegistry;
 
-/// Custom Kafka context that routes rdkafka logs to tracing.
+/// Maximum number of consumer groups whose lag is computed in parallel.
+const GROUP_LAG_CONCURRENCY: usize = 8;
+
+/// Upper bound on the lag lookup for a single consumer group.
+const GROUP_LAG_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Time allowed for any message fetch, however small
+const FETCH_DEADLINE_BASE: Duration = Duration::from_secs(5);
+
+/// Extra fetch time allowed per requested message
+const FETCH_DEADLINE_PER_MESSAGE: Duration = Duration::from_millis(10);
+
+/// Upper bound on the fetch deadline, so a huge limit can't stall the browser
+const FETCH_DEADLINE_MAX: Duration = Duration::from_secs(30);
+
+/// Consecutive empty 100ms polls, after the first message, taken to mean the end of the topic
+const TAIL_EMPTY_POLLS: u32 = 10;
+
+/// Consecutive empty 100ms polls after which a scan gives up on the remaining partitions
+const SCAN_IDLE_POLLS: u32 = 50;
+
+/// How often a scan reports its progress
+const SCAN_PROGRESS_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Group id of the short-lived consumers behind metadata, watermark and admin calls,
+/// unless the profile names a consumer group
+const TEMP_GROUP_ID: &str = "kafka-tui-temp";
+
+/// Group ids this tool has used for itself; `kafka-tui-browser` came from older releases
+/// and may linger on a cluster until its offsets expire
+const OWN_GROUP_IDS: [&str; 2] = [TEMP_GROUP_ID, "kafka-tui-browser"];
+
+/// Whether a listed group is one of the tool's own rather than something to monitor.
+fn is_own_group(group_id: &str) -> bool {
+    OWN_GROUP_IDS.contains(&group_id)
+}
+
+/// How long a fetch of `limit` messages may keep polling.
+fn fetch_deadline(limit: usize) -> Duration {
+    let extra = FETCH_DEADLINE_PER_MESSAGE.saturating_mul(u32::try_from(limit).unwrap_or(u32::MAX));
+    FETCH_DEADLINE_BASE.saturating_add(extra).min(FETCH_DEADLINE_MAX)
+}
+
+/// Messages each of `partitions` partitions starts back from its high watermark, so
+/// together they hold the newest `limit` messages wherever those landed.
+fn latest_backoff(limit: usize, partitions: usize) -> i64 {
+    limit.div_ceil(partitions.max(1)) as i64
+}
+
+/// Most watermark queries in flight at once
+const WATERMARK_WORKERS: usize = 16;
+
+/// Low and high watermarks of each of `partitions`. Every query is a broker round trip,
+/// so they are spread over a few threads instead of run one after another.
+fn fetch_watermarks<C: ConsumerContext>(
+    consumer: &BaseConsumer<C>,
+    topic: &str,
+    partitions: &[i32],
+    timeout: Duration,
+) -> AppResult<HashMap<i32, (i64, i64)>> {
+    if partitions.is_empty() {
+        return Ok(HashMap::new());
+    }
+    let per_worker = partitions.len().div_ceil(WATERMARK_WORKERS);
+    std::thread::scope(|s| {
+        let workers: Vec<_> = partitions
+            .chunks(per_worker)
+            .map(|chunk| {
+                s.spawn(move || {
+                    chunk
+                        .iter()
+                        .map(|&p| consumer.fetch_watermarks(topic, p, timeout).map(|w| (p, w)))
+                        .collect::<Result<Vec<_>, _>>()
+                })
+            })
+            .collect();
+        let mut watermarks = HashMap::new();
+        for worker in workers {
+            let found = worker.join().map_err(|_| AppError::Kafka("Watermark query panicked".into()))?;
+            watermarks.extend(found.map_err(|e| AppError::Kafka(format!("Watermarks: {}", e)))?);
+        }
+        Ok(watermarks)
+    })
+}
+
+/// Keep the newest `limit` messages by timestamp, falling back to partition and offset.
+fn keep_latest(messages: &mut Vec<KafkaMessage>, limit: usize) {
+    messages.sort_by_key(|m| (m.timestamp, m.partition, m.offset));
+    let excess = messages.len().saturating_sub(limit);
+    messages.drain(..excess);
+}
+
+/// Partition and offset a produced message was written to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Delivered {
+    pub partition: i32,
+    pub offset: i64,
+}
+
+/// Result of a bounded message fetch
+#[derive(Debug, Clone)]
+pub struct FetchedMessages {
+    pub messages: Vec<KafkaMessage>,
+    /// The deadline passed before `limit` messages or the end of the topic were reached
+    pub timed_out: bool,
+}
+
+/// Partitions `assign_partitions` set a consumer up to read
+struct Assignment {
+    partitions: usize,
+    /// Some partition has records at or after its start offset, as of the watermarks
+    readable: bool,
+}
+
+/// Custom Kafka context that routes rdkafka logs to tracing and hands out OAUTHBEARER tokens.
 #[derive(Clone)]
-struct LoggingContext;
+struct LoggingContext {
+    oauth: Option<Arc<OAuthSource>>,
+}
+
+impl LoggingContext {
+    fn new(config: &KafkaConfig) -> Self {
+        Self { oauth: OAuthSource::from_security(&config.security).map(Arc::new) }
+    }
+}
 
 impl ClientContext for LoggingContext {
+    const ENABLE_REFRESH_OAUTH_TOKEN: bool = true;
+
     fn log(&self, level: RDKafkaLogLevel, fac: &str, msg: &str) {
         match level {
             RDKafkaLogLevel::Emerg | RDKafkaLogLevel::Alert | RDKafkaLogLevel::Critical => {
@@ -45,6 +176,12 @@ impl ClientContext for LoggingContext {
     fn error(&self, err: rdkafka::error::KafkaError, msg: &str) {
         tracing::warn!(target: "rdkafka", error = %err, "{}", msg);
     }
+
+    fn generate_oauth_token(&self, _: Option<&str>) -> Result<OAuthToken, Box<dyn std::error::Error>> {
+        let source = self.oauth.as_ref().ok_or("OAUTHBEARER is not configured for this connection")?;
+        let token = source.token().inspect_err(|e| tracing::warn!(error = %e, "OAuth token refresh failed"))?;
+        Ok(OAuthToken { token: token.value, principal_name: token.principal, lifetime_ms: token.expires_at_ms })
+    }
 }
 
 impl ConsumerContext for LoggingContext {}
@@ -58,6 +195,8 @@ pub struct KafkaClient {
     config: KafkaConfig,
     admin: AdminClient<LoggingContext>,
     producer: FutureProducer<LoggingContext>,
+    /// Decodes Avro values when the profile names a registry
+    registry: Option<Arc<SchemaRegistry>>,
 }
 
 impl KafkaClient {
@@ -65,15 +204,64 @@ impl KafkaClient {
         let mut base = Self::base_config(&config);
 
         let admin = base.clone()
-            .create_with_context(LoggingContext)
+            .create_with_context(LoggingContext::new(&config))
             .map_err(|e| AppError::Kafka(format!("Admin client: {}", e)))?;
 
         let producer = base
             .set("message.timeout.ms", "5000")
-            .create_with_context(LoggingContext)
+            .set("acks", config.producer.acks.config_value())
+            .set("enable.idempotence", config.producer.idempotence.to_string())
+            .set("compression.type", config.producer.compression.config_value())
+            .create_with_context(LoggingContext::new(&config))
             .map_err(|e| AppError::Kafka(format!("Producer: {}", e)))?;
 
-        Ok(Arc::new(Self { config, admin, producer }))
+        let registry = config
+            .schema_registry_url
+            .as_deref()
+            .map(|url| Arc::new(SchemaRegistry::new(url, config.schema_registry_credentials.clone())));
+
+        let client = Arc::new(Self { config, admin, producer, registry });
+        if let Some(source) = client.admin.inner().context().oauth.clone() {
+            Self::spawn_admin_token_refresh(&client, source);
+        }
+        Ok(client)
+    }
+
+    /// Keep the admin client supplied with OAUTHBEARER tokens until the client is dropped.
+    ///
+    /// Unlike the producer and consumers, the admin client never serves librdkafka's
+    /// refresh requests, so its token is set directly and renewed on a timer.
+    fn spawn_admin_token_refresh(client: &Arc<Self>, source: Arc<OAuthSource>) {
+        let client = Arc::downgrade(client);
+        let spawned = std::thread::Builder::new().name("oauth-refresh".into()).spawn(move || loop {
+            let Some(kafka) = client.upgrade() else { return };
+            let client_ptr = kafka.admin.inner().native_ptr() as usize;
+            let wait = match source.token() {
+                Ok(token) => {
+                    if let Err(e) = super::admin_ffi::set_oauth_token(client_ptr, &token) {
+                        tracing::warn!(error = %e, "Could not set the admin client's OAuth token");
+                    }
+                    token.refresh_in()
+                }
+                Err(e) => {
+                    tracing::warn!(error = %e, "OAuth token refresh failed");
+                    TOKEN_RETRY
+                }
+            };
+            drop(kafka);
+
+            // Sleep in short steps so a dropped client doesn't leave the thread behind for long
+            let deadline = std::time::Instant::now() + wait;
+            while std::time::Instant::now() < deadline {
+                if client.strong_count() == 0 {
+                    return;
+                }
+                std::thread::sleep(Duration::from_secs(1));
+            }
+        });
+        if let Err(e) = spawned {
+            tracing::warn!(error = %e, "Could not start the OAuth token refresh thread");
+        }
     }
 
     fn base_config(config: &KafkaConfig) -> ClientConfig {
@@ -124,17 +312,36 @@ impl KafkaClient {
                     .set("sasl.password", password);
                 if let Some(v) = ca_location { c.set("ssl.ca.location", v); }
             }
+            SecurityConfig::OAuthBearer { ca_location, .. } => {
+                // Tokens come from LoggingContext::generate_oauth_token
+                c.set("security.protocol", "SASL_SSL")
+                    .set("sasl.mechanism", "OAUTHBEARER");
+                if let Some(v) = ca_location { c.set("ssl.ca.location", v); }
+            }
         }
         c
     }
 
+    /// OAUTHBEARER consumers get their first token from a refresh request librdkafka
+    /// queues at startup, which only a poll serves. Consumers used for metadata and
+    /// offsets never poll otherwise, so serve it before handing them out.
+    fn serve_token_request(consumer: BaseConsumer<LoggingContext>) -> BaseConsumer<LoggingContext> {
+        if consumer.context().oauth.is_some() {
+            let _ = consumer.poll(Duration::from_millis(100));
+        }
+        consumer
+    }
+
     /// Create a temporary consumer for blocking operations.
+    ///
+    /// It never subscribes, so it doesn't join or heartbeat to the group it's configured with.
     fn create_temp_consumer(config: &KafkaConfig) -> AppResult<BaseConsumer<LoggingContext>> {
-        let group_id = config.consumer_group.as_deref().unwrap_or("kafka-tui-temp");
+        let group_id = config.consumer_group.as_deref().unwrap_or(TEMP_GROUP_ID);
         Self::base_config(config)
             .set("group.id", group_id)
             .set("enable.auto.commit", "false")
-            .create_with_context(LoggingContext)
+            .create_with_context(LoggingContext::new(config))
+            .map(Self::serve_token_request)
             .map_err(|e| AppError::Kafka(format!("Temp consumer: {}", e)))
     }
 
@@ -142,31 +349,59 @@ impl KafkaClient {
         let config = self.config.clone();
         tokio::task::spawn_blocking(move || {
             let consumer = Self::create_temp_consumer(&config)?;
-            consumer
-                .fetch_metadata(None, Duration::from_secs(10))
-                .map_err(|e| AppError::Kafka(format!("Connection failed: {}", e)))?;
-            Ok(())
+            let metadata = consumer
+                .fetch_metadata(None, config.metadata_timeout())
+                .map_err(|e| connect_error(&config.brokers, e))?;
+            let broker_ids: Vec<i32> = metadata.brokers().iter().map(|b| b.id()).collect();
+            let timeout_ms = i32::try_from(config.metadata_timeout().as_millis()).unwrap_or(i32::MAX);
+            let cluster = super::admin_ffi::describe_cluster(consumer.client().native_ptr() as usize, timeout_ms);
+            check_cluster_metadata(&config.brokers, &broker_ids, cluster.as_ref())
         })
         .await
         .map_err(|e| AppError::Kafka(format!("Connection task failed: {}", e)))?
     }
 
+    /// Round-trip time of a metadata request over the existing admin connection.
+    pub async fn ping(self: &Arc<Self>) -> AppResult<Duration> {
+        let client = self.clone();
+        tokio::task::spawn_blocking(move || {
+            let started = std::time::Instant::now();
+            client.admin.inner()
+                .fetch_metadata(None, Duration::from_secs(5))
+                .map_err(|e| connect_error(&client.config.brokers, e))?;
+            Ok(started.elapsed())
+        })
+        .await
+        .map_err(|e| AppError::Kafka(format!("Ping task failed: {}", e)))?
+    }
+
     pub async fn list_topics(&self) -> AppResult<Vec<TopicInfo>> {
         let config = self.config.clone();
         tokio::task::spawn_blocking(move || {
             let consumer = Self::create_temp_consumer(&config)?;
             let metadata = consumer
-                .fetch_metadata(None, Duration::from_secs(30))
+                .fetch_metadata(None, config.metadata_timeout())
                 .map_err(|e| AppError::Kafka(format!("Metadata fetch failed: {}", e)))?;
 
+            // Degraded topics keep their entry and carry the error instead of failing the whole list
             let mut topics: Vec<_> = metadata.topics().iter().map(|t| {
                 let partitions = t.partitions();
+                let error = t.error()
+                    .map(|e| RDKafkaErrorCode::from(e).to_string())
+                    .or_else(|| partitions.iter().find_map(|p| {
+                        p.error().map(|e| format!("partition {}: {}", p.id(), RDKafkaErrorCode::from(e)))
+                    }));
+                if let Some(error) = &error {
+                    tracing::warn!(topic = t.name(), error = %error, "Topic metadata error");
+                }
                 TopicInfo {
                     name: t.name().to_string(),
                     partition_count: partitions.len() as i32,
                     replication_factor: partitions.first().map(|p| p.replicas().len() as i32).unwrap_or(0),
                     message_count: None,
                     is_internal: t.name().starts_with("__"),
+                    error,
+                    has_under_replicated: partitions.iter().any(|p| p.isr().len() < p.replicas().len()),
                 }
             }).collect();
 
@@ -177,8 +412,84 @@ impl KafkaClient {
         .map_err(|e| AppError::Kafka(format!("List topics task failed: {}", e)))?
     }
 
-    pub async fn create_topic(&self, name: &str, partitions: i32, replication: i32) -> AppResult<()> {
-        let topic = NewTopic::new(name, partitions, TopicReplication::Fixed(replication));
+    /// Approximate message count per topic: the sum of `high - low` watermarks over its partitions.
+    /// Topics whose watermarks can't be fetched are left out of the result.
+    pub async fn topic_message_counts(&self, topics: Vec<String>) -> AppResult<Vec<(String, i64)>> {
+        let config = self.config.clone();
+        tokio::task::spawn_blocking(move || {
+            let consumer = Self::create_temp_consumer(&config)?;
+            let metadata = consumer
+                .fetch_metadata(None, config.metadata_timeout())
+                .map_err(|e| AppError::Kafka(format!("Metadata fetch failed: {}", e)))?;
+
+            let counts = metadata
+                .topics()
+                .iter()
+                .filter(|t| topics.iter().any(|name| name == t.name()))
+                .filter_map(|t| {
+                    let ids: Vec<i32> = t.partitions().iter().map(|p| p.id()).collect();
+                    let count = fetch_watermarks(&consumer, t.name(), &ids, Duration::from_secs(5))
+                        .map(|w| w.values().map(|&(low, high)| (high - low).max(0)).sum::<i64>());
+                    match count {
+                        Ok(count) => Some((t.name().to_string(), count)),
+                        Err(e) => {
+                            tracing::warn!(topic = t.name(), error = %e, "Watermark fetch failed");
+                            None
+                        }
+                    }
+                })
+                .collect();
+            Ok(counts)
+        })
+        .await
+        .map_err(|e| AppError::Kafka(format!("Message count task failed: {}", e)))?
+    }
+
+    /// Sum of the partition high watermarks of every non-internal topic.
+    ///
+    /// Two samples apart in time give the number of messages appended in between.
+    pub async fn topic_high_watermarks(&self) -> AppResult<Vec<(String, i64)>> {
+        let config = self.config.clone();
+        tokio::task::spawn_blocking(move || {
+            let consumer = Self::create_temp_consumer(&config)?;
+            let metadata = consumer
+                .fetch_metadata(None, config.metadata_timeout())
+                .map_err(|e| AppError::Kafka(format!("Metadata fetch failed: {}", e)))?;
+
+            let watermarks = metadata
+                .topics()
+                .iter()
+                .filter(|t| !t.name().starts_with("__"))
+                .filter_map(|t| {
+                    let ids: Vec<i32> = t.partitions().iter().map(|p| p.id()).collect();
+                    let high = fetch_watermarks(&consumer, t.name(), &ids, Duration::from_secs(5))
+                        .map(|w| w.values().map(|&(_, high)| high.max(0)).sum::<i64>());
+                    match high {
+                        Ok(high) => Some((t.name().to_string(), high)),
+                        Err(e) => {
+                            tracing::warn!(topic = t.name(), error = %e, "Watermark fetch failed");
+                            None
+                        }
+                    }
+                })
+                .collect();
+            Ok(watermarks)
+        })
+        .await
+        .map_err(|e| AppError::Kafka(format!("Watermark sample task failed: {}", e)))?
+    }
+
+    pub async fn create_topic(
+        &self,
+        name: &str,
+        partitions: i32,
+        replication: i32,
+        configs: &[(String, String)],
+    ) -> AppResult<()> {
+        let topic = configs.iter().fold(
+            NewTopic::new(name, partitions, TopicReplication::Fixed(replication)),
+            |topic, (key, value)| topic.set(key, value),
+        );
         let opts = AdminOptions::new().operation_timeout(Some(Duration::from_secs(30)));
 
         let results = self.admin.create_topics(&[topic], &opts).await
@@ -206,61 +517,55 @@ impl KafkaClient {
         Ok(())
     }
 
+    /// Delete several topics in one request, returning those the brokers refused and why.
+    pub async fn delete_topics(&self, names: &[String]) -> AppResult<Vec<(String, String)>> {
+        let opts = AdminOptions::new().operation_timeout(Some(Duration::from_secs(30)));
+        let refs: Vec<&str> = names.iter().map(String::as_str).collect();
+
+        let results = self.admin.delete_topics(&refs, &opts).await
+            .map_err(|e| AppError::Kafka(format!("Delete topics failed: {}", e)))?;
+
+        Ok(results
+            .into_iter()
+            .filter_map(|r| r.err().map(|(name, e)| (name, format!("{:?}", e))))
+            .collect())
+    }
+
     pub async fn fetch_messages(
         &self,
         topic: &str,
         offset_mode: OffsetMode,
         partition: Option<i32>,
         limit: usize,
-    ) -> AppResult<Vec<KafkaMessage>> {
+    ) -> AppResult<FetchedMessages> {
         tracing::debug!(topic, ?offset_mode, ?partition, limit, "Fetching messages");
         let config = self.config.clone();
+        let registry = self.registry.clone();
         let topic = topic.to_string();
 
         tokio::task::spawn_blocking(move || {
             let consumer = Self::create_temp_consumer(&config)?;
-
-            let metadata = consumer
-                .fetch_metadata(Some(&topic), Duration::from_secs(10))
-                .map_err(|e| AppError::Kafka(format!("Topic metadata: {}", e)))?;
-
-            let topic_meta = metadata.topics().first()
-                .ok_or_else(|| AppError::Kafka("Topic not found".into()))?;
-
-            let partitions: Vec<i32> = partition
-                .map(|p| vec![p])
-                .unwrap_or_else(|| topic_meta.partitions().iter().map(|p| p.id()).collect());
-
-            let mut tpl = TopicPartitionList::new();
-            for &p in &partitions {
-                tpl.add_partition(&topic, p);
-                let offset = match &offset_mode {
-                    OffsetMode::Earliest => rdkafka::Offset::Beginning,
-                    OffsetMode::Specific(o) => rdkafka::Offset::Offset(*o),
-                    OffsetMode::Timestamp(ts) => rdkafka::Offset::Offset(ts.timestamp_millis()),
-                    OffsetMode::Latest => {
-                        let (low, high) = consumer
-                            .fetch_watermarks(&topic, p, Duration::from_secs(10))
-                            .map_err(|e| AppError::Kafka(format!("Watermarks: {}", e)))?;
-                        tracing::debug!(topic, partition = p, low_watermark = low, high_watermark = high, "Watermark fetched");
-                        rdkafka::Offset::Offset((high - limit as i64).max(low))
-                    }
-                };
-                tpl.set_partition_offset(&topic, p, offset)
-                    .map_err(|e| AppError::Kafka(format!("Set offset: {}", e)))?;
+            let assignment = Self::assign_partitions(&consumer, &topic, &offset_mode, partition, limit)?;
+            if !assignment.readable {
+                // Polling would only wait out the deadline
+                tracing::debug!(topic, "Nothing to read from the start offsets");
+                consumer.unassign().ok();
+                return Ok(FetchedMessages { messages: Vec::new(), timed_out: false });
             }
+            let partitions = assignment.partitions;
+            // Latest reads every partition's share of the limit, then keeps the newest overall
+            let latest = matches!(offset_mode, OffsetMode::Latest);
+            let target = if latest { latest_backoff(limit, partitions) as usize * partitions } else { limit };
 
-            consumer.assign(&tpl)
-                .map_err(|e| AppError::Kafka(format!("Assign: {}", e)))?;
-
-            let mut messages = Vec::with_capacity(limit);
-            let deadline = std::time::Instant::now() + Duration::from_secs(5);
+            let mut messages = Vec::with_capacity(target);
+            let deadline = std::time::Instant::now() + fetch_deadline(limit);
             let mut consecutive_nones: u32 = 0;
+            let mut reached_tail = false;
 
-            while messages.len() < limit && std::time::Instant::now() < deadline {
+            while messages.len() < target && std::time::Instant::now() < deadline {
                 match consumer.poll(Duration::from_millis(100)) {
                     Some(Ok(msg)) => {
-                        messages.push(Self::parse_message(&msg));
+                        messages.push(Self::parse_message(&msg, registry.as_deref()));
                         consecutive_nones = 0;
                     }
                     Some(Err(e)) => {
@@ -269,48 +574,264 @@ impl KafkaClient {
                     None if messages.is_empty() => continue,
                     None => {
                         consecutive_nones += 1;
-                        if consecutive_nones >= 5 {
+                        // A single empty poll can be a slow broker; several in a row mean the tail
+                        if consecutive_nones >= TAIL_EMPTY_POLLS {
+                            reached_tail = true;
                             break;
                         }
                     }
                 }
             }
+            let timed_out = !reached_tail && messages.len() < target;
+            if latest {
+                keep_latest(&mut messages, limit);
+            }
 
-            tracing::debug!(topic, fetched = messages.len(), "Messages fetched");
+            tracing::debug!(topic, fetched = messages.len(), timed_out, "Messages fetched");
             consumer.unassign().ok();
-            Ok(messages)
+            Ok(FetchedMessages { messages, timed_out })
         })
         .await
         .map_err(|e| AppError::Kafka(format!("Fetch messages task failed: {}", e)))?
     }
 
-    fn parse_message(msg: &rdkafka::message::BorrowedMessage<'_>) -> KafkaMessage {
+    /// Assign the topic's partitions (or a single one) to `consumer`, positioned per `offset_mode`.
+    ///
+    /// For `OffsetMode::Latest`, each partition starts its share of `latest_limit` messages
+    /// before its high watermark (clamped to the low watermark); a limit of zero tails new
+    /// records only.
+    fn assign_partitions(
+        consumer: &BaseConsumer<LoggingContext>,
+        topic: &str,
+        offset_mode: &OffsetMode,
+        partition: Option<i32>,
+        latest_limit: usize,
+    ) -> AppResult<Assignment> {
+        let metadata = consumer
+            .fetch_metadata(Some(topic), Duration::from_secs(10))
+            .map_err(|e| AppError::Kafka(format!("Topic metadata: {}", e)))?;
+
+        let topic_meta = metadata.topics().first()
+            .ok_or_else(|| AppError::Kafka("Topic not found".into()))?;
+
+        let mut partitions: Vec<i32> = partition
+            .map(|p| vec![p])
+            .unwrap_or_else(|| topic_meta.partitions().iter().map(|p| p.id()).collect());
+        partitions.retain(|&p| offset_mode.reads_partition(p));
+
+        let watermarks = fetch_watermarks(consumer, topic, &partitions, Duration::from_secs(10))?;
+        let mut tpl = TopicPartitionList::new();
+        let mut readable = false;
+        for &p in &partitions {
+            tpl.add_partition(topic, p);
+            let (low, high) = watermarks[&p];
+            tracing::debug!(topic, partition = p, low_watermark = low, high_watermark = high, "Watermark fetched");
+            let offset = match offset_mode {
+                OffsetMode::Earliest => rdkafka::Offset::Beginning,
+                // Below the log start would reset to the end, so begin at the oldest record instead
+                OffsetMode::Specific(o) => rdkafka::Offset::Offset((*o).max(low)),
+                OffsetMode::Partitions(starts) => rdkafka::Offset::Offset(starts[&p].max(low)),
+                OffsetMode::Timestamp(ts) => {
+                    let mut query = TopicPartitionList::new();
+                    query.add_partition_offset(topic, p, rdkafka::Offset::Offset(ts.timestamp_millis()))
+                        .map_err(|e| AppError::Kafka(format!("Set offset: {}", e)))?;
+                    let found = consumer
+                        .offsets_for_times(query, Duration::from_secs(10))
+                        .map_err(|e| AppError::Kafka(format!("Offsets for time: {}", e)))?;
+                    // No record at or after the timestamp: start from the end
+                    match found.find_partition(topic, p).map(|e| e.offset()) {
+                        Some(offset @ rdkafka::Offset::Offset(_)) => offset,
+                        _ => rdkafka::Offset::End,
+                    }
+                }
+                OffsetMode::Latest => {
+                    rdkafka::Offset::Offset((high - latest_backoff(latest_limit, partitions.len())).max(low))
+                }
+            };
+            readable |= match offset {
+                rdkafka::Offset::Offset(o) => o.max(low) < high,
+                rdkafka::Offset::End => false,
+                _ => low < high,
+            };
+            tpl.set_partition_offset(topic, p, offset)
+                .map_err(|e| AppError::Kafka(format!("Set offset: {}", e)))?;
+        }
+
+        consumer.assign(&tpl)
+            .map_err(|e| AppError::Kafka(format!("Assign: {}", e)))?;
+        Ok(Assignment { partitions: partitions.len(), readable })
+    }
+
+    /// Continuously consume a topic, invoking `on_message` for every record until `cancel` is set.
+    pub async fn stream_messages<F>(
+        &self,
+        topic: &str,
+        offset_mode: OffsetMode,
+        partition: Option<i32>,
+        cancel: Arc<AtomicBool>,
+        mut on_message: F,
+    ) -> AppResult<()>
+    where
+        F: FnMut(KafkaMessage) + Send + 'static,
+    {
+        tracing::debug!(topic, ?offset_mode, ?partition, "Starting message stream");
+        let config = self.config.clone();
+        let registry = self.registry.clone();
+        let topic = topic.to_string();
+
+        tokio::task::spawn_blocking(move || {
+            let consumer = Self::create_temp_consumer(&config)?;
+            Self::assign_partitions(&consumer, &topic, &offset_mode, partition, 0)?;
+
+            while !cancel.load(Ordering::Relaxed) {
+                match consumer.poll(Duration::from_millis(100)) {
+                    Some(Ok(msg)) => on_message(Self::parse_message(&msg, registry.as_deref())),
+                    Some(Err(e)) => tracing::warn!(topic, error = %e, "Poll error"),
+                    None => {}
+                }
+            }
+
+            tracing::debug!(topic, "Message stream stopped");
+            consumer.unassign().ok();
+            Ok(())
+        })
+        .await
+        .map_err(|e| AppError::Kafka(format!("Stream messages task failed: {}", e)))?
+    }
+
+    /// Read a topic from `offset_mode` up to its current high watermarks, reporting messages that
+    /// match `pattern` through `on_progress` until a cap is hit or `cancel` is set.
+    pub async fn scan_messages<F>(
+        &self,
+        topic: &str,
+        offset_mode: OffsetMode,
+        partition: Option<i32>,
+        pattern: ScanPattern,
+        cancel: Arc<AtomicBool>,
+        mut on_progress: F,
+    ) -> AppResult<(u64, ScanStop)>
+    where
+        F: FnMut(ScanProgress) + Send + 'static,
+    {
+        tracing::debug!(topic, ?offset_mode, ?partition, ?pattern, "Starting topic scan");
+        let config = self.config.clone();
+        let registry = self.registry.clone();
+        let topic = topic.to_string();
+
+        tokio::task::spawn_blocking(move || {
+            let consumer = Self::create_temp_consumer(&config)?;
+            Self::assign_partitions(&consumer, &topic, &offset_mode, partition, 0)?;
+
+            // Records produced once the scan is running are not waited for
+            let assignment = consumer.assignment()
+                .map_err(|e| AppError::Kafka(format!("Assignment: {}", e)))?;
+            let assigned: Vec<i32> = assignment.elements().iter().map(|e| e.partition()).collect();
+            let mut remaining: HashMap<i32, i64> = fetch_watermarks(&consumer, &topic, &assigned, Duration::from_secs(10))?
+                .into_iter()
+                .filter(|&(_, (low, high))| high > low)
+                .map(|(p, (_, high))| (p, high))
+                .collect();
+
+            let mut scanned: u64 = 0;
+            let mut matched: usize = 0;
+            let mut found = Vec::new();
+            let mut reported = std::time::Instant::now();
+            let mut idle_polls: u32 = 0;
+            let stop = loop {
+                if cancel.load(Ordering::Relaxed) {
+                    break ScanStop::Cancelled;
+                }
+                if matched >= SCAN_MAX_MATCHES {
+                    break ScanStop::MatchCap;
+                }
+                if scanned >= SCAN_MAX_SCANNED {
+                    break ScanStop::ScanCap;
+                }
+                if remaining.is_empty() {
+                    break ScanStop::EndOfTopic;
+                }
+                match consumer.poll(Duration::from_millis(100)) {
+                    Some(Ok(msg)) => {
+                        idle_polls = 0;
+                        scanned += 1;
+                        if remaining.get(&msg.partition()).is_some_and(|&high| msg.offset() + 1 >= high) {
+                            remaining.remove(&msg.partition());
+                        }
+                        let msg = Self::parse_message(&msg, registry.as_deref());
+                        if pattern.is_match(&msg) {
+                            matched += 1;
+                            found.push(msg);
+                        }
+                    }
+                    Some(Err(e)) => tracing::warn!(topic, error = %e, "Poll error"),
+                    // Partitions positioned past their last record never deliver anything
+                    None => {
+                        idle_polls += 1;
+                        if idle_polls >= SCAN_IDLE_POLLS {
+                            break ScanStop::EndOfTopic;
+                        }
+                    }
+                }
+                if reported.elapsed() >= SCAN_PROGRESS_INTERVAL {
+                    on_progress(ScanProgress { scanned, matches: std::mem::take(&mut found) });
+                    reported = std::time::Instant::now();
+                }
+            };
+            on_progress(ScanProgress { scanned, matches: found });
+
+            tracing::debug!(topic, scanned, matched, ?stop, "Topic scan finished");
+            consumer.unassign().ok();
+            Ok((scanned, stop))
+        })
+        .await
+        .map_err(|e| AppError::Kafka(format!("Scan messages task failed: {}", e)))?
+    }
+
+    fn parse_message(msg: &rdkafka::message::BorrowedMessage<'_>, registry: Option<&SchemaRegistry>) -> KafkaMessage {
+        let payload = msg.payload().unwrap_or_default();
+        // Anything the registry can't decode is shown as (lossy) text
+        let (schema_id, value) = match registry.and_then(|r| r.decode(payload)) {
+            Some((id, json)) => (Some(id), json),
+            None => (None, String::from_utf8_lossy(payload).into()),
+        };
         KafkaMessage {
             partition: msg.partition(),
             offset: msg.offset(),
             timestamp: msg.timestamp().to_millis()
                 .and_then(chrono::DateTime::from_timestamp_millis),
+            timestamp_type: match msg.timestamp() {
+                rdkafka::Timestamp::LogAppendTime(_) => TimestampType::LogAppendTime,
+                _ => TimestampType::CreateTime,
+            },
             key: msg.key().map(|k| String::from_utf8_lossy(k).into()),
-            value: msg.payload().map(|v| String::from_utf8_lossy(v).into()).unwrap_or_default(),
+            value,
             headers: msg.headers().map(|h| {
                 h.iter()
                     .filter_map(|hdr| hdr.value.map(|v| (hdr.key.into(), String::from_utf8_lossy(v).into())))
                     .collect()
             }).unwrap_or_default(),
+            key_size: msg.key_len(),
+            value_size: msg.payload_len(),
+            raw_value: payload.to_vec(),
+            schema_id,
         }
     }
 
     pub async fn produce_message(
         &self,
         topic: &str,
+        partition: Option<i32>,
         key: Option<&str>,
         value: &str,
-        headers: &HashMap<String, String>,
-    ) -> AppResult<()> {
+        headers: &[(String, String)],
+    ) -> AppResult<Delivered> {
         let mut record: FutureRecord<'_, str, str> = FutureRecord::to(topic).payload(value);
         if let Some(k) = key {
             record = record.key(k);
         }
+        if let Some(p) = partition {
+            record = record.partition(p);
+        }
 
         let owned_headers = headers.iter().fold(
             rdkafka::message::OwnedHeaders::new(),
@@ -323,22 +844,72 @@ impl KafkaClient {
             .map_err(|(e, _)| AppError::Kafka(format!("Produce failed: {}", e)))?;
 
         tracing::debug!(topic, partition = delivery.partition, offset = delivery.offset, "Message produced");
-        Ok(())
+        Ok(Delivered { partition: delivery.partition, offset: delivery.offset })
+    }
+
+    /// Wait up to `timeout` for queued produce requests to be delivered.
+    ///
+    /// Returns how many were delivered and how many were still in flight when the
+    /// timeout ran out; those are lost once the client is dropped.
+    pub async fn flush(self: &Arc<Self>, timeout: Duration) -> AppResult<(i32, i32)> {
+        let client = self.clone();
+        tokio::task::spawn_blocking(move || {
+            let pending = client.producer.in_flight_count();
+            if pending == 0 {
+                return (0, 0);
+            }
+            if let Err(e) = client.producer.flush(timeout) {
+                tracing::warn!(error = %e, "Producer flush did not complete");
+            }
+            let dropped = client.producer.in_flight_count();
+            (pending - dropped, dropped)
+        })
+        .await
+        .map_err(|e| AppError::Kafka(format!("Flush task failed: {}", e)))
+    }
+
+    /// Send `count` copies of a message with up to 64 deliveries in flight, stopping at the first failure.
+    ///
+    /// Returns how many were produced and where the last one landed.
+    pub async fn produce_messages(
+        &self,
+        topic: &str,
+        partition: Option<i32>,
+        key: Option<&str>,
+        value: &str,
+        headers: &[(String, String)],
+        count: usize,
+    ) -> AppResult<(usize, Option<Delivered>)> {
+        let mut sends = futures::stream::iter(0..count)
+            .map(|_| self.produce_message(topic, partition, key, value, headers))
+            .buffered(64);
+
+        let mut produced = 0;
+        let mut last = None;
+        while let Some(result) = sends.next().await {
+            match result {
+                Ok(delivered) => last = Some(delivered),
+                Err(e) => return Err(AppError::Kafka(format!("{} of {} messages produced; {}", produced, count, e))),
+            }
+            produced += 1;
+        }
+        Ok((produced, last))
     }
 
     pub async fn list_consumer_groups(&self) -> AppResult<Vec<ConsumerGroupInfo>> {
         let config = self.config.clone();
-        tokio::task::spawn_blocking(move || {
+        let groups: Vec<ConsumerGroupInfo> = tokio::task::spawn_blocking(move || {
             let consumer = Self::create_temp_consumer(&config)?;
             let groups = consumer.client()
                 .fetch_group_list(None, Duration::from_secs(30))
                 .map_err(|e| AppError::Kafka(format!("Fetch groups: {}", e)))?;
 
-            Ok(groups.groups().iter()
-                .filter(|g| g.name() != "kafka-tui-browser" && g.name() != "kafka-tui-temp")
+            Ok::<_, AppError>(groups.groups().iter()
+                .filter(|g| !is_own_group(g.name()))
                 .map(|g| ConsumerGroupInfo {
                     group_id: g.name().into(),
                     state: g.state().into(),
+                    protocol_type: g.protocol_type().into(),
                     members_count: g.members().len(),
                     topics: vec![],
                     total_lag: 0,
@@ -346,7 +917,28 @@ impl KafkaClient {
                 .collect())
         })
         .await
-        .map_err(|e| AppError::Kafka(format!("List consumer groups task failed: {}", e)))?
+        .map_err(|e| AppError::Kafka(format!("List consumer groups task failed: {}", e)))??;
+
+        // Committed offsets require a round-trip per group, so fan out with bounded concurrency
+        // and give up on any single group that takes too long.
+        let groups = futures::stream::iter(groups)
+            .map(|mut group| async move {
+                let lookup = tokio::time::timeout(GROUP_LAG_TIMEOUT, self.get_group_offsets(&group.group_id));
+                match lookup.await {
+                    Ok(Ok(offsets)) => {
+                        group.total_lag = offsets.iter().map(|o| o.lag).sum();
+                        group.topics = offsets.into_iter().map(|o| o.topic).collect::<BTreeSet<_>>().into_iter().collect();
+                    }
+                    Ok(Err(e)) => tracing::warn!(group = %group.group_id, error = %e, "Group offsets unavailable"),
+                    Err(_) => tracing::warn!(group = %group.group_id, "Group offsets timed out"),
+                }
+                group
+            })
+            .buffered(GROUP_LAG_CONCURRENCY)
+            .collect()
+            .await;
+
+        Ok(groups)
     }
 
     pub async fn get_topic_details(&self, topic_name: &str) -> AppResult<TopicDetail> {
@@ -366,11 +958,11 @@ impl KafkaClient {
                 let topic_meta = metadata.topics().first()
                     .ok_or_else(|| AppError::Kafka("Topic not found".into()))?;
 
+                let ids: Vec<i32> = topic_meta.partitions().iter().map(|p| p.id()).collect();
+                let watermarks = fetch_watermarks(&consumer, &topic_name, &ids, Duration::from_secs(5)).unwrap_or_default();
                 let mut partitions = Vec::new();
                 for p in topic_meta.partitions() {
-                    let (low, high) = consumer
-                        .fetch_watermarks(&topic_name, p.id(), Duration::from_secs(5))
-                        .unwrap_or((0, 0));
+                    let (low, high) = watermarks.get(&p.id()).copied().unwrap_or((0, 0));
 
                     partitions.push(PartitionInfo {
                         id: p.id(),
@@ -400,11 +992,33 @@ impl KafkaClient {
         })
     }
 
+    /// Low and high watermarks of each partition of `topic`, as (partition, low, high).
+    ///
+    /// The part of `get_topic_details` that changes as messages arrive, without the config
+    /// describe, for refreshing the partitions table cheaply.
+    pub async fn get_topic_watermarks(&self, topic: &str) -> AppResult<Vec<(i32, i64, i64)>> {
+        let config = self.config.clone();
+        let topic = topic.to_string();
+
+        tokio::task::spawn_blocking(move || {
+            let consumer = Self::create_temp_consumer(&config)?;
+            Self::partition_watermarks(&consumer, &topic)
+        })
+        .await
+        .map_err(|e| AppError::Kafka(format!("Get topic watermarks task failed: {}", e)))?
+    }
+
     async fn get_topic_config(&self, topic_name: &str) -> AppResult<Vec<(String, String)>> {
-        use rdkafka::admin::ResourceSpecifier;
+        self.describe_config(ResourceSpecifier::Topic(topic_name)).await
+    }
+
+    /// Fetch the configuration of a single broker
+    pub async fn get_broker_config(&self, broker_id: i32) -> AppResult<Vec<(String, String)>> {
+        self.describe_config(ResourceSpecifier::Broker(broker_id)).await
+    }
 
+    async fn describe_config(&self, resource: ResourceSpecifier<'_>) -> AppResult<Vec<(String, String)>> {
         let opts = AdminOptions::new().operation_timeout(Some(Duration::from_secs(10)));
-        let resource = ResourceSpecifier::Topic(topic_name);
 
         let results = self.admin.describe_configs([&resource], &opts).await
             .map_err(|e| AppError::Kafka(format!("Describe config: {}", e)))?;
@@ -432,7 +1046,7 @@ impl KafkaClient {
         let group_id_owned = group_id.to_string();
 
         // Get group description using spawn_blocking
-        let (state, members) = tokio::task::spawn_blocking({
+        let (state, members, coordinator) = tokio::task::spawn_blocking({
             let config = config.clone();
             let group_id = group_id_owned.clone();
             move || {
@@ -455,7 +1069,10 @@ impl KafkaClient {
                     }
                 }).collect();
 
-                Ok::<_, AppError>((state, members))
+                let client_ptr = consumer.client().native_ptr() as usize;
+                let coordinator = super::admin_ffi::group_coordinator(client_ptr, &group_id, 5_000);
+
+                Ok::<_, AppError>((state, members, coordinator))
             }
         })
         .await
@@ -467,80 +1084,50 @@ impl KafkaClient {
         Ok(ConsumerGroupDetail {
             group_id: group_id_owned,
             state,
-            coordinator: None,
+            coordinator,
             members,
             offsets,
         })
     }
 
+    /// Parse a member's `ConsumerProtocolAssignment`.
+    ///
+    /// Every version so far starts with the assigned partitions per topic, followed by the
+    /// user data; newer versions may only append fields, so everything after the partitions is
+    /// skipped. Malformed input yields the partitions read before the problem, with a warning.
     fn parse_member_assignment(data: &[u8]) -> Vec<TopicPartition> {
-        // Member assignment is binary protocol, simplified parsing
-        // Format: version(2) + topic_count(4) + [topic_name_len(2) + topic_name + partition_count(4) + [partition(4)]]
-        if data.len() < 6 {
-            return vec![];
-        }
-
         let mut result = Vec::new();
-        let mut pos = 2; // skip version
-
-        if pos + 4 > data.len() { return result; }
-        let topic_count = i32::from_be_bytes([data[pos], data[pos+1], data[pos+2], data[pos+3]]) as usize;
-        pos += 4;
-
-        for _ in 0..topic_count {
-            if pos + 2 > data.len() { break; }
-            let topic_len = i16::from_be_bytes([data[pos], data[pos+1]]) as usize;
-            pos += 2;
-
-            if pos + topic_len > data.len() { break; }
-            let topic = String::from_utf8_lossy(&data[pos..pos+topic_len]).to_string();
-            pos += topic_len;
-
-            if pos + 4 > data.len() { break; }
-            let partition_count = i32::from_be_bytes([data[pos], data[pos+1], data[pos+2], data[pos+3]]) as usize;
-            pos += 4;
-
-            for _ in 0..partition_count {
-                if pos + 4 > data.len() { break; }
-                let partition = i32::from_be_bytes([data[pos], data[pos+1], data[pos+2], data[pos+3]]);
-                pos += 4;
-                result.push(TopicPartition { topic: topic.clone(), partition });
-            }
+        // Members that haven't been assigned yet, e.g. mid-rebalance
+        if data.is_empty() {
+            return result;
+        }
+        if let Err(e) = read_assignment(&mut AssignmentReader { data, pos: 0 }, &mut result) {
+            tracing::warn!(error = e, len = data.len(), parsed = result.len(), "Malformed member assignment");
         }
-
         result
     }
 
+    /// Committed offsets and lag of `group_id`, read without joining the group.
     async fn get_group_offsets(&self, group_id: &str) -> AppResult<Vec<PartitionOffset>> {
         let config = self.config.clone();
         let group_id = group_id.to_string();
 
         tokio::task::spawn_blocking(move || {
-            // Create a temporary consumer for this group to fetch offsets
-            let consumer: BaseConsumer<LoggingContext> = Self::base_config(&config)
-                .set("group.id", &group_id)
-                .create_with_context(LoggingContext)
-                .map_err(|e| AppError::Kafka(format!("Consumer for offsets: {}", e)))?;
-
-            let committed = consumer
-                .committed(Duration::from_secs(10))
-                .map_err(|e| AppError::Kafka(format!("Fetch committed: {}", e)))?;
+            // The temp consumer never subscribes, so it doesn't join its own group either
+            let consumer = Self::create_temp_consumer(&config)?;
+            let client_ptr = consumer.client().native_ptr() as usize;
+            let committed = super::admin_ffi::list_group_offsets(client_ptr, &group_id, 10_000)?;
 
             let mut offsets = Vec::new();
-            for elem in committed.elements() {
-                let current_offset = match elem.offset() {
-                    rdkafka::Offset::Offset(o) => o,
-                    _ => continue,
-                };
-
+            for (topic, partition, current_offset) in committed {
                 // Get log end offset (high watermark)
                 let (_, high) = consumer
-                    .fetch_watermarks(elem.topic(), elem.partition(), Duration::from_secs(5))
+                    .fetch_watermarks(&topic, partition, Duration::from_secs(5))
                     .unwrap_or((0, 0));
 
                 offsets.push(PartitionOffset {
-                    topic: elem.topic().to_string(),
-                    partition: elem.partition(),
+                    topic,
+                    partition,
                     current_offset,
                     log_end_offset: high,
                     lag: (high - current_offset).max(0),
@@ -554,26 +1141,120 @@ impl KafkaClient {
         .map_err(|e| AppError::Kafka(format!("Get group offsets task failed: {}", e)))?
     }
 
+    /// Commit new offsets for a consumer group on one topic (optionally a single partition).
+    ///
+    /// The group must have no active members; otherwise the broker would reject or immediately
+    /// overwrite the commit.
+    pub async fn reset_group_offsets(
+        &self,
+        group_id: &str,
+        topic: &str,
+        partition: Option<i32>,
+        mode: OffsetMode,
+    ) -> AppResult<()> {
+        tracing::debug!(group_id, topic, ?partition, ?mode, "Resetting group offsets");
+        let config = self.config.clone();
+        let group_id = group_id.to_string();
+        let topic = topic.to_string();
+
+        tokio::task::spawn_blocking(move || {
+            let consumer: BaseConsumer<LoggingContext> = Self::base_config(&config)
+                .set("group.id", &group_id)
+                .set("enable.auto.commit", "false")
+                .create_with_context(LoggingContext::new(&config))
+                .map(Self::serve_token_request)
+                .map_err(|e| AppError::Kafka(format!("Consumer for reset: {}", e)))?;
+
+            let groups = consumer.client()
+                .fetch_group_list(Some(&group_id), Duration::from_secs(10))
+                .map_err(|e| AppError::Kafka(format!("Fetch group: {}", e)))?;
+            if let Some(group) = groups.groups().iter().find(|g| g.name() == group_id) {
+                if !matches!(group.state().to_lowercase().as_str(), "empty" | "dead") {
+                    return Err(AppError::Kafka(format!(
+                        "Group '{}' is {}; stop its consumers before resetting offsets",
+                        group_id,
+                        group.state()
+                    )));
+                }
+            }
+
+            let metadata = consumer
+                .fetch_metadata(Some(&topic), Duration::from_secs(10))
+                .map_err(|e| AppError::Kafka(format!("Metadata fetch: {}", e)))?;
+            let topic_meta = metadata.topics().iter()
+                .find(|t| t.name() == topic)
+                .ok_or_else(|| AppError::Kafka("Topic not found".into()))?;
+
+            let mut partitions: Vec<i32> = partition
+                .map(|p| vec![p])
+                .unwrap_or_else(|| topic_meta.partitions().iter().map(|p| p.id()).collect());
+            partitions.retain(|&p| mode.reads_partition(p));
+
+            let watermarks = fetch_watermarks(&consumer, &topic, &partitions, Duration::from_secs(5))?;
+            let mut tpl = TopicPartitionList::new();
+            for &p in &partitions {
+                let (low, high) = watermarks[&p];
+
+                let target = match &mode {
+                    OffsetMode::Earliest => low,
+                    OffsetMode::Latest => high,
+                    OffsetMode::Specific(o) => (*o).clamp(low, high),
+                    OffsetMode::Partitions(starts) => starts[&p].clamp(low, high),
+                    OffsetMode::Timestamp(ts) => {
+                        let mut query = TopicPartitionList::new();
+                        query.add_partition_offset(&topic, p, rdkafka::Offset::Offset(ts.timestamp_millis()))
+                            .map_err(|e| AppError::Kafka(format!("Set offset: {}", e)))?;
+                        let found = consumer
+                            .offsets_for_times(query, Duration::from_secs(10))
+                            .map_err(|e| AppError::Kafka(format!("Offsets for time: {}", e)))?;
+                        match found.find_partition(&topic, p).map(|e| e.offset()) {
+                            Some(rdkafka::Offset::Offset(o)) => o,
+                            _ => high,
+                        }
+                    }
+                };
+
+                tpl.add_partition_offset(&topic, p, rdkafka::Offset::Offset(target))
+                    .map_err(|e| AppError::Kafka(format!("Set offset: {}", e)))?;
+            }
+
+            consumer.assign(&tpl)
+                .map_err(|e| AppError::Kafka(format!("Assign: {}", e)))?;
+            consumer.commit(&tpl, CommitMode::Sync)
+                .map_err(|e| AppError::Kafka(format!("Commit offsets: {}", e)))?;
+            consumer.unassign().ok();
+            Ok(())
+        })
+        .await
+        .map_err(|e| AppError::Kafka(format!("Reset offsets task failed: {}", e)))?
+    }
+
     pub async fn list_brokers(&self) -> AppResult<(Vec<BrokerInfo>, Option<String>)> {
         let config = self.config.clone();
         tokio::task::spawn_blocking(move || {
             let consumer = Self::create_temp_consumer(&config)?;
             let metadata = consumer
-                .fetch_metadata(None, Duration::from_secs(10))
+                .fetch_metadata(None, config.metadata_timeout())
                 .map_err(|e| AppError::Kafka(format!("Metadata fetch: {}", e)))?;
 
-            let controller_id = metadata.orig_broker_id();
+            // Metadata has no rack or controller; without DescribeCluster the answering broker stands in
+            let client_ptr = consumer.client().native_ptr() as usize;
+            let mut nodes = super::admin_ffi::describe_cluster(client_ptr, 5_000).unwrap_or_default();
+            let controller_id = nodes.controller.unwrap_or(metadata.orig_broker_id());
 
             let brokers: Vec<BrokerInfo> = metadata.brokers().iter().map(|b| {
                 BrokerInfo {
                     id: b.id(),
                     host: b.host().to_string(),
                     port: b.port(),
+                    rack: nodes.racks.remove(&b.id()),
                     is_controller: b.id() == controller_id,
                 }
             }).collect();
 
-            Ok((brokers, None)) // cluster_id not easily available in rdkafka
+            let cluster_id = super::admin_ffi::cluster_id(client_ptr, 5_000);
+
+            Ok((brokers, cluster_id))
         })
         .await
         .map_err(|e| AppError::Kafka(format!("List brokers task failed: {}", e)))?
@@ -601,8 +1282,32 @@ impl KafkaClient {
         Ok(())
     }
 
-    /// Alter topic configuration
-    pub async fn alter_topic_config(&self, topic: &str, configs: &[(String, String)]) -> AppResult<()> {
+    /// Set the given topic config entries and remove the overrides named in `deletes`,
+    /// leaving every other entry as it is
+    pub async fn alter_topic_config(
+        &self,
+        topic: &str,
+        configs: &[(String, String)],
+        deletes: &[String],
+    ) -> AppResult<()> {
+        let config = self.config.clone();
+        let topic = topic.to_string();
+        let configs = configs.to_vec();
+        let deletes = deletes.to_vec();
+
+        tokio::task::spawn_blocking(move || {
+            let consumer = Self::create_temp_consumer(&config)?;
+            let client_ptr = consumer.client().native_ptr() as usize;
+            super::admin_ffi::incremental_alter_topic_config(client_ptr, &topic, &configs, &deletes, 30_000)
+        })
+        .await
+        .map_err(|e| AppError::Kafka(format!("Alter config task failed: {}", e)))?
+    }
+
+    /// Alter topic configuration with the legacy AlterConfigs request, for brokers older
+    /// than Kafka 2.3. It replaces the topic's whole config, so entries not passed here
+    /// may revert to their defaults.
+    pub async fn alter_topic_config_legacy(&self, topic: &str, configs: &[(String, String)]) -> AppResult<()> {
         // Build AlterConfig with all entries
         // We need configs to live long enough, so we reference the input slice directly
         let resource = ResourceSpecifier::Topic(topic);
@@ -629,6 +1334,7 @@ impl KafkaClient {
         if before_offset < 0 {
             return Err(AppError::Kafka("Offset must be >= 0".into()));
         }
+        self.ensure_purgeable(topic).await?;
 
         let config = self.config.clone();
         let topic = topic.to_string();
@@ -636,22 +1342,10 @@ impl KafkaClient {
         tokio::task::spawn_blocking(move || {
             let consumer = Self::create_temp_consumer(&config)?;
 
-            let metadata = consumer
-                .fetch_metadata(Some(&topic), Duration::from_secs(10))
-                .map_err(|e| AppError::Kafka(format!("Metadata fetch: {}", e)))?;
-
-            let topic_meta = metadata.topics().iter()
-                .find(|t| t.name() == topic)
-                .ok_or_else(|| AppError::Kafka("Topic not found".into()))?;
-
             let mut tpl = TopicPartitionList::new();
-            for p in topic_meta.partitions() {
-                let (_, high) = consumer
-                    .fetch_watermarks(&topic, p.id(), Duration::from_secs(5))
-                    .map_err(|e| AppError::Kafka(format!("Fetch watermarks: {}", e)))?;
-
+            for (partition, _, high) in Self::partition_watermarks(&consumer, &topic)? {
                 let offset = before_offset.min(high);
-                tpl.add_partition_offset(&topic, p.id(), rdkafka::Offset::Offset(offset))
+                tpl.add_partition_offset(&topic, partition, rdkafka::Offset::Offset(offset))
                     .map_err(|e| AppError::Kafka(format!("Set offset: {}", e)))?;
             }
 
@@ -662,4 +1356,258 @@ impl KafkaClient {
         .await
         .map_err(|e| AppError::Kafka(format!("DeleteRecords task failed: {}", e)))?
     }
+
+    /// Count the messages `delete_records(topic, before_offset)` would remove.
+    pub async fn purge_estimate(&self, topic: &str, before_offset: i64) -> AppResult<i64> {
+        self.ensure_purgeable(topic).await?;
+
+        let config = self.config.clone();
+        let topic = topic.to_string();
+
+        tokio::task::spawn_blocking(move || {
+            let consumer = Self::create_temp_consumer(&config)?;
+            let count = Self::partition_watermarks(&consumer, &topic)?
+                .into_iter()
+                .map(|(_, low, high)| (before_offset.min(high) - low).max(0))
+                .sum();
+            Ok(count)
+        })
+        .await
+        .map_err(|e| AppError::Kafka(format!("Purge estimate task failed: {}", e)))?
+    }
+
+    /// DeleteRecords is rejected by brokers for topics that only compact, so check up front.
+    async fn ensure_purgeable(&self, topic: &str) -> AppResult<()> {
+        let config = self.get_topic_config(topic).await?;
+        let policy = config
+            .iter()
+            .find(|(name, _)| name == "cleanup.policy")
+            .map_or("delete", |(_, value)| value.as_str());
+        if policy.split(',').any(|p| p.trim() == "delete") {
+            Ok(())
+        } else {
+            Err(AppError::Validation {
+                field: "cleanup.policy".into(),
+                message: format!("'{}' is compacted only ({}); purging needs the delete policy", topic, policy),
+            })
+        }
+    }
+
+    /// `(partition, low, high)` watermarks for every partition of `topic`
+    fn partition_watermarks(consumer: &BaseConsumer<LoggingContext>, topic: &str) -> AppResult<Vec<(i32, i64, i64)>> {
+        let metadata = consumer
+            .fetch_metadata(Some(topic), Duration::from_secs(10))
+            .map_err(|e| AppError::Kafka(format!("Metadata fetch: {}", e)))?;
+
+        let topic_meta = metadata.topics().iter()
+            .find(|t| t.name() == topic)
+            .ok_or_else(|| AppError::Kafka("Topic not found".into()))?;
+
+        let ids: Vec<i32> = topic_meta.partitions().iter().map(|p| p.id()).collect();
+        let watermarks = fetch_watermarks(consumer, topic, &ids, Duration::from_secs(5))?;
+        Ok(ids.iter().map(|p| (*p, watermarks[p].0, watermarks[p].1)).collect())
+    }
+}
+
+fn read_assignment(reader: &mut AssignmentReader, result: &mut Vec<TopicPartition>) -> Result<(), &'static str> {
+    if reader.i16()? < 0 {
+        return Err("negative version");
+    }
+    // Each topic takes at least a name length and a partition count
+    for _ in 0..reader.array_len(2 + 4)? {
+        let topic = reader.string()?;
+        for _ in 0..reader.array_len(4)? {
+            let partition = reader.i32()?;
+            result.push(TopicPartition { topic: topic.clone(), partition });
+        }
+    }
+    Ok(())
+}
+
+/// Big-endian reader over the Kafka protocol encoding of a member assignment
+struct AssignmentReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl AssignmentReader<'_> {
+    fn take<const N: usize>(&mut self) -> Result<[u8; N], &'static str> {
+        let bytes = self.data.get(self.pos..self.pos + N).ok_or("truncated")?;
+        self.pos += N;
+        Ok(bytes.try_into().expect("slice has N bytes"))
+    }
+
+    fn i16(&mut self) -> Result<i16, &'static str> {
+        self.take().map(i16::from_be_bytes)
+    }
+
+    fn i32(&mut self) -> Result<i32, &'static str> {
+        self.take().map(i32::from_be_bytes)
+    }
+
+    /// Array length; a null array counts as empty, and one that can't fit in the remaining
+    /// bytes at `min_item_len` each is rejected before anything is allocated for it
+    fn array_len(&mut self, min_item_len: usize) -> Result<usize, &'static str> {
+        match self.i32()? {
+            -1 => Ok(0),
+            n if n < 0 => Err("negative array length"),
+            n if n as usize > (self.data.len() - self.pos) / min_item_len => Err("array overruns the data"),
+            n => Ok(n as usize),
+        }
+    }
+
+    fn string(&mut self) -> Result<String, &'static str> {
+        let len = usize::try_from(self.i16()?).map_err(|_| "null topic name")?;
+        let bytes = self.data.get(self.pos..self.pos + len).ok_or("truncated")?;
+        self.pos += len;
+        std::str::from_utf8(bytes).map(String::from).map_err(|_| "topic name is not UTF-8")
+    }
+}
+
+/// Reject metadata that can't back a usable session: no brokers at all, or no active controller
+/// among them. Both otherwise look like an empty cluster.
+///
+/// `cluster` is `None` when the brokers can't describe the cluster (before Kafka 2.8); the
+/// controller then goes unchecked.
+fn check_cluster_metadata(bootstrap: &str, broker_ids: &[i32], cluster: Option<&ClusterNodes>) -> AppResult<()> {
+    if broker_ids.is_empty() {
+        return Err(AppError::Connection(format!(
+            "'{}' answered but reported no brokers; check that it points at a Kafka listener",
+            bootstrap
+        )));
+    }
+    match cluster.map(|c| c.controller) {
+        Some(None) => Err(AppError::Connection(
+            "the cluster has no active controller; it may be starting up or degraded".into(),
+        )),
+        Some(Some(controller)) if !broker_ids.contains(&controller) => Err(AppError::Connection(format!(
+            "controller {} is missing from the cluster's broker list {:?}; the cluster may be degraded",
+            controller, broker_ids
+        ))),
+        _ => Ok(()),
+    }
+}
+
+/// Explain a failed connection attempt in terms of its likely cause.
+///
+/// Authentication failures become [`AppError::Authentication`], which retrying won't fix;
+/// everything else is an [`AppError::Connection`].
+fn connect_error(brokers: &str, e: rdkafka::error::KafkaError) -> AppError {
+    match e.rdkafka_error_code() {
+        Some(RDKafkaErrorCode::Authentication | RDKafkaErrorCode::SaslAuthenticationFailed) => {
+            AppError::Authentication("the broker rejected the credentials; check username, password and mechanism".into())
+        }
+        Some(RDKafkaErrorCode::Resolve) => {
+            AppError::Connection(format!("cannot resolve the broker host in '{}'", brokers))
+        }
+        Some(RDKafkaErrorCode::SSL) => AppError::Connection(
+            "TLS handshake failed; check the certificates and that the listener uses TLS".into(),
+        ),
+        Some(RDKafkaErrorCode::OperationTimedOut | RDKafkaErrorCode::RequestTimedOut) => {
+            AppError::Connection(format!("timed out waiting for '{}'", brokers))
+        }
+        Some(RDKafkaErrorCode::BrokerTransportFailure | RDKafkaErrorCode::AllBrokersDown) => {
+            AppError::Connection(format!("no broker reachable at '{}'; check the address and that Kafka is running", brokers))
+        }
+        _ => AppError::Connection(e.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tp(topic: &str, partition: i32) -> TopicPartition {
+        TopicPartition { topic: topic.into(), partition }
+    }
+
+    #[test]
+    fn test_parse_member_assignment() {
+        // Version 3, two topics, then 3 bytes of user data
+        let multi_topic = [
+            0x00, 0x03, 0x00, 0x00, 0x00, 0x02, //
+            0x00, 0x06, b'o', b'r', b'd', b'e', b'r', b's', 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x02, //
+            0x00, 0x01, b'p', 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x05, //
+            0x00, 0x00, 0x00, 0x03, 0xaa, 0xbb, 0xcc,
+        ];
+        assert_eq!(
+            KafkaClient::parse_member_assignment(&multi_topic),
+            vec![tp("orders", 0), tp("orders", 2), tp("p", 5)]
+        );
+
+        // Version 0 with no partitions and null user data, and a member not yet assigned
+        let empty = [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff];
+        assert_eq!(KafkaClient::parse_member_assignment(&empty), vec![]);
+        assert_eq!(KafkaClient::parse_member_assignment(&[]), vec![]);
+
+        // Cut off in the second topic: the first one is still returned
+        assert_eq!(KafkaClient::parse_member_assignment(&multi_topic[..30]), vec![tp("orders", 0), tp("orders", 2)]);
+
+        // A topic count far beyond the data is rejected outright
+        let bogus_count = [0x00, 0x00, 0x7f, 0xff, 0xff, 0xff, 0x00, 0x01, b't'];
+        assert_eq!(KafkaClient::parse_member_assignment(&bogus_count), vec![]);
+    }
+
+    #[test]
+    fn test_own_groups_are_not_listed() {
+        let listed = ["orders-service", TEMP_GROUP_ID, "kafka-tui-browser", "kafka-tui-temp-2"];
+        let shown: Vec<&str> = listed.into_iter().filter(|g| !is_own_group(g)).collect();
+        assert_eq!(shown, ["orders-service", "kafka-tui-temp-2"]);
+    }
+
+    #[test]
+    fn test_fetch_deadline() {
+        assert_eq!(fetch_deadline(0), FETCH_DEADLINE_BASE);
+        assert_eq!(fetch_deadline(1000), Duration::from_secs(15));
+        assert_eq!(fetch_deadline(usize::MAX), FETCH_DEADLINE_MAX);
+    }
+
+    fn message(partition: i32, offset: i64, secs: i64) -> KafkaMessage {
+        KafkaMessage {
+            partition,
+            offset,
+            timestamp: chrono::DateTime::from_timestamp(secs, 0),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_latest_single_partition() {
+        assert_eq!(latest_backoff(100, 1), 100);
+        let mut messages: Vec<_> = (0..100).map(|o| message(0, o, o)).collect();
+        keep_latest(&mut messages, 100);
+        assert_eq!(messages.len(), 100);
+        assert_eq!(messages.first().map(|m| m.offset), Some(0));
+    }
+
+    #[test]
+    fn test_latest_multi_partition() {
+        assert_eq!(latest_backoff(100, 10), 10);
+        assert_eq!(latest_backoff(10, 3), 4);
+        assert_eq!(latest_backoff(5, 0), 5);
+
+        // Partition 0 is busy and recent, partition 1 quiet and old
+        let mut messages = vec![message(1, 7, 10), message(0, 40, 100), message(1, 8, 20), message(0, 41, 110), message(0, 39, 90)];
+        keep_latest(&mut messages, 3);
+        let kept: Vec<_> = messages.iter().map(|m| (m.partition, m.offset)).collect();
+        assert_eq!(kept, [(0, 39), (0, 40), (0, 41)]);
+    }
+
+    #[test]
+    fn test_check_cluster_metadata() {
+        let controller = |id| ClusterNodes { controller: id, ..Default::default() };
+        // Metadata over the bootstrap connection answers as node -1, which is never in the list
+        assert!(check_cluster_metadata("localhost:9092", &[1, 2, 3], Some(&controller(Some(2)))).is_ok());
+        assert!(check_cluster_metadata("localhost:9092", &[1, 2, 3], None).is_ok());
+        assert!(matches!(check_cluster_metadata("localhost:9092", &[], None), Err(AppError::Connection(_))));
+        assert!(matches!(
+            check_cluster_metadata("localhost:9092", &[1, 2], Some(&controller(Some(7)))),
+            Err(AppError::Connection(_))
+        ));
+        assert!(matches!(
+            check_cluster_metadata("localhost:9092", &[1, 2], Some(&controller(None))),
+            Err(AppError::Connection(_))
+        ));
+    }
 }
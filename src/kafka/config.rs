@@ -1,6 +1,10 @@
+use std::time::Duration;
+
 use serde::{Deserialize, Serialize};
 
-use crate::app::state::{AuthConfig, ConnectionProfile, SaslMechanism};
+use crate::app::state::{AuthConfig, ConnectionProfile, ProducerSettings, SaslMechanism, SecretStorage};
+use crate::error::{AppError, AppResult};
+use crate::storage::secrets::{self, KEYRING_SENTINEL};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct KafkaConfig {
@@ -9,6 +13,13 @@ pub struct KafkaConfig {
     #[serde(default)]
     pub consumer_group: Option<String>,
 
+    #[serde(default)]
+    pub schema_registry_url: Option<String>,
+
+    /// `user:password` for the registry
+    #[serde(default)]
+    pub schema_registry_credentials: Option<String>,
+
     #[serde(default)]
     pub security: SecurityConfig,
 
@@ -17,8 +28,17 @@ pub struct KafkaConfig {
 
     #[serde(default = "default_request_timeout")]
     pub request_timeout_ms: u32,
+
+    #[serde(default)]
+    pub producer: ProducerSettings,
 }
 
+/// `socket.timeout.ms` for profiles that don't set their own
+pub const DEFAULT_SOCKET_TIMEOUT_MS: u32 = 30_000;
+
+/// `request.timeout.ms` for profiles that don't set their own, also the cluster metadata deadline
+pub const DEFAULT_REQUEST_TIMEOUT_MS: u32 = 60_000;
+
 fn default_connection_timeout() -> u32 {
     10000 // 10 seconds
 }
@@ -26,6 +46,13 @@ fn default_request_timeout() -> u32 {
     15000 // 15 seconds
 }
 
+impl KafkaConfig {
+    /// Deadline for cluster-wide metadata requests, following the request timeout
+    pub fn metadata_timeout(&self) -> Duration {
+        Duration::from_millis(self.request_timeout_ms.into())
+    }
+}
+
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum SecurityConfig {
@@ -60,6 +87,14 @@ pub enum SecurityConfig {
         password: String,
         ca_location: Option<String>,
     },
+
+    OAuthBearer {
+        token_endpoint: Option<String>,
+        client_id: String,
+        secret: String,
+        scope: Option<String>,
+        ca_location: Option<String>,
+    },
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -71,8 +106,35 @@ pub enum KafkaSaslMechanism {
     ScramSha512,
 }
 
-impl From<ConnectionProfile> for KafkaConfig {
-    fn from(profile: ConnectionProfile) -> Self {
+impl KafkaConfig {
+    /// Build the client config for `profile`, first reading its secret from the keyring if
+    /// it is kept there. The lookup blocks, so it runs on the blocking pool.
+    pub async fn for_profile(mut profile: ConnectionProfile) -> AppResult<Self> {
+        if profile.secret_storage == SecretStorage::Keyring {
+            profile = tokio::task::spawn_blocking(move || secrets::resolve_profile_secret(&mut profile).map(|()| profile))
+                .await
+                .map_err(|e| AppError::Config(format!("Keyring task failed: {}", e)))??;
+        }
+        Self::try_from(profile)
+    }
+}
+
+/// Fails when the profile's secret is still the keyring placeholder, which must never reach
+/// the brokers; [`KafkaConfig::for_profile`] reads it first.
+impl TryFrom<ConnectionProfile> for KafkaConfig {
+    type Error = AppError;
+
+    fn try_from(mut profile: ConnectionProfile) -> AppResult<Self> {
+        // Profiles saved before credentials had their own field still carry them in the URL
+        profile.split_registry_credentials();
+        let sentinel = |s: &String| s == KEYRING_SENTINEL;
+        if profile.auth.secret_mut().is_some_and(|s| sentinel(s)) || profile.schema_registry_credentials.as_ref().is_some_and(sentinel) {
+            return Err(AppError::Config(format!(
+                "The password for '{}' is kept in the OS keyring and was not read",
+                profile.name
+            )));
+        }
+
         let security = match profile.auth {
             AuthConfig::None => SecurityConfig::None,
             AuthConfig::SaslPlain { username, password } => {
@@ -113,14 +175,30 @@ impl From<ConnectionProfile> for KafkaConfig {
                     ca_location,
                 }
             }
+            AuthConfig::OAuthBearer {
+                token_endpoint,
+                client_id,
+                secret,
+                scope,
+                ca_location,
+            } => SecurityConfig::OAuthBearer {
+                token_endpoint,
+                client_id,
+                secret,
+                scope,
+                ca_location,
+            },
         };
 
-        KafkaConfig {
+        Ok(KafkaConfig {
             brokers: profile.brokers,
             consumer_group: profile.consumer_group,
+            schema_registry_url: profile.schema_registry_url,
+            schema_registry_credentials: profile.schema_registry_credentials,
             security,
-            connection_timeout_ms: 30000,
-            request_timeout_ms: 60000,
-        }
+            connection_timeout_ms: profile.socket_timeout_ms.unwrap_or(DEFAULT_SOCKET_TIMEOUT_MS),
+            request_timeout_ms: profile.request_timeout_ms.unwrap_or(DEFAULT_REQUEST_TIMEOUT_MS),
+            producer: profile.producer,
+        })
     }
 }
@@ -0,0 +1,157 @@
+//! Decompression of payloads that producers compressed themselves, before handing them to Kafka.
+//!
+//! The codec is picked by magic bytes. Snappy has no magic of its own, so only the framed
+//! (`sNaPpY`) and Xerial (`\x82SNAPPY`, written by snappy-java) containers are recognised.
+
+use std::io::Read;
+
+/// Output is cut off past this size, so a small corrupt or hostile payload can't exhaust memory
+const MAX_OUTPUT: usize = 64 * 1024 * 1024;
+
+const GZIP_MAGIC: &[u8] = &[0x1f, 0x8b];
+const ZSTD_MAGIC: &[u8] = &[0x28, 0xb5, 0x2f, 0xfd];
+const LZ4_MAGIC: &[u8] = &[0x04, 0x22, 0x4d, 0x18];
+const SNAPPY_FRAMED_MAGIC: &[u8] = b"\xff\x06\x00\x00sNaPpY";
+const SNAPPY_XERIAL_MAGIC: &[u8] = b"\x82SNAPPY\x00";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Codec {
+    Gzip,
+    Snappy,
+    Lz4,
+    Zstd,
+}
+
+impl Codec {
+    /// Identify the codec from the payload's first bytes.
+    pub fn detect(payload: &[u8]) -> Option<Self> {
+        if payload.starts_with(GZIP_MAGIC) {
+            Some(Self::Gzip)
+        } else if payload.starts_with(ZSTD_MAGIC) {
+            Some(Self::Zstd)
+        } else if payload.starts_with(LZ4_MAGIC) {
+            Some(Self::Lz4)
+        } else if payload.starts_with(SNAPPY_FRAMED_MAGIC) || payload.starts_with(SNAPPY_XERIAL_MAGIC) {
+            Some(Self::Snappy)
+        } else {
+            None
+        }
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Self::Gzip => "gzip",
+            Self::Snappy => "snappy",
+            Self::Lz4 => "lz4",
+            Self::Zstd => "zstd",
+        }
+    }
+}
+
+/// Detect the payload's codec and decompress it.
+pub fn decompress(payload: &[u8]) -> Result<(Codec, Vec<u8>), String> {
+    let codec = Codec::detect(payload).ok_or("not compressed")?;
+    let bytes = match codec {
+        Codec::Gzip => gunzip(payload),
+        Codec::Zstd => unzstd(payload),
+        Codec::Lz4 => unlz4(payload),
+        Codec::Snappy => unsnappy(payload),
+    }?;
+    Ok((codec, bytes))
+}
+
+fn too_large() -> String {
+    format!("decompressed payload exceeds {} MiB", MAX_OUTPUT / (1024 * 1024))
+}
+
+/// Read a decoder to the end, stopping once the output passes [`MAX_OUTPUT`].
+fn read_capped(decoder: impl Read, codec: Codec) -> Result<Vec<u8>, String> {
+    let mut out = Vec::new();
+    decoder
+        .take(MAX_OUTPUT as u64 + 1)
+        .read_to_end(&mut out)
+        .map_err(|e| format!("corrupt {} data: {}", codec.display_name(), e))?;
+    if out.len() > MAX_OUTPUT {
+        return Err(too_large());
+    }
+    Ok(out)
+}
+
+fn gunzip(input: &[u8]) -> Result<Vec<u8>, String> {
+    read_capped(flate2::read::GzDecoder::new(input), Codec::Gzip)
+}
+
+fn unzstd(input: &[u8]) -> Result<Vec<u8>, String> {
+    let decoder = zstd::stream::read::Decoder::new(input).map_err(|e| format!("cannot initialise zstd: {}", e))?;
+    read_capped(decoder, Codec::Zstd)
+}
+
+fn unlz4(input: &[u8]) -> Result<Vec<u8>, String> {
+    read_capped(lz4_flex::frame::FrameDecoder::new(input), Codec::Lz4)
+}
+
+/// Unwrap a framed or Xerial Snappy stream.
+fn unsnappy(input: &[u8]) -> Result<Vec<u8>, String> {
+    let Some(mut rest) = input.strip_prefix(SNAPPY_XERIAL_MAGIC) else {
+        return read_capped(snap::read::FrameDecoder::new(input), Codec::Snappy);
+    };
+
+    // Version and minimum compatible version, then length-prefixed raw blocks
+    rest = rest.get(8..).ok_or("truncated snappy header")?;
+    let mut out = Vec::new();
+    while !rest.is_empty() {
+        let (len, body) = rest.split_first_chunk::<4>().ok_or("truncated snappy block")?;
+        let len = u32::from_be_bytes(*len) as usize;
+        let block = body.get(..len).ok_or("truncated snappy block")?;
+        let expected = snap::raw::decompress_len(block).map_err(|e| format!("corrupt snappy data: {}", e))?;
+        if out.len() + expected > MAX_OUTPUT {
+            return Err(too_large());
+        }
+        let decoded = snap::raw::Decoder::new()
+            .decompress_vec(block)
+            .map_err(|e| format!("corrupt snappy data: {}", e))?;
+        out.extend_from_slice(&decoded);
+        rest = &body[len..];
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEXT: &[u8] = b"hello hello hello";
+
+    /// "hello " as a literal, then an 11-byte copy from 6 bytes back
+    const SNAPPY_BLOCK: &[u8] = &[0x11, 0x14, b'h', b'e', b'l', b'l', b'o', b' ', 0x1d, 0x06];
+
+    #[test]
+    fn test_decompress_by_magic() {
+        let gzip = [
+            0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x03, 0xcb, 0x48, 0xcd, 0xc9, 0xc9, 0x57, 0xc8, 0x40,
+            0x90, 0x00, 0x80, 0x88, 0xf9, 0xe5, 0x11, 0x00, 0x00, 0x00,
+        ];
+        assert_eq!(decompress(&gzip), Ok((Codec::Gzip, TEXT.to_vec())));
+        assert!(decompress(&gzip[..gzip.len() - 10]).is_err());
+
+        let zstd = zstd::encode_all(TEXT, 3).unwrap();
+        assert_eq!(decompress(&zstd), Ok((Codec::Zstd, TEXT.to_vec())));
+        assert!(decompress(&zstd[..zstd.len() - 4]).is_err());
+
+        let mut lz4 = lz4_flex::frame::FrameEncoder::new(Vec::new());
+        std::io::Write::write_all(&mut lz4, TEXT).unwrap();
+        let lz4 = lz4.finish().unwrap();
+        assert_eq!(decompress(&lz4), Ok((Codec::Lz4, TEXT.to_vec())));
+
+        let mut framed = snap::write::FrameEncoder::new(Vec::new());
+        std::io::Write::write_all(&mut framed, TEXT).unwrap();
+        let framed = framed.into_inner().unwrap();
+        assert_eq!(decompress(&framed), Ok((Codec::Snappy, TEXT.to_vec())));
+        let xerial = [SNAPPY_XERIAL_MAGIC, &[0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 10], SNAPPY_BLOCK].concat();
+        assert_eq!(decompress(&xerial), Ok((Codec::Snappy, TEXT.to_vec())));
+        let bad_offset = [SNAPPY_XERIAL_MAGIC, &[0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 10], &SNAPPY_BLOCK[..9], &[0x07]].concat();
+        assert!(decompress(&bad_offset).is_err());
+
+        assert_eq!(decompress(b"{\"plain\": true}"), Err("not compressed".into()));
+    }
+}
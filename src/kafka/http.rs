@@ -0,0 +1,57 @@
+//! Blocking HTTP for the Schema Registry and OAuth token endpoints.
+//!
+//! Both talk to small JSON APIs from a worker thread, so one shared `ureq` agent (with rustls
+//! for HTTPS) covers them without an async HTTP stack.
+
+use std::io::Read;
+use std::sync::LazyLock;
+use std::time::Duration;
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+
+/// How long a request may take, connecting included
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Largest response body read; schemas and tokens are far smaller
+const MAX_BODY: u64 = 4 * 1024 * 1024;
+
+/// How much of an error response is quoted in the error message
+const ERROR_BODY_CHARS: usize = 200;
+
+static AGENT: LazyLock<ureq::Agent> = LazyLock::new(|| ureq::AgentBuilder::new().timeout(REQUEST_TIMEOUT).build());
+
+/// GET `url` as `accept`, with basic auth when `credentials` (`user:password`) are given.
+pub fn get(url: &str, accept: &str, credentials: Option<&str>) -> Result<Vec<u8>, String> {
+    let mut request = AGENT.get(url).set("Accept", accept);
+    if let Some(credentials) = credentials {
+        request = request.set("Authorization", &format!("Basic {}", STANDARD.encode(credentials)));
+    }
+    read_body(request.call())
+}
+
+/// POST an `application/x-www-form-urlencoded` body to `url`, accepting JSON.
+pub fn post_form(url: &str, form: &str) -> Result<Vec<u8>, String> {
+    let request = AGENT
+        .post(url)
+        .set("Accept", "application/json")
+        .set("Content-Type", "application/x-www-form-urlencoded");
+    read_body(request.send_string(form))
+}
+
+fn read_body(response: Result<ureq::Response, ureq::Error>) -> Result<Vec<u8>, String> {
+    match response {
+        Ok(response) => {
+            let mut body = Vec::new();
+            response.into_reader().take(MAX_BODY).read_to_end(&mut body).map_err(|e| e.to_string())?;
+            Ok(body)
+        }
+        Err(ureq::Error::Status(code, response)) => {
+            let body = response.into_string().unwrap_or_default();
+            let detail: String = body.trim().chars().take(ERROR_BODY_CHARS).collect();
+            Err(if detail.is_empty() { format!("HTTP {}", code) } else { format!("HTTP {}: {}", code, detail) })
+        }
+        Err(e) => Err(e.to_string()),
+    }
+}
+
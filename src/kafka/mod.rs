@@ -1,6 +1,13 @@
 mod admin_ffi;
+mod avro;
 pub mod client;
 pub mod config;
+pub mod decompress;
+mod http;
+mod oauth;
+pub mod protobuf;
+pub mod scan;
+pub mod schema_registry;
 
 pub use client::KafkaClient;
 pub use config::KafkaConfig;
@@ -0,0 +1,197 @@
+//! Bearer tokens for SASL/OAUTHBEARER, either static or from an OAuth client-credentials endpoint.
+
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
+use std::time::Duration;
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+
+use crate::app::validation::validate_http_url;
+use crate::error::{AppError, AppResult};
+use crate::kafka::config::SecurityConfig;
+use crate::kafka::http;
+
+/// Lifetime assumed for a token that doesn't say when it expires
+const DEFAULT_TOKEN_LIFETIME_MS: i64 = 60 * 60 * 1000;
+
+/// Wait before asking the endpoint again after a failed request
+pub const TOKEN_RETRY: Duration = Duration::from_secs(10);
+
+/// Endpoint tokens shared by every client of a connection, keyed by endpoint, client id and scope
+static TOKENS: LazyLock<Mutex<HashMap<String, BearerToken>>> = LazyLock::new(Default::default);
+
+/// A token handed to librdkafka
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BearerToken {
+    pub value: String,
+    pub principal: String,
+    pub issued_at_ms: i64,
+    /// Milliseconds since the Unix epoch
+    pub expires_at_ms: i64,
+}
+
+impl BearerToken {
+    /// How long to keep the token before fetching the next one: 80% of what is left,
+    /// the same margin librdkafka uses for its own refresh requests.
+    pub fn refresh_in(&self) -> Duration {
+        let remaining = self.expires_at_ms - chrono::Utc::now().timestamp_millis();
+        Duration::from_millis((remaining * 4 / 5).max(TOKEN_RETRY.as_millis() as i64) as u64)
+    }
+
+    /// Whether a cached token is young enough to give to another client
+    fn reusable(&self, now_ms: i64) -> bool {
+        now_ms - self.issued_at_ms < (self.expires_at_ms - self.issued_at_ms) / 2
+    }
+}
+
+/// Where a connection's OAUTHBEARER tokens come from.
+pub enum OAuthSource {
+    Static(String),
+    ClientCredentials {
+        endpoint: String,
+        client_id: String,
+        client_secret: String,
+        scope: Option<String>,
+    },
+}
+
+impl OAuthSource {
+    /// The token source for an OAUTHBEARER connection, `None` for any other security setup.
+    pub fn from_security(security: &SecurityConfig) -> Option<Self> {
+        let SecurityConfig::OAuthBearer { token_endpoint, client_id, secret, scope, .. } = security else {
+            return None;
+        };
+        Some(match token_endpoint {
+            None => Self::Static(secret.clone()),
+            Some(endpoint) => Self::ClientCredentials {
+                endpoint: endpoint.clone(),
+                client_id: client_id.clone(),
+                client_secret: secret.clone(),
+                scope: scope.clone(),
+            },
+        })
+    }
+
+    /// A token valid for a while yet, fetching a new one from the endpoint when needed.
+    pub fn token(&self) -> AppResult<BearerToken> {
+        let now = chrono::Utc::now().timestamp_millis();
+        match self {
+            Self::Static(token) => Ok(static_token(token, now)),
+            Self::ClientCredentials { endpoint, client_id, client_secret, scope } => {
+                let key = format!("{}\n{}\n{}", endpoint, client_id, scope.as_deref().unwrap_or_default());
+                if let Some(cached) = TOKENS.lock().unwrap_or_else(|e| e.into_inner()).get(&key).filter(|t| t.reusable(now)) {
+                    return Ok(cached.clone());
+                }
+                let token = request_token(endpoint, client_id, client_secret, scope.as_deref(), now)?;
+                TOKENS.lock().unwrap_or_else(|e| e.into_inner()).insert(key, token.clone());
+                Ok(token)
+            }
+        }
+    }
+}
+
+/// A user-supplied token, expiring when its JWT `exp` claim says if it has one.
+fn static_token(token: &str, now_ms: i64) -> BearerToken {
+    let claims = jwt_claims(token);
+    let claim = |name: &str| claims.as_ref().and_then(|c| c.get(name));
+    BearerToken {
+        value: token.to_string(),
+        principal: claim("sub").and_then(|s| s.as_str()).unwrap_or("kafka-tui").to_string(),
+        issued_at_ms: now_ms,
+        expires_at_ms: claim("exp")
+            .and_then(|e| e.as_i64())
+            .map_or(now_ms + DEFAULT_TOKEN_LIFETIME_MS, |secs| secs * 1000),
+    }
+}
+
+/// Run the client-credentials grant against `endpoint`.
+fn request_token(
+    endpoint: &str,
+    client_id: &str,
+    client_secret: &str,
+    scope: Option<&str>,
+    now_ms: i64,
+) -> AppResult<BearerToken> {
+    // Profiles can be imported from elsewhere, so the form's check may never have run
+    validate_http_url("token_endpoint", endpoint)?;
+    let mut form = format!(
+        "grant_type=client_credentials&client_id={}&client_secret={}",
+        form_encode(client_id),
+        form_encode(client_secret)
+    );
+    if let Some(scope) = scope {
+        form.push_str(&format!("&scope={}", form_encode(scope)));
+    }
+
+    let body = http::post_form(endpoint, &form)
+        .map_err(|e| AppError::Authentication(format!("Token request failed: {}", e)))?;
+    let (value, expires_in) = parse_token_response(&body)?;
+    let principal = jwt_claims(&value)
+        .and_then(|c| c.get("sub").and_then(|s| s.as_str()).map(String::from))
+        .unwrap_or_else(|| client_id.to_string());
+    Ok(BearerToken {
+        value,
+        principal,
+        issued_at_ms: now_ms,
+        expires_at_ms: expires_in.map_or(now_ms + DEFAULT_TOKEN_LIFETIME_MS, |secs| now_ms + secs * 1000),
+    })
+}
+
+/// The access token and its lifetime in seconds from a token endpoint's JSON answer.
+fn parse_token_response(body: &[u8]) -> AppResult<(String, Option<i64>)> {
+    let json: serde_json::Value = serde_json::from_slice(body)
+        .map_err(|e| AppError::Authentication(format!("Invalid token response: {}", e)))?;
+    let token = json
+        .get("access_token")
+        .and_then(|t| t.as_str())
+        .ok_or_else(|| AppError::Authentication("Token response has no access_token".into()))?;
+    // Some providers send the lifetime as a string
+    let expires_in = json.get("expires_in").and_then(|e| e.as_i64().or_else(|| e.as_str()?.parse().ok()));
+    Ok((token.to_string(), expires_in))
+}
+
+/// The claims of a JWT, without checking its signature.
+fn jwt_claims(token: &str) -> Option<serde_json::Value> {
+    let payload = token.split('.').nth(1)?;
+    let bytes = URL_SAFE_NO_PAD.decode(payload.trim_end_matches('=')).ok()?;
+    serde_json::from_slice(&bytes).ok()
+}
+
+/// Percent-encode a value for an `application/x-www-form-urlencoded` body.
+fn form_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => (b as char).to_string(),
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_token_lifetimes() {
+        let claims = URL_SAFE_NO_PAD.encode(r#"{"sub":"svc-orders","exp":1700000000}"#);
+        let jwt = format!("eyJhbGciOiJSUzI1NiJ9.{}.c2ln", claims);
+        let token = static_token(&jwt, 1_699_990_000_000);
+        assert_eq!(token.principal, "svc-orders");
+        assert_eq!(token.expires_at_ms, 1_700_000_000_000);
+
+        // An opaque token gets the default lifetime
+        let opaque = static_token("not-a-jwt", 1_000);
+        assert_eq!(opaque.expires_at_ms, 1_000 + DEFAULT_TOKEN_LIFETIME_MS);
+        assert!(opaque.reusable(1_000 + DEFAULT_TOKEN_LIFETIME_MS / 4));
+        assert!(!opaque.reusable(1_000 + DEFAULT_TOKEN_LIFETIME_MS * 3 / 4));
+
+        assert_eq!(
+            parse_token_response(br#"{"access_token":"abc","token_type":"bearer","expires_in":"300"}"#).unwrap(),
+            ("abc".to_string(), Some(300))
+        );
+        assert!(parse_token_response(br#"{"error":"invalid_client"}"#).is_err());
+        assert_eq!(form_encode("a b&c=d/é"), "a%20b%26c%3Dd%2F%C3%A9");
+    }
+}
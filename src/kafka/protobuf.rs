@@ -0,0 +1,200 @@
+//! Protobuf decoding for display, driven by a compiled `FileDescriptorSet`.
+//!
+//! The descriptor set (`protoc --include_imports --descriptor_set_out=types.protoset`) is
+//! loaded into a `prost-reflect` pool, and payloads are shown in the proto3 JSON mapping:
+//! fields by JSON name, enums by name, maps as objects and bytes as base64, while 64-bit
+//! integers stay numbers. Fields the descriptor doesn't know are skipped.
+
+use std::path::Path;
+
+use prost_reflect::{DescriptorPool, DynamicMessage, SerializeOptions};
+use serde_json::Value;
+
+use crate::error::{AppError, AppResult};
+
+use super::schema_registry::wire_schema_id;
+
+/// Message and enum types from a descriptor set
+#[derive(Debug, Default)]
+pub struct ProtoDescriptors {
+    pool: DescriptorPool,
+}
+
+impl ProtoDescriptors {
+    /// Read a `.protoset` file.
+    pub fn load(path: &Path) -> AppResult<Self> {
+        let bytes = std::fs::read(path)
+            .map_err(|e| AppError::Config(format!("Cannot read {}: {}", path.display(), e)))?;
+        Self::parse(&bytes).map_err(|e| AppError::Config(format!("Invalid descriptor set {}: {}", path.display(), e)))
+    }
+
+    /// Parse a serialized `FileDescriptorSet`.
+    pub fn parse(bytes: &[u8]) -> Result<Self, String> {
+        let pool = DescriptorPool::decode(bytes).map_err(|e| e.to_string())?;
+        if pool.all_messages().len() == 0 {
+            return Err("no message types".into());
+        }
+        Ok(Self { pool })
+    }
+
+    /// Whether `type_name` (e.g. `shop.v1.Order`) is a message type in the set
+    pub fn contains(&self, type_name: &str) -> bool {
+        self.pool.get_message_by_name(type_name.trim_start_matches('.')).is_some()
+    }
+
+    /// Decode a payload as `type_name`; a Confluent framing header is skipped.
+    pub fn decode(&self, type_name: &str, payload: &[u8]) -> Result<Value, String> {
+        let type_name = type_name.trim_start_matches('.');
+        let descriptor = self
+            .pool
+            .get_message_by_name(type_name)
+            .ok_or_else(|| format!("unknown message type '{}'", type_name))?;
+        let message = DynamicMessage::decode(descriptor, strip_confluent_framing(payload)).map_err(|e| e.to_string())?;
+        let options = SerializeOptions::new().stringify_64_bit_integers(false);
+        message.serialize_with_options(serde_json::value::Serializer, &options).map_err(|e| e.to_string())
+    }
+}
+
+/// Skip the magic byte, schema id and message indexes the Confluent serializer writes.
+///
+/// A plain protobuf payload can't start with a zero byte (field number 0 is invalid), so
+/// this never mistakes one for a framed payload.
+fn strip_confluent_framing(payload: &[u8]) -> &[u8] {
+    let Some((_, mut rest)) = wire_schema_id(payload) else {
+        return payload;
+    };
+    let mut skip = || {
+        // Zig-zag encoded count; zero is shorthand for the first message in the schema
+        let count = varint(&mut rest)? >> 1;
+        for _ in 0..count {
+            varint(&mut rest)?;
+        }
+        Some(rest)
+    };
+    skip().unwrap_or(payload)
+}
+
+/// Read a base-128 varint off the front of `bytes`
+fn varint(bytes: &mut &[u8]) -> Option<u64> {
+    let mut value: u64 = 0;
+    for shift in (0..64).step_by(7) {
+        let (&byte, rest) = bytes.split_first()?;
+        *bytes = rest;
+        value |= u64::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            return Some(value);
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn varint(mut v: u64, out: &mut Vec<u8>) {
+        while v >= 0x80 {
+            out.push(v as u8 | 0x80);
+            v >>= 7;
+        }
+        out.push(v as u8);
+    }
+
+    fn int_field(number: u32, v: u64) -> Vec<u8> {
+        let mut out = Vec::new();
+        varint(u64::from(number) << 3, &mut out);
+        varint(v, &mut out);
+        out
+    }
+
+    fn bytes_field(number: u32, bytes: &[u8]) -> Vec<u8> {
+        let mut out = Vec::new();
+        varint(u64::from(number) << 3 | 2, &mut out);
+        varint(bytes.len() as u64, &mut out);
+        out.extend_from_slice(bytes);
+        out
+    }
+
+    fn field_descriptor(name: &str, number: u64, repeated: bool, ty: u64, type_name: &str) -> Vec<u8> {
+        [
+            bytes_field(1, name.as_bytes()),
+            int_field(3, number),
+            int_field(4, if repeated { 3 } else { 1 }),
+            int_field(5, ty),
+            bytes_field(6, type_name.as_bytes()),
+        ]
+        .concat()
+    }
+
+    /// syntax = "proto3"; package shop; enum Status { NEW = 0; PAID = 1; }
+    /// message Order { string id = 1; sint64 delta = 2; repeated int32 codes = 3; Status status = 4;
+    ///                 map<string, int32> stock = 5; Order parent = 6; }
+    fn descriptor_set() -> Vec<u8> {
+        let status = [
+            bytes_field(1, b"Status"),
+            bytes_field(2, &[bytes_field(1, b"NEW"), int_field(2, 0)].concat()),
+            bytes_field(2, &[bytes_field(1, b"PAID"), int_field(2, 1)].concat()),
+        ]
+        .concat();
+        let stock_entry = [
+            bytes_field(1, b"StockEntry"),
+            bytes_field(2, &field_descriptor("key", 1, false, 9, "")),
+            bytes_field(2, &field_descriptor("value", 2, false, 5, "")),
+            bytes_field(7, &int_field(7, 1)),
+        ]
+        .concat();
+        let order = [
+            bytes_field(1, b"Order"),
+            bytes_field(2, &field_descriptor("id", 1, false, 9, "")),
+            bytes_field(2, &field_descriptor("delta", 2, false, 18, "")),
+            bytes_field(2, &field_descriptor("codes", 3, true, 5, "")),
+            bytes_field(2, &field_descriptor("status", 4, false, 14, ".shop.Status")),
+            bytes_field(2, &field_descriptor("stock", 5, true, 11, ".shop.Order.StockEntry")),
+            bytes_field(2, &field_descriptor("parent", 6, false, 11, ".shop.Order")),
+            bytes_field(3, &stock_entry),
+        ]
+        .concat();
+        let file = [
+            bytes_field(1, b"shop.proto"),
+            bytes_field(2, b"shop"),
+            bytes_field(4, &order),
+            bytes_field(5, &status),
+            bytes_field(12, b"proto3"),
+        ]
+        .concat();
+        bytes_field(1, &file)
+    }
+
+    #[test]
+    fn test_decode_with_descriptor_set() {
+        let set = ProtoDescriptors::parse(&descriptor_set()).unwrap();
+        assert!(set.contains("shop.Order"));
+        assert!(set.contains(".shop.Order.StockEntry"));
+        assert!(!set.contains("Order"));
+
+        let payload = [
+            bytes_field(1, b"o-1"),
+            int_field(2, 3), // zig-zag -2
+            bytes_field(3, &[1, 2]),
+            int_field(3, 7),
+            int_field(4, 1),
+            bytes_field(5, &[bytes_field(1, b"apple"), int_field(2, 4)].concat()),
+            bytes_field(6, &[bytes_field(1, b"o-0"), int_field(4, 9)].concat()),
+            int_field(15, 1), // not in the descriptor
+        ]
+        .concat();
+        let expected = serde_json::json!({
+            "id": "o-1", "delta": -2, "codes": [1, 2, 7], "status": "PAID",
+            "stock": {"apple": 4}, "parent": {"id": "o-0", "status": 9}
+        });
+        assert_eq!(set.decode("shop.Order", &payload).unwrap(), expected);
+
+        // Confluent framing: magic byte, schema id 7, message index list [0]
+        let framed = [&[0, 0, 0, 0, 7, 0][..], &payload].concat();
+        assert_eq!(set.decode("shop.Order", &framed).unwrap(), expected);
+
+        assert!(set.decode("shop.Order", &payload[..3]).is_err());
+        assert!(set.decode("shop.Missing", &payload).is_err());
+        assert!(ProtoDescriptors::parse(b"").is_err());
+    }
+}
@@ -0,0 +1,104 @@
+//! Pattern matching and bookkeeping for scanning a topic for matching messages.
+
+use regex::{Regex, RegexBuilder};
+
+use crate::app::state::KafkaMessage;
+use crate::error::{AppError, AppResult};
+
+/// A scan stops once this many messages matched
+pub const SCAN_MAX_MATCHES: usize = 500;
+
+/// A scan stops once this many messages were read, matching or not
+pub const SCAN_MAX_SCANNED: u64 = 1_000_000;
+
+/// What a scan looks for in message keys and values.
+#[derive(Debug, Clone)]
+pub enum ScanPattern {
+    /// Case-insensitive substring, stored lowercased
+    Text(String),
+    /// Case-insensitive regular expression, typed as `/pattern/`
+    Regex(Regex),
+}
+
+impl ScanPattern {
+    /// Parse user input: `/.../` is a regex, anything else a plain substring.
+    pub fn parse(input: &str) -> AppResult<Self> {
+        let input = input.trim();
+        if input.is_empty() {
+            return Err(AppError::InvalidInput("Enter text or a /regex/ to find".into()));
+        }
+        match input.strip_prefix('/').and_then(|s| s.strip_suffix('/')) {
+            Some(re) if !re.is_empty() => RegexBuilder::new(re)
+                .case_insensitive(true)
+                .build()
+                .map(Self::Regex)
+                .map_err(|e| AppError::InvalidInput(format!("Invalid regex: {}", e))),
+            _ => Ok(Self::Text(input.to_lowercase())),
+        }
+    }
+
+    /// Whether the message key or value matches.
+    pub fn is_match(&self, msg: &KafkaMessage) -> bool {
+        let key = msg.key.as_deref();
+        match self {
+            Self::Text(text) => {
+                msg.value.to_lowercase().contains(text) || key.is_some_and(|k| k.to_lowercase().contains(text))
+            }
+            Self::Regex(re) => re.is_match(&msg.value) || key.is_some_and(|k| re.is_match(k)),
+        }
+    }
+}
+
+/// Matches found since the previous report, and how many messages were read so far.
+#[derive(Debug)]
+pub struct ScanProgress {
+    pub scanned: u64,
+    pub matches: Vec<KafkaMessage>,
+}
+
+/// Why a scan ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScanStop {
+    /// Every partition was read up to its high watermark at the start of the scan
+    EndOfTopic,
+    MatchCap,
+    ScanCap,
+    Cancelled,
+}
+
+impl ScanStop {
+    pub fn describe(&self) -> &'static str {
+        match self {
+            Self::EndOfTopic => "reached the end",
+            Self::MatchCap => "match limit reached",
+            Self::ScanCap => "scan limit reached",
+            Self::Cancelled => "stopped",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(key: Option<&str>, value: &str) -> KafkaMessage {
+        KafkaMessage { key: key.map(Into::into), value: value.into(), ..Default::default() }
+    }
+
+    #[test]
+    fn test_scan_pattern_matches_key_or_value() {
+        let text = ScanPattern::parse("Order 12345").unwrap();
+        assert!(text.is_match(&message(None, r#"{"note":"order 12345 shipped"}"#)));
+        assert!(text.is_match(&message(Some("ORDER 12345"), "")));
+        assert!(!text.is_match(&message(Some("order 1234"), "order-12345")));
+
+        let regex = ScanPattern::parse(r"/order-\d{5}\b/").unwrap();
+        assert!(regex.is_match(&message(None, "ORDER-12345 shipped")));
+        assert!(!regex.is_match(&message(Some("order-1234"), "order-123456")));
+
+        // A lone slash or an empty pair is looked for literally
+        assert!(matches!(ScanPattern::parse("//"), Ok(ScanPattern::Text(t)) if t == "//"));
+        assert!(ScanPattern::parse("/(/").is_err());
+        assert!(ScanPattern::parse("  ").is_err());
+    }
+}
@@ -2,6 +2,7 @@ pub mod app;
 pub mod config;
 pub mod error;
 pub mod events;
+pub mod headless;
 pub mod kafka;
 pub mod storage;
 pub mod ui;
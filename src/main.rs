@@ -3,12 +3,17 @@ use std::path::PathBuf;
 
 use clap::Parser;
 use crossterm::{
+    event::{DisableMouseCapture, EnableMouseCapture},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 use ratatui::prelude::*;
 
+use kafka_tui::app::state::OffsetMode;
 use kafka_tui::app::App;
+use kafka_tui::headless::{self, ConsumeOptions};
+use kafka_tui::config::AppConfig;
+use kafka_tui::storage::paths;
 
 #[derive(Parser, Debug)]
 #[command(
@@ -23,10 +28,38 @@ struct Args {
     #[arg(short, long)]
     config: Option<PathBuf>,
 
-    /// Broker addresses (comma-separated)
-    #[arg(short, long)]
+    /// Keep profiles, settings, logs and exports in this directory instead of the platform defaults
+    #[arg(long, env = "KAFKA_TUI_DATA_DIR", value_name = "DIR")]
+    data_dir: Option<PathBuf>,
+
+    /// Broker addresses (comma-separated) to connect to without a saved profile
+    #[arg(short, long, conflicts_with = "profile")]
     brokers: Option<String>,
 
+    /// Name of a saved connection profile to connect to on startup
+    #[arg(short, long)]
+    profile: Option<String>,
+
+    /// Print messages from TOPIC to stdout as NDJSON instead of starting the TUI
+    #[arg(long, value_name = "TOPIC")]
+    consume: Option<String>,
+
+    /// Where --consume starts reading
+    #[arg(long, requires = "consume", value_parser = ["earliest", "latest"])]
+    from: Option<String>,
+
+    /// Only consume this partition
+    #[arg(long, requires = "consume")]
+    partition: Option<i32>,
+
+    /// Stop after this many messages (default 100 unless --follow)
+    #[arg(long, requires = "consume")]
+    limit: Option<usize>,
+
+    /// Keep printing new messages until interrupted
+    #[arg(long, requires = "consume")]
+    follow: bool,
+
     /// Verbosity level (-v, -vv, -vvv)
     #[arg(short, long, action = clap::ArgAction::Count)]
     verbose: u8,
@@ -43,13 +76,25 @@ async fn main() -> anyhow::Result<()> {
         std::env::set_var("RDKAFKA_LOG_LEVEL", rdkafka_level);
     }
 
+    if let Some(dir) = args.data_dir.clone() {
+        paths::set_data_dir(dir);
+    }
+
     // Setup logging — guard must live until app exits
     let _log_guard = setup_logging(args.verbose)?;
 
+    if let Some(topic) = args.consume.clone() {
+        if let Err(e) = run_headless(args, topic).await {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
     // Setup terminal
     enable_raw_mode()?;
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen)?;
+    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
@@ -61,7 +106,7 @@ async fn main() -> anyhow::Result<()> {
 
     // Cleanup terminal
     disable_raw_mode()?;
-    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+    execute!(terminal.backend_mut(), LeaveAlternateScreen, DisableMouseCapture)?;
     terminal.show_cursor()?;
 
     // Handle any errors from the app
@@ -75,18 +120,46 @@ async fn main() -> anyhow::Result<()> {
 
 async fn run_app<B: Backend>(
     terminal: &mut Terminal<B>,
-    _args: Args,
+    args: Args,
 ) -> anyhow::Result<()> {
-    let mut app = App::new();
+    let mut app = match AppConfig::load(args.config) {
+        Ok(config) => App::new(&config),
+        Err(e) => {
+            let mut app = App::new(&AppConfig::default());
+            app.startup_warning(&format!("Failed to load config, using defaults: {}", e));
+            app
+        }
+    };
 
-    // If brokers were provided via CLI, we could auto-connect here
-    // For now, just start the app normally
+    if let Some(name) = &args.profile {
+        app.connect_profile_on_start(name);
+    } else if let Some(brokers) = &args.brokers {
+        app.connect_brokers_on_start(brokers);
+    }
 
     app.run(terminal).await?;
 
     Ok(())
 }
 
+/// `--consume`: stream messages to stdout for scripts, bypassing the TUI.
+async fn run_headless(args: Args, topic: String) -> anyhow::Result<()> {
+    let profile = headless::resolve_profile(args.profile.as_deref(), args.brokers.as_deref())?;
+    let opts = ConsumeOptions {
+        topic,
+        offset_mode: match args.from.as_deref() {
+            Some("earliest") => OffsetMode::Earliest,
+            _ => OffsetMode::Latest,
+        },
+        partition: args.partition,
+        limit: args.limit,
+        follow: args.follow,
+    };
+    let printed = headless::consume(profile, opts).await?;
+    tracing::info!(printed, "Headless consume finished");
+    Ok(())
+}
+
 fn setup_logging(verbosity: u8) -> anyhow::Result<tracing_appender::non_blocking::WorkerGuard> {
     let log_level = match verbosity {
         0 => tracing::Level::WARN,
@@ -96,10 +169,7 @@ fn setup_logging(verbosity: u8) -> anyhow::Result<tracing_appender::non_blocking
     };
 
     // For now, log to a file in the data directory
-    let log_dir = dirs::data_dir()
-        .unwrap_or_else(|| PathBuf::from("."))
-        .join("kafka-tui")
-        .join("logs");
+    let log_dir = paths::data_dir().join("logs");
 
     std::fs::create_dir_all(&log_dir)?;
 
@@ -0,0 +1,91 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use uuid::Uuid;
+
+use crate::app::state::Bookmarks;
+use crate::error::{AppError, AppResult};
+use crate::storage::paths;
+
+/// Get the path to the bookmarks file
+fn get_bookmarks_path() -> PathBuf {
+    let config_dir = paths::config_dir();
+
+    // Ensure directory exists
+    let _ = fs::create_dir_all(&config_dir);
+
+    config_dir.join("bookmarks.json")
+}
+
+/// Load the bookmarks of a connection profile
+pub fn load_bookmarks(profile_id: Uuid) -> AppResult<Bookmarks> {
+    Ok(load_from(&get_bookmarks_path())?.remove(&profile_id).unwrap_or_default())
+}
+
+/// Replace the bookmarks of a connection profile
+pub fn save_bookmarks(profile_id: Uuid, bookmarks: &Bookmarks) -> AppResult<()> {
+    save_at(&get_bookmarks_path(), profile_id, bookmarks)
+}
+
+/// Bookmarks for every profile, keyed by profile id
+fn load_from(path: &Path) -> AppResult<HashMap<Uuid, Bookmarks>> {
+    if !path.exists() {
+        return Ok(HashMap::new());
+    }
+
+    let content = fs::read_to_string(path)
+        .map_err(|e| AppError::Config(format!("Failed to read bookmarks file: {}", e)))?;
+
+    if content.trim().is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    serde_json::from_str(&content)
+        .map_err(|e| AppError::Config(format!("Failed to parse bookmarks: {}", e)))
+}
+
+/// A file that can't be read is moved aside to `bookmarks.json.bak` first, so saving one
+/// profile's bookmarks never writes over the others' it couldn't parse.
+fn save_at(path: &Path, profile_id: Uuid, bookmarks: &Bookmarks) -> AppResult<()> {
+    let mut all = match load_from(path) {
+        Ok(all) => all,
+        Err(e) => {
+            let backup = path.with_extension("json.bak");
+            fs::rename(path, &backup)
+                .map_err(|re| AppError::Config(format!("{}; could not move it aside: {}", e, re)))?;
+            tracing::warn!(error = %e, backup = %backup.display(), "Unreadable bookmarks file moved aside");
+            HashMap::new()
+        }
+    };
+    all.insert(profile_id, bookmarks.clone());
+
+    let content = serde_json::to_string_pretty(&all)
+        .map_err(|e| AppError::Config(format!("Failed to serialize bookmarks: {}", e)))?;
+
+    fs::write(path, content)
+        .map_err(|e| AppError::Config(format!("Failed to write bookmarks file: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unreadable_bookmarks_are_kept_aside() {
+        let dir = std::env::temp_dir().join(format!("kafka-tui-bookmarks-{}", Uuid::new_v4()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("bookmarks.json");
+        fs::write(&path, "{ truncated").unwrap();
+
+        let profile = Uuid::new_v4();
+        let bookmarks = Bookmarks { topics: ["orders".to_string()].into(), ..Default::default() };
+        save_at(&path, profile, &bookmarks).unwrap();
+
+        assert_eq!(fs::read_to_string(dir.join("bookmarks.json.bak")).unwrap(), "{ truncated");
+        let all = load_from(&path).unwrap();
+        assert_eq!(all.len(), 1);
+        assert_eq!(all[&profile].topics, bookmarks.topics);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -1,14 +1,13 @@
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-use crate::app::state::ConnectionProfile;
+use crate::app::state::{ConnectionProfile, SecretStorage};
+use crate::storage::{paths, secrets};
 use crate::error::{AppError, AppResult};
 
 /// Get the path to the connections file
 fn get_connections_path() -> PathBuf {
-    let config_dir = dirs::config_dir()
-        .unwrap_or_else(|| PathBuf::from("."))
-        .join("kafka-tui");
+    let config_dir = paths::config_dir();
 
     // Ensure directory exists
     let _ = fs::create_dir_all(&config_dir);
@@ -18,13 +17,15 @@ fn get_connections_path() -> PathBuf {
 
 /// Load all saved connection profiles
 pub fn load_connections() -> AppResult<Vec<ConnectionProfile>> {
-    let path = get_connections_path();
+    load_from(&get_connections_path())
+}
 
+fn load_from(path: &Path) -> AppResult<Vec<ConnectionProfile>> {
     if !path.exists() {
         return Ok(vec![]);
     }
 
-    let content = fs::read_to_string(&path)
+    let content = fs::read_to_string(path)
         .map_err(|e| AppError::Config(format!("Failed to read connections file: {}", e)))?;
 
     if content.trim().is_empty() {
@@ -54,19 +55,245 @@ pub fn save_connection(profile: &ConnectionProfile) -> AppResult<()> {
 /// Delete a connection profile by ID
 pub fn delete_connection(id: uuid::Uuid) -> AppResult<()> {
     let mut profiles = load_connections().unwrap_or_default();
+    let in_keyring = profiles
+        .iter()
+        .any(|p| p.id == id && p.secret_storage == SecretStorage::Keyring);
+    if in_keyring {
+        if let Err(e) = secrets::delete_secret(id) {
+            tracing::warn!(%id, error = %e, "Failed to remove keyring entry");
+        }
+    }
     profiles.retain(|p| p.id != id);
     save_all_connections(&profiles)
 }
 
 /// Save all connections to file
 fn save_all_connections(profiles: &[ConnectionProfile]) -> AppResult<()> {
-    let path = get_connections_path();
+    save_to(&get_connections_path(), profiles)
+}
 
+fn save_to(path: &Path, profiles: &[ConnectionProfile]) -> AppResult<()> {
     let content = serde_json::to_string_pretty(profiles)
         .map_err(|e| AppError::Config(format!("Failed to serialize connections: {}", e)))?;
 
-    fs::write(&path, content)
+    fs::write(path, content)
         .map_err(|e| AppError::Config(format!("Failed to write connections file: {}", e)))?;
 
     Ok(())
 }
+
+/// Default file for exported profiles: `~/kafka-tui-connections.json`
+pub fn default_transfer_path() -> PathBuf {
+    dirs::home_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("kafka-tui-connections.json")
+}
+
+/// Expand a leading `~/` to the home directory
+pub fn expand_home(path: &str) -> PathBuf {
+    match (path.strip_prefix("~/"), dirs::home_dir()) {
+        (Some(rest), Some(home)) => home.join(rest),
+        _ => PathBuf::from(path),
+    }
+}
+
+/// Write every saved profile to `path`. Secrets are blanked unless `include_secrets` is set,
+/// in which case keyring secrets are resolved so the file is usable on another machine.
+pub fn export_all(path: &Path, include_secrets: bool) -> AppResult<usize> {
+    let mut profiles = load_connections()?;
+
+    for profile in &mut profiles {
+        if include_secrets {
+            secrets::resolve_profile_secret(profile)?;
+        } else {
+            redact(profile);
+        }
+        profile.secret_storage = SecretStorage::Plaintext;
+    }
+
+    let content = serde_json::to_string_pretty(&profiles)
+        .map_err(|e| AppError::Config(format!("Failed to serialize connections: {}", e)))?;
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, content)
+        .map_err(|e| AppError::Config(format!("Failed to write {}: {}", path.display(), e)))?;
+
+    Ok(profiles.len())
+}
+
+/// Blank the profile's secrets for an export without them
+fn redact(profile: &mut ConnectionProfile) {
+    if let Some(secret) = profile.auth.secret_mut() {
+        secret.clear();
+    }
+    if let Some(credentials) = profile.schema_registry_credentials.as_mut() {
+        credentials.clear();
+    }
+}
+
+/// Outcome of an import
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct ImportSummary {
+    pub added: usize,
+    pub updated: usize,
+    pub skipped: usize,
+}
+
+/// Merge the profiles in `path` into the saved ones. Profiles whose id is already saved
+/// replace the saved one when `overwrite` is set and are skipped otherwise; secrets the
+/// file leaves blank keep their saved value.
+///
+/// Fails without writing anything when the saved profiles can't be read, rather than
+/// replacing them with the imported ones.
+pub fn import(path: &Path, overwrite: bool) -> AppResult<ImportSummary> {
+    import_into(&get_connections_path(), path, overwrite)
+}
+
+fn import_into(saved_path: &Path, path: &Path, overwrite: bool) -> AppResult<ImportSummary> {
+    let content = fs::read_to_string(path)
+        .map_err(|e| AppError::Config(format!("Failed to read {}: {}", path.display(), e)))?;
+    let incoming: Vec<ConnectionProfile> = serde_json::from_str(&content)
+        .map_err(|e| AppError::Config(format!("Failed to parse {}: {}", path.display(), e)))?;
+
+    let mut profiles = load_from(saved_path)?;
+    let summary = merge_profiles(&mut profiles, incoming, overwrite);
+    // Secrets imported into a profile that keeps the rest in the keyring go there too
+    for profile in &mut profiles {
+        secrets::stash_profile_secret(profile)?;
+    }
+    save_to(saved_path, &profiles)?;
+    Ok(summary)
+}
+
+fn merge_profiles(
+    profiles: &mut Vec<ConnectionProfile>,
+    incoming: Vec<ConnectionProfile>,
+    overwrite: bool,
+) -> ImportSummary {
+    let mut summary = ImportSummary::default();
+    for profile in incoming {
+        match profiles.iter_mut().find(|p| p.id == profile.id) {
+            Some(existing) if overwrite => {
+                let mut profile = profile;
+                keep_saved_secrets(existing, &mut profile);
+                *existing = profile;
+                summary.updated += 1;
+            }
+            Some(_) => summary.skipped += 1,
+            None => {
+                profiles.push(profile);
+                summary.added += 1;
+            }
+        }
+    }
+    summary
+}
+
+/// Carry the saved secrets over to `incoming` wherever it has them blank or as the keyring
+/// sentinel, as a redacted export does. A kept keyring secret keeps the profile in the keyring.
+fn keep_saved_secrets(saved: &mut ConnectionProfile, incoming: &mut ConnectionProfile) {
+    let same_auth = std::mem::discriminant(&saved.auth) == std::mem::discriminant(&incoming.auth);
+    let slots = [
+        (incoming.auth.secret_mut().filter(|_| same_auth), saved.auth.secret_mut()),
+        (incoming.schema_registry_credentials.as_mut(), saved.schema_registry_credentials.as_mut()),
+    ];
+    let mut in_keyring = false;
+    for (new, old) in slots {
+        let (Some(new), Some(old)) = (new, old) else { continue };
+        if (new.is_empty() || new == secrets::KEYRING_SENTINEL) && !old.is_empty() {
+            new.clone_from(old);
+            in_keyring |= old == secrets::KEYRING_SENTINEL;
+        }
+    }
+    if in_keyring {
+        incoming.secret_storage = SecretStorage::Keyring;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::state::AuthConfig;
+
+    fn profile(name: &str) -> ConnectionProfile {
+        ConnectionProfile { name: name.into(), ..Default::default() }
+    }
+
+    #[test]
+    fn test_merge_profiles() {
+        let a = profile("a");
+        let b = profile("b");
+        let renamed_a = ConnectionProfile { name: "a2".into(), ..a.clone() };
+
+        let mut saved = vec![a.clone()];
+        let summary = merge_profiles(&mut saved, vec![renamed_a.clone(), b.clone()], false);
+        assert_eq!(summary, ImportSummary { added: 1, updated: 0, skipped: 1 });
+        assert_eq!(saved[0].name, "a");
+
+        let summary = merge_profiles(&mut saved, vec![renamed_a], true);
+        assert_eq!(summary, ImportSummary { added: 0, updated: 1, skipped: 0 });
+        assert_eq!(saved[0].name, "a2");
+        assert_eq!(saved.len(), 2);
+    }
+
+    #[test]
+    fn test_redacted_import_keeps_saved_secrets() {
+        let plain = ConnectionProfile {
+            auth: AuthConfig::SaslPlain { username: "app".into(), password: "hunter2".into() },
+            schema_registry_credentials: Some("app:s3cret".into()),
+            ..profile("plain")
+        };
+        let keyring = ConnectionProfile {
+            auth: AuthConfig::SaslPlain { username: "app".into(), password: secrets::KEYRING_SENTINEL.into() },
+            secret_storage: SecretStorage::Keyring,
+            ..profile("keyring")
+        };
+        let mut saved = vec![plain.clone(), keyring.clone()];
+
+        let exported: Vec<ConnectionProfile> = saved
+            .iter()
+            .cloned()
+            .map(|mut p| {
+                redact(&mut p);
+                p.secret_storage = SecretStorage::Plaintext;
+                p.name.push_str("-renamed");
+                p
+            })
+            .collect();
+        let summary = merge_profiles(&mut saved, exported, true);
+        assert_eq!(summary, ImportSummary { added: 0, updated: 2, skipped: 0 });
+
+        assert_eq!(saved[0].name, "plain-renamed");
+        for (merged, before) in saved.iter().zip([&plain, &keyring]) {
+            assert_eq!(merged.auth, before.auth);
+            assert_eq!(merged.schema_registry_credentials, before.schema_registry_credentials);
+            assert_eq!(merged.secret_storage, before.secret_storage);
+        }
+
+        // A new secret in the file still replaces the saved one
+        let mut changed = saved[0].clone();
+        changed.auth = AuthConfig::SaslPlain { username: "app".into(), password: "rotated".into() };
+        merge_profiles(&mut saved, vec![changed.clone()], true);
+        assert_eq!(saved[0].auth, changed.auth);
+    }
+
+    #[test]
+    fn test_import_refuses_unreadable_saved_profiles() {
+        let dir = std::env::temp_dir().join(format!("kafka-tui-import-{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&dir).unwrap();
+        let saved = dir.join("connections.json");
+        let file = dir.join("export.json");
+        fs::write(&saved, "[{ truncated").unwrap();
+        save_to(&file, &[profile("imported")]).unwrap();
+
+        assert!(import_into(&saved, &file, true).is_err());
+        assert_eq!(fs::read_to_string(&saved).unwrap(), "[{ truncated");
+
+        fs::remove_file(&saved).unwrap();
+        assert_eq!(import_into(&saved, &file, true).unwrap(), ImportSummary { added: 1, updated: 0, skipped: 0 });
+        assert_eq!(load_from(&saved).unwrap()[0].name, "imported");
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,118 @@
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use chrono::Utc;
+
+use crate::app::state::{ExportFormat, KafkaMessage, LogEntry};
+use crate::error::{AppError, AppResult};
+use crate::storage::paths;
+
+/// Build the default export path for a topic: `<data_dir>/exports/<topic>-<timestamp>.<ext>`
+pub fn default_export_path(topic: &str, format: ExportFormat) -> PathBuf {
+    paths::data_dir()
+        .join("exports")
+        .join(format!(
+            "{}-{}.{}",
+            topic,
+            Utc::now().format("%Y%m%d-%H%M%S"),
+            format.extension()
+        ))
+}
+
+/// Build the default log export path: `<data_dir>/exports/logs-<timestamp>.log`
+pub fn default_log_export_path() -> PathBuf {
+    paths::data_dir()
+        .join("exports")
+        .join(format!("logs-{}.log", Utc::now().format("%Y%m%d-%H%M%S")))
+}
+
+/// Write log entries to `path`, one `<timestamp> <LEVEL> <message>` line each
+pub fn export_logs(path: &Path, entries: &[LogEntry]) -> AppResult<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    let mut out = std::io::BufWriter::new(fs::File::create(path)?);
+    for entry in entries {
+        writeln!(out, "{}", entry.line())?;
+    }
+    out.flush()?;
+    Ok(())
+}
+
+/// Write messages to `path` as newline-delimited JSON or CSV
+pub fn export_messages(path: &Path, format: ExportFormat, messages: &[KafkaMessage]) -> AppResult<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    let mut out = std::io::BufWriter::new(fs::File::create(path)?);
+    match format {
+        ExportFormat::Json => {
+            for msg in messages {
+                let line = serde_json::to_string(&message_to_json(msg))
+                    .map_err(|e| AppError::Config(format!("Failed to serialize message: {}", e)))?;
+                writeln!(out, "{}", line)?;
+            }
+        }
+        ExportFormat::Csv => {
+            writeln!(out, "partition,offset,timestamp,key,value,headers")?;
+            for msg in messages {
+                let headers = serde_json::to_string(&msg.headers)
+                    .map_err(|e| AppError::Config(format!("Failed to serialize headers: {}", e)))?;
+                writeln!(
+                    out,
+                    "{},{},{},{},{},{}",
+                    msg.partition,
+                    msg.offset,
+                    msg.timestamp.map(|t| t.to_rfc3339()).unwrap_or_default(),
+                    csv_field(msg.key.as_deref().unwrap_or_default()),
+                    csv_field(&msg.value),
+                    csv_field(&headers),
+                )?;
+            }
+        }
+    }
+    out.flush()?;
+    Ok(())
+}
+
+/// JSON object for one message, as written to `.jsonl` exports
+pub fn message_to_json(msg: &KafkaMessage) -> serde_json::Value {
+    serde_json::json!({
+        "partition": msg.partition,
+        "offset": msg.offset,
+        "timestamp": msg.timestamp.map(|t| t.to_rfc3339()),
+        "key": msg.key,
+        "value": msg.value,
+        "headers": msg.headers,
+    })
+}
+
+/// Quote a CSV field when it contains a delimiter, quote, or line break.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_csv_field_plain() {
+        assert_eq!(csv_field("hello"), "hello");
+        assert_eq!(csv_field(""), "");
+    }
+
+    #[test]
+    fn test_csv_field_quoted() {
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(csv_field("line\nbreak"), "\"line\nbreak\"");
+    }
+}
@@ -0,0 +1,118 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::app::state::HistoryEntry;
+use crate::error::{AppError, AppResult};
+use crate::storage::paths;
+
+/// Oldest entries beyond this are dropped when a new one is recorded
+pub const MAX_HISTORY_ENTRIES: usize = 200;
+
+/// Get the path to the history file
+fn get_history_path() -> PathBuf {
+    let config_dir = paths::config_dir();
+
+    // Ensure directory exists
+    let _ = fs::create_dir_all(&config_dir);
+
+    config_dir.join("history.json")
+}
+
+/// Load history, newest first
+pub fn load_history() -> AppResult<Vec<HistoryEntry>> {
+    load_from(&get_history_path())
+}
+
+/// Record an entry at the front of the history
+pub fn record(entry: HistoryEntry) -> AppResult<()> {
+    record_at(&get_history_path(), entry)
+}
+
+/// Remove all history entries
+pub fn clear_history() -> AppResult<()> {
+    save_to(&get_history_path(), &[])
+}
+
+fn load_from(path: &Path) -> AppResult<Vec<HistoryEntry>> {
+    if !path.exists() {
+        return Ok(vec![]);
+    }
+
+    let content = fs::read_to_string(path)
+        .map_err(|e| AppError::Config(format!("Failed to read history file: {}", e)))?;
+
+    if content.trim().is_empty() {
+        return Ok(vec![]);
+    }
+
+    serde_json::from_str(&content)
+        .map_err(|e| AppError::Config(format!("Failed to parse history: {}", e)))
+}
+
+/// A file that can't be read is moved aside to `history.json.bak` before a fresh one is
+/// started, so recording never writes over history it couldn't parse.
+fn record_at(path: &Path, entry: HistoryEntry) -> AppResult<()> {
+    let mut entries = match load_from(path) {
+        Ok(entries) => entries,
+        Err(e) => {
+            let backup = path.with_extension("json.bak");
+            fs::rename(path, &backup)
+                .map_err(|re| AppError::Config(format!("{}; could not move it aside: {}", e, re)))?;
+            tracing::warn!(error = %e, backup = %backup.display(), "Unreadable history file moved aside");
+            Vec::new()
+        }
+    };
+    push_capped(&mut entries, entry);
+    save_to(path, &entries)
+}
+
+fn push_capped(entries: &mut Vec<HistoryEntry>, entry: HistoryEntry) {
+    entries.insert(0, entry);
+    entries.truncate(MAX_HISTORY_ENTRIES);
+}
+
+/// Write through a temporary file and rename it over the old one, so a crash mid-write
+/// leaves either the old history or the new one.
+fn save_to(path: &Path, entries: &[HistoryEntry]) -> AppResult<()> {
+    let content = serde_json::to_string_pretty(entries)
+        .map_err(|e| AppError::Config(format!("Failed to serialize history: {}", e)))?;
+
+    let tmp = path.with_extension("json.tmp");
+    fs::write(&tmp, content)
+        .and_then(|_| fs::rename(&tmp, path))
+        .map_err(|e| AppError::Config(format!("Failed to write history file: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_push_capped_keeps_newest() {
+        let mut entries = Vec::new();
+        for i in 0..MAX_HISTORY_ENTRIES + 5 {
+            push_capped(&mut entries, HistoryEntry::produced("t", None, &i.to_string(), &[]));
+        }
+        assert_eq!(entries.len(), MAX_HISTORY_ENTRIES);
+        assert_eq!(entries[0].value, (MAX_HISTORY_ENTRIES + 4).to_string());
+        assert_eq!(entries.last().unwrap().value, "5");
+    }
+
+    #[test]
+    fn test_unreadable_history_is_kept_aside() {
+        let dir = std::env::temp_dir().join(format!("kafka-tui-history-{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("history.json");
+        fs::write(&path, "[{ truncated").unwrap();
+
+        assert!(load_from(&path).is_err());
+        record_at(&path, HistoryEntry::produced("t", None, "v", &[])).unwrap();
+
+        assert_eq!(fs::read_to_string(dir.join("history.json.bak")).unwrap(), "[{ truncated");
+        let entries = load_from(&path).unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].value, "v");
+        assert!(!dir.join("history.json.tmp").exists());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
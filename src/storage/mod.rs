@@ -1 +1,8 @@
+pub mod bookmarks;
 pub mod connections;
+pub mod export;
+pub mod history;
+pub mod paths;
+pub mod recent_commands;
+pub mod secrets;
+pub mod sessions;
@@ -0,0 +1,34 @@
+//! Where kafka-tui keeps its files.
+//!
+//! Settings and saved state (profiles, bookmarks, sessions, history) go to the platform config
+//! directory, logs and exports to the data directory, each under `kafka-tui/`. A data directory
+//! given with `--data-dir` or `KAFKA_TUI_DATA_DIR` holds all of them instead.
+
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+static DATA_DIR: OnceLock<PathBuf> = OnceLock::new();
+
+/// Keep every file under `dir`. Call once at startup, before anything is read or written.
+pub fn set_data_dir(dir: PathBuf) {
+    if DATA_DIR.set(dir).is_err() {
+        tracing::warn!("Data directory already set; ignoring the new one");
+    }
+}
+
+/// Directory for `config.toml`, connection profiles, bookmarks, sessions and history.
+pub fn config_dir() -> PathBuf {
+    resolve(dirs::config_dir)
+}
+
+/// Directory for logs and exports.
+pub fn data_dir() -> PathBuf {
+    resolve(dirs::data_dir)
+}
+
+fn resolve(platform_dir: fn() -> Option<PathBuf>) -> PathBuf {
+    DATA_DIR
+        .get()
+        .cloned()
+        .unwrap_or_else(|| platform_dir().unwrap_or_else(|| PathBuf::from(".")).join("kafka-tui"))
+}
@@ -0,0 +1,46 @@
+use std::fs;
+use std::path::PathBuf;
+
+use crate::error::{AppError, AppResult};
+use crate::storage::paths;
+
+/// Labels beyond this are dropped when a command is run
+pub const MAX_RECENT_COMMANDS: usize = 10;
+
+/// Get the path to the recent palette commands file
+fn get_recent_commands_path() -> PathBuf {
+    let config_dir = paths::config_dir();
+
+    // Ensure directory exists
+    let _ = fs::create_dir_all(&config_dir);
+
+    config_dir.join("recent_commands.json")
+}
+
+/// Labels of the command palette entries run most recently, newest first
+pub fn load_recent_commands() -> AppResult<Vec<String>> {
+    let path = get_recent_commands_path();
+
+    if !path.exists() {
+        return Ok(vec![]);
+    }
+
+    let content = fs::read_to_string(&path)
+        .map_err(|e| AppError::Config(format!("Failed to read recent commands file: {}", e)))?;
+
+    if content.trim().is_empty() {
+        return Ok(vec![]);
+    }
+
+    serde_json::from_str(&content)
+        .map_err(|e| AppError::Config(format!("Failed to parse recent commands: {}", e)))
+}
+
+/// Replace the recent palette commands
+pub fn save_recent_commands(labels: &[String]) -> AppResult<()> {
+    let content = serde_json::to_string_pretty(labels)
+        .map_err(|e| AppError::Config(format!("Failed to serialize recent commands: {}", e)))?;
+
+    fs::write(get_recent_commands_path(), content)
+        .map_err(|e| AppError::Config(format!("Failed to write recent commands file: {}", e)))
+}
@@ -0,0 +1,92 @@
+//! Connection secrets kept in the OS keyring.
+//!
+//! Goes through the `keyring` crate: the Keychain on macOS, the Credential Manager on
+//! Windows and the Secret Service (GNOME Keyring, KWallet) elsewhere. Every call may block
+//! on the platform store, so the connect path runs them off the async runtime.
+
+use uuid::Uuid;
+
+use crate::app::state::{ConnectionProfile, SecretStorage};
+use crate::error::{AppError, AppResult};
+
+/// Placeholder written to disk in place of a secret that lives in the keyring
+pub const KEYRING_SENTINEL: &str = "<keyring>";
+
+const SERVICE: &str = "kafka-tui";
+
+/// Move the profile's secrets into the keyring, leaving the sentinel in their place.
+pub fn stash_profile_secret(profile: &mut ConnectionProfile) -> AppResult<()> {
+    if profile.secret_storage != SecretStorage::Keyring {
+        return Ok(());
+    }
+    for (account, secret) in secret_slots(profile) {
+        if secret.is_empty() || secret == KEYRING_SENTINEL {
+            continue;
+        }
+        store_secret(&account, secret)?;
+        *secret = KEYRING_SENTINEL.into();
+    }
+    Ok(())
+}
+
+/// Replace keyring sentinels with the real secrets.
+pub fn resolve_profile_secret(profile: &mut ConnectionProfile) -> AppResult<()> {
+    if profile.secret_storage != SecretStorage::Keyring {
+        return Ok(());
+    }
+    for (account, secret) in secret_slots(profile) {
+        if secret == KEYRING_SENTINEL {
+            *secret = lookup_secret(&account)?;
+        }
+    }
+    Ok(())
+}
+
+/// Remove the connection's secrets; ones that were never stored are not an error.
+pub fn delete_secret(id: Uuid) -> AppResult<()> {
+    for account in [id.to_string(), registry_account(id)] {
+        match entry(&account)?.delete_credential() {
+            Ok(()) | Err(keyring::Error::NoEntry) => {}
+            Err(e) => return Err(keyring_error(e)),
+        }
+    }
+    Ok(())
+}
+
+/// The profile's secrets with the keyring account each is kept under: the password under
+/// the profile id, the registry credentials under [`registry_account`].
+fn secret_slots(profile: &mut ConnectionProfile) -> Vec<(String, &mut String)> {
+    let id = profile.id;
+    let mut slots = Vec::new();
+    if let Some(secret) = profile.auth.secret_mut() {
+        slots.push((id.to_string(), secret));
+    }
+    if let Some(credentials) = profile.schema_registry_credentials.as_mut() {
+        slots.push((registry_account(id), credentials));
+    }
+    slots
+}
+
+fn registry_account(id: Uuid) -> String {
+    format!("{}/schema-registry", id)
+}
+
+fn store_secret(account: &str, secret: &str) -> AppResult<()> {
+    entry(account)?.set_password(secret).map_err(keyring_error)
+}
+
+fn lookup_secret(account: &str) -> AppResult<String> {
+    match entry(account)?.get_password() {
+        Ok(secret) => Ok(secret),
+        Err(keyring::Error::NoEntry) => Err(AppError::Config(format!("No keyring entry for {}", account))),
+        Err(e) => Err(keyring_error(e)),
+    }
+}
+
+fn entry(account: &str) -> AppResult<keyring::Entry> {
+    keyring::Entry::new(SERVICE, account).map_err(keyring_error)
+}
+
+fn keyring_error(e: keyring::Error) -> AppError {
+    AppError::Config(format!("Keyring unavailable: {}", e))
+}
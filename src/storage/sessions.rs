@@ -0,0 +1,89 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use uuid::Uuid;
+
+use crate::app::state::SessionSnapshot;
+use crate::error::{AppError, AppResult};
+use crate::storage::paths;
+
+/// Get the path to the sessions file
+fn get_sessions_path() -> PathBuf {
+    let config_dir = paths::config_dir();
+
+    // Ensure directory exists
+    let _ = fs::create_dir_all(&config_dir);
+
+    config_dir.join("sessions.json")
+}
+
+/// Load the last session of a connection profile, if one was saved
+pub fn load_session(profile_id: Uuid) -> AppResult<Option<SessionSnapshot>> {
+    Ok(load_from(&get_sessions_path())?.remove(&profile_id))
+}
+
+/// Replace the saved session of a connection profile
+pub fn save_session(profile_id: Uuid, session: &SessionSnapshot) -> AppResult<()> {
+    save_at(&get_sessions_path(), profile_id, session)
+}
+
+/// Last session of every profile, keyed by profile id
+fn load_from(path: &Path) -> AppResult<HashMap<Uuid, SessionSnapshot>> {
+    if !path.exists() {
+        return Ok(HashMap::new());
+    }
+
+    let content = fs::read_to_string(path)
+        .map_err(|e| AppError::Config(format!("Failed to read sessions file: {}", e)))?;
+
+    if content.trim().is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    serde_json::from_str(&content)
+        .map_err(|e| AppError::Config(format!("Failed to parse sessions: {}", e)))
+}
+
+/// A file that can't be read is moved aside to `sessions.json.bak` first, so saving one
+/// profile's session never writes over the others' it couldn't parse.
+fn save_at(path: &Path, profile_id: Uuid, session: &SessionSnapshot) -> AppResult<()> {
+    let mut all = match load_from(path) {
+        Ok(all) => all,
+        Err(e) => {
+            let backup = path.with_extension("json.bak");
+            fs::rename(path, &backup)
+                .map_err(|re| AppError::Config(format!("{}; could not move it aside: {}", e, re)))?;
+            tracing::warn!(error = %e, backup = %backup.display(), "Unreadable sessions file moved aside");
+            HashMap::new()
+        }
+    };
+    all.insert(profile_id, session.clone());
+
+    let content = serde_json::to_string_pretty(&all)
+        .map_err(|e| AppError::Config(format!("Failed to serialize sessions: {}", e)))?;
+
+    fs::write(path, content)
+        .map_err(|e| AppError::Config(format!("Failed to write sessions file: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unreadable_sessions_are_kept_aside() {
+        let dir = std::env::temp_dir().join(format!("kafka-tui-sessions-{}", Uuid::new_v4()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("sessions.json");
+        fs::write(&path, "{ truncated").unwrap();
+
+        let profile = Uuid::new_v4();
+        let session = SessionSnapshot { topic: Some("orders".into()), ..Default::default() };
+        save_at(&path, profile, &session).unwrap();
+
+        assert_eq!(fs::read_to_string(dir.join("sessions.json.bak")).unwrap(), "{ truncated");
+        assert_eq!(load_from(&path).unwrap(), HashMap::from([(profile, session)]));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
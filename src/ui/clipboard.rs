@@ -0,0 +1,26 @@
+//! Thin wrapper around the system clipboard.
+
+use crate::error::{AppError, AppResult};
+
+/// Lazily-opened system clipboard.
+///
+/// The handle is kept alive for the app's lifetime: on X11/Wayland the copied
+/// text is only served while its owner exists.
+#[derive(Default)]
+pub struct Clipboard {
+    inner: Option<arboard::Clipboard>,
+}
+
+impl Clipboard {
+    pub fn copy(&mut self, text: &str) -> AppResult<()> {
+        let clipboard = match &mut self.inner {
+            Some(c) => c,
+            None => self.inner.insert(arboard::Clipboard::new().map_err(clipboard_error)?),
+        };
+        clipboard.set_text(text).map_err(clipboard_error)
+    }
+}
+
+fn clipboard_error(e: arboard::Error) -> AppError {
+    AppError::Terminal(format!("Clipboard unavailable: {}", e))
+}
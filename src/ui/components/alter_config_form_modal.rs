@@ -3,9 +3,10 @@ use ratatui::{
     widgets::{Block, Borders, Clear, Paragraph, Row, Table},
 };
 
-use crate::app::state::AlterConfigFormState;
+use crate::app::state::{AlterConfigFormState, ConfigChange};
 use crate::ui::layout::centered_rect_fixed;
 use crate::ui::theme::THEME;
+use crate::ui::widgets::format_duration_ms;
 
 pub struct AlterConfigFormModal;
 
@@ -15,8 +16,12 @@ impl AlterConfigFormModal {
 
         frame.render_widget(Clear, area);
 
+        let title = match form_state.review {
+            Some(_) => format!(" Review Changes: {} ", form_state.topic),
+            None => format!(" Edit Config: {} ", form_state.topic),
+        };
         let block = Block::default()
-            .title(format!(" Edit Config: {} ", form_state.topic))
+            .title(title)
             .title_style(THEME.header_style())
             .borders(Borders::ALL)
             .border_style(THEME.border_style(true))
@@ -35,6 +40,11 @@ impl AlterConfigFormModal {
             ])
             .split(inner);
 
+        if let Some(selected) = form_state.review {
+            Self::render_review(frame, &chunks, &form_state.changes(), selected);
+            return;
+        }
+
         // Config table
         let rows: Vec<Row> = form_state
             .configs
@@ -42,20 +52,24 @@ impl AlterConfigFormModal {
             .enumerate()
             .map(|(i, (key, value, modified))| {
                 let selected = i == form_state.selected_index;
+                let deleted = form_state.deleted.contains(key);
                 let style = if selected {
                     THEME.selected_style()
+                } else if deleted {
+                    THEME.error_style()
                 } else if *modified {
                     Style::default().fg(THEME.accent)
                 } else {
                     THEME.normal_style()
                 };
 
-                let marker = if *modified { "*" } else { " " };
-                Row::new(vec![
-                    format!("{}{}", marker, key),
-                    value.clone(),
-                ])
-                .style(style)
+                let (marker, value) = if deleted {
+                    let old = Span::styled(value.clone(), Style::default().add_modifier(Modifier::CROSSED_OUT));
+                    ("-", Line::from(vec![old, Span::raw(" → default")]))
+                } else {
+                    (if *modified { "*" } else { " " }, Line::raw(value.clone()))
+                };
+                Row::new(vec![Line::raw(format!("{}{}", marker, key)), value]).style(style)
             })
             .collect();
 
@@ -81,11 +95,56 @@ impl AlterConfigFormModal {
         let hint_text = if form_state.editing {
             "Enter: save | Esc: cancel edit"
         } else {
-            "j/k: navigate | e: edit | Enter: apply | Esc: cancel"
+            "j/k: navigate | e: edit | d: reset to default | Enter: apply | Esc: cancel"
         };
         let hint = Paragraph::new(hint_text)
             .style(THEME.muted_style())
             .alignment(Alignment::Center);
         frame.render_widget(hint, chunks[2]);
     }
+
+    /// The changes an apply makes, old value against new, before anything is sent.
+    fn render_review(frame: &mut Frame, chunks: &[Rect], changes: &[ConfigChange], selected: usize) {
+        let rows: Vec<Row> = changes
+            .iter()
+            .enumerate()
+            .map(|(i, change)| {
+                let new = match &change.new {
+                    Some(value) => Span::styled(readable_value(&change.key, value), Style::default().fg(THEME.accent)),
+                    None => Span::styled("(broker default)", THEME.warning_style()),
+                };
+                let row = Row::new(vec![
+                    Line::raw(change.key.clone()),
+                    Line::styled(readable_value(&change.key, &change.old), THEME.muted_style()),
+                    Line::from(new),
+                ]);
+                if i == selected { row.style(THEME.selected_style()) } else { row }
+            })
+            .collect();
+
+        let widths = [Constraint::Percentage(34), Constraint::Percentage(33), Constraint::Percentage(33)];
+        let table = Table::new(rows, widths).header(
+            Row::new(vec!["Config Key", "Current", "New"])
+                .style(THEME.header_style())
+                .bottom_margin(1),
+        );
+        frame.render_widget(table, chunks[0]);
+
+        let summary = Paragraph::new(format!("{} change(s) will be applied", changes.len())).style(THEME.warning_style());
+        frame.render_widget(summary, chunks[1]);
+
+        let hint = Paragraph::new("y/Y: copy change/all | Enter: apply | Esc: back to editing")
+            .style(THEME.muted_style())
+            .alignment(Alignment::Center);
+        frame.render_widget(hint, chunks[2]);
+    }
+}
+
+/// Millisecond settings with their duration alongside, so a dropped zero stands out
+fn readable_value(key: &str, value: &str) -> String {
+    let duration = value.parse::<i64>().ok().filter(|ms| key.ends_with(".ms") && *ms > 0).map(format_duration_ms);
+    match duration {
+        Some(d) if !d.ends_with("ms") => format!("{} ({})", value, d),
+        _ => value.to_string(),
+    }
 }
@@ -0,0 +1,82 @@
+use ratatui::{
+    prelude::*,
+    widgets::{Cell, Clear, Paragraph, Row, Table, TableState},
+};
+
+use crate::app::state::CommandPaletteState;
+use crate::events::keymap::key_label;
+use crate::ui::layout::centered_rect_fixed;
+use crate::ui::theme::THEME;
+use crate::ui::widgets::{format_input, modal_block, scrolled_window};
+
+/// Command rows shown at once
+const PALETTE_ROWS: u16 = 12;
+
+pub struct CommandPalette;
+
+impl CommandPalette {
+    pub fn render(frame: &mut Frame, palette: &CommandPaletteState) {
+        // Border, margin, query, spacer, rows and hint
+        let area = centered_rect_fixed(60, PALETTE_ROWS + 7, frame.area());
+
+        frame.render_widget(Clear, area);
+
+        let block = modal_block("Commands");
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(1)
+            .constraints([
+                Constraint::Length(1), // Query
+                Constraint::Length(1), // Spacer
+                Constraint::Min(1),    // Commands
+                Constraint::Length(1), // Hint
+            ])
+            .split(inner);
+
+        let query = Line::from(vec![
+            Span::styled(": ", THEME.key_hint_style()),
+            Span::styled(format_input(&palette.query, true, ""), THEME.input_style(true)),
+        ]);
+        frame.render_widget(Paragraph::new(query), chunks[0]);
+
+        let matches = palette.matches();
+        if matches.is_empty() {
+            let empty = Paragraph::new("No matching commands").style(THEME.muted_style()).alignment(Alignment::Center);
+            frame.render_widget(empty, chunks[2]);
+        } else {
+            let window = scrolled_window(matches.len(), palette.selected, chunks[2].height as usize, &palette.scroll);
+            let rows: Vec<Row> = matches[window.clone()]
+                .iter()
+                .map(|(command, positions)| {
+                    let label: Vec<Span> = command
+                        .label
+                        .chars()
+                        .enumerate()
+                        .map(|(i, c)| {
+                            let style = if positions.contains(&i) { THEME.search_match_style() } else { THEME.normal_style() };
+                            Span::styled(c.to_string(), style)
+                        })
+                        .collect();
+                    Row::new(vec![
+                        Cell::from(Line::from(label)),
+                        Cell::from(Line::from(key_label(command.key)).alignment(Alignment::Right)).style(THEME.key_hint_style()),
+                    ])
+                })
+                .collect();
+
+            let table = Table::new(rows, [Constraint::Min(20), Constraint::Length(8)])
+                .row_highlight_style(THEME.selected_style());
+            let mut table_state = TableState::default();
+            table_state.select(Some(palette.selected.saturating_sub(window.start)));
+            frame.render_stateful_widget(table, chunks[2], &mut table_state);
+        }
+
+        let hint = Paragraph::new("↑/↓: select | Enter: run | Esc: close")
+            .style(THEME.muted_style())
+            .alignment(Alignment::Center);
+        frame.render_widget(hint, chunks[3]);
+    }
+}
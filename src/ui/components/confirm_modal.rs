@@ -14,7 +14,10 @@ impl ConfirmModal {
         title: &str,
         message: &str,
     ) {
-        let area = centered_rect_fixed(50, 9, frame.area());
+        // Grow for long messages such as a bulk delete's topic list, up to the screen height
+        let wrapped: usize = message.lines().map(|l| l.chars().count().div_ceil(46).max(1)).sum();
+        let height = (wrapped as u16 + 7).clamp(9, frame.area().height.max(9));
+        let area = centered_rect_fixed(50, height, frame.area());
 
         // Clear the background
         frame.render_widget(Clear, area);
@@ -3,17 +3,25 @@ use ratatui::{
     widgets::{Clear, Paragraph},
 };
 
-use crate::app::state::{ConnectionFormField, ConnectionFormState};
+use crate::app::state::{AuthType, ConnectionFormField, ConnectionFormState};
+use crate::kafka::config::{DEFAULT_REQUEST_TIMEOUT_MS, DEFAULT_SOCKET_TIMEOUT_MS};
 use crate::ui::layout::centered_rect_fixed;
 use crate::ui::theme::THEME;
-use crate::ui::widgets::{format_input, label_style, modal_block};
+use crate::ui::widgets::{input_text, label_style, modal_block};
 
 pub struct ConnectionFormModal;
 
 impl ConnectionFormModal {
     pub fn render(frame: &mut Frame, form_state: &ConnectionFormState) {
-        let height = if form_state.auth_type.requires_credentials() { 24 } else { 18 };
-        let area = centered_rect_fixed(60, height, frame.area());
+        // Fields between the auth selector and the producer section depend on the chosen auth type
+        let extra_fields: Vec<ConnectionFormField> = ConnectionFormField::sequence(&form_state.auth_type)
+            .into_iter()
+            .skip_while(|f| *f != ConnectionFormField::AuthType)
+            .skip(1)
+            .take_while(|f| *f != ConnectionFormField::Acks)
+            .collect();
+        let extra_rows = if extra_fields.is_empty() { 0 } else { 1 + 2 * extra_fields.len() as u16 };
+        let area = centered_rect_fixed(60, 31 + extra_rows, frame.area());
 
         frame.render_widget(Clear, area);
 
@@ -30,22 +38,27 @@ impl ConnectionFormModal {
             Constraint::Length(1), // Spacer
             Constraint::Length(1), // Consumer Group label
             Constraint::Length(1), // Consumer Group input
+            Constraint::Length(1), // Start offset selector
+            Constraint::Length(1), // Spacer
+            Constraint::Length(1), // Schema Registry label
+            Constraint::Length(1), // Schema Registry input
             Constraint::Length(1), // Spacer
             Constraint::Length(1), // Auth type label
             Constraint::Length(1), // Auth type selector
         ];
 
-        if form_state.auth_type.requires_credentials() {
-            constraints.extend([
-                Constraint::Length(1), // Spacer
-                Constraint::Length(1), // Username label
-                Constraint::Length(1), // Username input
-                Constraint::Length(1), // Spacer
-                Constraint::Length(1), // Password label
-                Constraint::Length(1), // Password input
-            ]);
-        }
+        // Spacer, then a label + input per auth-specific field
+        constraints.extend((0..extra_rows).map(|_| Constraint::Length(1)));
 
+        constraints.push(Constraint::Length(1)); // Spacer
+        constraints.push(Constraint::Length(1)); // Producer heading
+        constraints.push(Constraint::Length(1)); // Acks selector
+        constraints.push(Constraint::Length(1)); // Idempotence selector
+        constraints.push(Constraint::Length(1)); // Compression selector
+        constraints.push(Constraint::Length(1)); // Spacer
+        constraints.push(Constraint::Length(1)); // Timeouts heading
+        constraints.push(Constraint::Length(1)); // Socket timeout input
+        constraints.push(Constraint::Length(1)); // Request timeout input
         constraints.push(Constraint::Length(1)); // Spacer
         constraints.push(Constraint::Length(1)); // Hint
 
@@ -56,52 +69,115 @@ impl ConnectionFormModal {
             .split(inner);
 
         let mut idx = 0;
+        let cursor = |focused: bool, value: &str| focused.then(|| form_state.cursor.position(value));
 
         // Name field
         let name_focused = form_state.focused_field == ConnectionFormField::Name;
-        Self::render_field(frame, &chunks, &mut idx, "Connection Name:", &form_state.name, "(empty)", name_focused);
+        Self::render_field(frame, &chunks, &mut idx, "Connection Name:", &form_state.name, "(empty)", cursor(name_focused, &form_state.name));
         idx += 1; // skip spacer
 
         // Brokers field
         let brokers_focused = form_state.focused_field == ConnectionFormField::Brokers;
-        Self::render_field(frame, &chunks, &mut idx, "Bootstrap Servers:", &form_state.brokers, "localhost:9092", brokers_focused);
+        Self::render_field(frame, &chunks, &mut idx, "Bootstrap Servers:", &form_state.brokers, "localhost:9092", cursor(brokers_focused, &form_state.brokers));
         idx += 1; // skip spacer
 
         // Consumer Group field
         let cg_focused = form_state.focused_field == ConnectionFormField::ConsumerGroup;
-        Self::render_field(frame, &chunks, &mut idx, "Consumer Group (optional):", &form_state.consumer_group, "kafka-tui", cg_focused);
+        Self::render_field(frame, &chunks, &mut idx, "Consumer Group (optional):", &form_state.consumer_group, "kafka-tui", cursor(cg_focused, &form_state.consumer_group));
+        let start_focused = form_state.focused_field == ConnectionFormField::StartOffset;
+        Self::render_inline_selector(frame, &chunks, &mut idx, "Messages from:", form_state.start_offset.display_name(), start_focused);
+        idx += 1; // skip spacer
+
+        // Schema Registry field
+        let sr_focused = form_state.focused_field == ConnectionFormField::SchemaRegistry;
+        Self::render_field(frame, &chunks, &mut idx, "Schema Registry URL (optional):", &form_state.schema_registry, "http://localhost:8081", cursor(sr_focused, &form_state.schema_registry));
         idx += 1; // skip spacer
 
         // Auth type selector
         let auth_focused = form_state.focused_field == ConnectionFormField::AuthType;
-        let auth_label = Paragraph::new("Authentication:").style(label_style(auth_focused));
-        frame.render_widget(auth_label, chunks[idx]);
-        idx += 1;
+        Self::render_selector(frame, &chunks, &mut idx, "Authentication:", form_state.auth_type.display_name(), auth_focused);
 
-        let auth_display = format!("◀ {} ▶", form_state.auth_type.display_name());
-        let auth_input = Paragraph::new(auth_display).style(THEME.input_style(auth_focused));
-        frame.render_widget(auth_input, chunks[idx]);
-        idx += 1;
-
-        // Credentials fields (if needed)
-        if form_state.auth_type.requires_credentials() {
+        // Auth-specific fields
+        let oauth = form_state.auth_type == AuthType::OAuthBearer;
+        if !extra_fields.is_empty() {
             idx += 1; // skip spacer
+        }
+        for field in &extra_fields {
+            let focused = form_state.focused_field == *field;
+            match field {
+                ConnectionFormField::SaslMechanism => Self::render_selector(
+                    frame, &chunks, &mut idx, "SASL Mechanism:", form_state.sasl_mechanism.display_name(), focused,
+                ),
+                ConnectionFormField::TokenEndpoint => {
+                    Self::render_field(frame, &chunks, &mut idx, "Token Endpoint (empty for a static token):", &form_state.token_endpoint, "https://idp.example.com/oauth2/token", cursor(focused, &form_state.token_endpoint));
+                }
+                ConnectionFormField::Username => {
+                    let label = if oauth { "Client ID:" } else { "Username:" };
+                    Self::render_field(frame, &chunks, &mut idx, label, &form_state.username, "(empty)", cursor(focused, &form_state.username));
+                }
+                ConnectionFormField::Password => {
+                    let label = if oauth { "Client Secret or Token:" } else { "Password:" };
+                    let masked = "*".repeat(form_state.password.chars().count());
+                    Self::render_field(frame, &chunks, &mut idx, label, &masked, "(empty)", cursor(focused, &masked));
+                }
+                ConnectionFormField::Scope => {
+                    Self::render_field(frame, &chunks, &mut idx, "Scope (optional):", &form_state.scope, "(none)", cursor(focused, &form_state.scope));
+                }
+                ConnectionFormField::CaLocation => {
+                    Self::render_field(frame, &chunks, &mut idx, "CA Certificate (optional):", &form_state.ca_location, "/path/to/ca.pem", cursor(focused, &form_state.ca_location));
+                }
+                ConnectionFormField::CertLocation => {
+                    Self::render_field(frame, &chunks, &mut idx, "Client Certificate (optional):", &form_state.cert_location, "/path/to/client.pem", cursor(focused, &form_state.cert_location));
+                }
+                ConnectionFormField::KeyLocation => {
+                    Self::render_field(frame, &chunks, &mut idx, "Client Key (optional):", &form_state.key_location, "/path/to/client.key", cursor(focused, &form_state.key_location));
+                }
+                ConnectionFormField::SecretStorage => Self::render_selector(
+                    frame, &chunks, &mut idx, "Store Password In:", form_state.secret_storage.display_name(), focused,
+                ),
+                ConnectionFormField::KeyPassword => {
+                    let masked = "*".repeat(form_state.key_password.chars().count());
+                    Self::render_field(frame, &chunks, &mut idx, "Key Password (optional):", &masked, "(none)", cursor(focused, &masked));
+                }
+                _ => {}
+            }
+        }
 
-            let user_focused = form_state.focused_field == ConnectionFormField::Username;
-            Self::render_field(frame, &chunks, &mut idx, "Username:", &form_state.username, "(empty)", user_focused);
-            idx += 1; // skip spacer
+        idx += 1; // skip spacer
 
-            let pass_focused = form_state.focused_field == ConnectionFormField::Password;
-            let pass_masked = "*".repeat(form_state.password.len());
-            Self::render_field(frame, &chunks, &mut idx, "Password:", &pass_masked, "(empty)", pass_focused);
-        }
+        // Producer settings, one compact row each
+        let heading = Paragraph::new("Producer (advanced)").style(THEME.muted_style());
+        frame.render_widget(heading, chunks[idx]);
+        idx += 1;
+        let producer = &form_state.producer;
+        let idempotence = if producer.idempotence { "on" } else { "off" };
+        Self::render_inline_selector(frame, &chunks, &mut idx, "Acks:", producer.acks.display_name(), form_state.focused_field == ConnectionFormField::Acks);
+        Self::render_inline_selector(frame, &chunks, &mut idx, "Idempotence:", idempotence, form_state.focused_field == ConnectionFormField::Idempotence);
+        Self::render_inline_selector(frame, &chunks, &mut idx, "Compression:", producer.compression.config_value(), form_state.focused_field == ConnectionFormField::Compression);
+        idx += 1; // skip spacer
+
+        // Client timeouts, empty for the defaults
+        let heading = Paragraph::new("Timeouts in ms (optional)").style(THEME.muted_style());
+        frame.render_widget(heading, chunks[idx]);
+        idx += 1;
+        let socket_focused = form_state.focused_field == ConnectionFormField::SocketTimeout;
+        let default_socket = DEFAULT_SOCKET_TIMEOUT_MS.to_string();
+        Self::render_inline_field(frame, &chunks, &mut idx, "Socket:", &form_state.socket_timeout, &default_socket, cursor(socket_focused, &form_state.socket_timeout));
+        let request_focused = form_state.focused_field == ConnectionFormField::RequestTimeout;
+        let default_request = DEFAULT_REQUEST_TIMEOUT_MS.to_string();
+        Self::render_inline_field(frame, &chunks, &mut idx, "Request:", &form_state.request_timeout, &default_request, cursor(request_focused, &form_state.request_timeout));
 
         idx += 1; // skip spacer
 
-        let hint_text = if form_state.focused_field == ConnectionFormField::AuthType {
-            "←/→: change auth | Tab: next | Enter: connect | Esc: cancel"
-        } else {
-            "Tab: next field | Enter: connect | Esc: cancel"
+        let hint_text = match form_state.focused_field {
+            ConnectionFormField::AuthType => "←/→: change auth | Tab: next | Enter: connect | Esc: cancel",
+            ConnectionFormField::SaslMechanism => "←/→: change mechanism | Tab: next | Enter: connect | Esc: cancel",
+            ConnectionFormField::SecretStorage => "←/→: change storage | Tab: next | Enter: connect | Esc: cancel",
+            ConnectionFormField::StartOffset => "←/→: latest/earliest | Tab: next | Enter: connect | Esc: cancel",
+            ConnectionFormField::Acks => "←/→: change acks | Tab: next | Enter: connect | Esc: cancel",
+            ConnectionFormField::Idempotence => "←/→: toggle (needs acks=all) | Tab: next | Enter: connect",
+            ConnectionFormField::Compression => "←/→: change codec | Tab: next | Enter: connect | Esc: cancel",
+            _ => "Tab: next field | Enter: connect | Esc: cancel",
         };
         let hint = Paragraph::new(hint_text)
             .style(THEME.muted_style())
@@ -109,22 +185,72 @@ impl ConnectionFormModal {
         frame.render_widget(hint, chunks[idx]);
     }
 
-    fn render_field(
+    fn render_selector(
         frame: &mut Frame,
         chunks: &std::rc::Rc<[Rect]>,
         idx: &mut usize,
         label_text: &str,
         value: &str,
-        placeholder: &str,
         focused: bool,
     ) {
         let label = Paragraph::new(label_text).style(label_style(focused));
         frame.render_widget(label, chunks[*idx]);
         *idx += 1;
 
-        let display = format_input(value, focused, placeholder);
+        let display = format!("◀ {} ▶", value);
         let input = Paragraph::new(display).style(THEME.input_style(focused));
         frame.render_widget(input, chunks[*idx]);
         *idx += 1;
     }
+
+    fn render_inline_selector(
+        frame: &mut Frame,
+        chunks: &std::rc::Rc<[Rect]>,
+        idx: &mut usize,
+        label_text: &str,
+        value: &str,
+        focused: bool,
+    ) {
+        let line = Line::from(vec![
+            Span::styled(format!("{:<14}", label_text), label_style(focused)),
+            Span::styled(format!("◀ {} ▶", value), THEME.input_style(focused)),
+        ]);
+        frame.render_widget(Paragraph::new(line), chunks[*idx]);
+        *idx += 1;
+    }
+
+    fn render_inline_field(
+        frame: &mut Frame,
+        chunks: &std::rc::Rc<[Rect]>,
+        idx: &mut usize,
+        label_text: &str,
+        value: &str,
+        placeholder: &str,
+        cursor: Option<usize>,
+    ) {
+        let mut line = Line::from(Span::styled(format!("{:<14}", label_text), label_style(cursor.is_some())));
+        for input in input_text(value, placeholder, cursor).lines {
+            line.spans.extend(input.spans.into_iter().map(|s| s.patch_style(THEME.input_style(cursor.is_some()))));
+        }
+        frame.render_widget(Paragraph::new(line), chunks[*idx]);
+        *idx += 1;
+    }
+
+    fn render_field(
+        frame: &mut Frame,
+        chunks: &std::rc::Rc<[Rect]>,
+        idx: &mut usize,
+        label_text: &str,
+        value: &str,
+        placeholder: &str,
+        cursor: Option<usize>,
+    ) {
+        let label = Paragraph::new(label_text).style(label_style(cursor.is_some()));
+        frame.render_widget(label, chunks[*idx]);
+        *idx += 1;
+
+        let input = Paragraph::new(input_text(value, placeholder, cursor)).style(THEME.input_style(cursor.is_some()));
+        frame.render_widget(input, chunks[*idx]);
+        *idx += 1;
+    }
 }
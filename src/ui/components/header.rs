@@ -46,6 +46,10 @@ impl Header {
         let (status_text, status_style) = match &state.connection.status {
             ConnectionStatus::Connected => ("Connected".to_string(), THEME.status_connected()),
             ConnectionStatus::Connecting => ("Connecting...".to_string(), THEME.status_connecting()),
+            ConnectionStatus::Retrying { attempt, max } => {
+                (format!("Connecting ({}/{})...", attempt, max), THEME.status_connecting())
+            }
+            ConnectionStatus::Reconnecting => ("Reconnecting...".to_string(), THEME.status_connecting()),
             ConnectionStatus::Disconnected => ("Disconnected".to_string(), THEME.status_disconnected()),
             ConnectionStatus::Error(e) => {
                 let msg = if e.len() > 15 {
@@ -0,0 +1,90 @@
+use ratatui::{
+    prelude::*,
+    widgets::{Cell, Clear, Paragraph, Row, Table, TableState},
+};
+
+use crate::app::state::{HistoryBrowserState, HistoryKind};
+use crate::ui::layout::centered_rect;
+use crate::ui::theme::THEME;
+use crate::ui::widgets::{modal_block, scrolled_window};
+
+pub struct HistoryModal;
+
+impl HistoryModal {
+    pub fn render(frame: &mut Frame, history: &HistoryBrowserState) {
+        let area = centered_rect(80, 70, frame.area());
+
+        frame.render_widget(Clear, area);
+
+        let block = modal_block("History");
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(1)
+            .constraints([Constraint::Min(3), Constraint::Length(1)])
+            .split(inner);
+
+        if history.entries.is_empty() {
+            let empty = Paragraph::new("No history yet. Produced messages and offset resets appear here.")
+                .style(THEME.muted_style())
+                .alignment(Alignment::Center);
+            frame.render_widget(empty, chunks[0]);
+        } else {
+            Self::render_table(frame, chunks[0], history);
+        }
+
+        let hint = Paragraph::new("[Enter] Re-send  [Ctrl+L] Clear  [Esc] Close")
+            .style(THEME.muted_style())
+            .alignment(Alignment::Center);
+        frame.render_widget(hint, chunks[1]);
+    }
+
+    fn render_table(frame: &mut Frame, area: Rect, history: &HistoryBrowserState) {
+        let header = Row::new(vec![
+            Cell::from("Time").style(THEME.table_header_style()),
+            Cell::from("Type").style(THEME.table_header_style()),
+            Cell::from("Topic").style(THEME.table_header_style()),
+            Cell::from("Key").style(THEME.table_header_style()),
+            Cell::from("Value").style(THEME.table_header_style()),
+        ])
+        .height(1);
+
+        let window = scrolled_window(history.entries.len(), history.selected, area.height.saturating_sub(1) as usize, &history.scroll);
+
+        let rows: Vec<Row> = history.entries[window.clone()]
+            .iter()
+            .map(|e| {
+                let (kind, kind_style) = match e.kind {
+                    HistoryKind::Produced => ("produce", THEME.success_style()),
+                    HistoryKind::Seek => ("seek", THEME.info_style()),
+                };
+                Row::new(vec![
+                    Cell::from(e.timestamp.format("%m-%d %H:%M:%S").to_string()).style(THEME.muted_style()),
+                    Cell::from(kind).style(kind_style),
+                    Cell::from(e.topic.clone()),
+                    Cell::from(e.key.clone().unwrap_or_else(|| "-".into())).style(THEME.muted_style()),
+                    Cell::from(e.value.replace('\n', " ")),
+                ])
+            })
+            .collect();
+
+        let widths = [
+            Constraint::Length(14),
+            Constraint::Length(8),
+            Constraint::Length(20),
+            Constraint::Length(12),
+            Constraint::Min(10),
+        ];
+
+        let table = Table::new(rows, widths)
+            .header(header)
+            .row_highlight_style(THEME.selected_style());
+
+        let mut table_state = TableState::default();
+        table_state.select(Some(history.selected.saturating_sub(window.start)));
+
+        frame.render_stateful_widget(table, area, &mut table_state);
+    }
+}
@@ -15,6 +15,7 @@ impl InputModal {
         title: &str,
         placeholder: &str,
         value: &str,
+        hint: &str,
     ) {
         let area = centered_rect_fixed(50, 7, frame.area());
 
@@ -45,7 +46,7 @@ impl InputModal {
         let input = Paragraph::new(display_value).style(THEME.input_style(true));
         frame.render_widget(input, chunks[1]);
 
-        let hint = Paragraph::new("Enter to confirm, Esc to cancel")
+        let hint = Paragraph::new(hint)
             .style(THEME.muted_style())
             .alignment(Alignment::Center);
         frame.render_widget(hint, chunks[2]);
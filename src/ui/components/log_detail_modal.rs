@@ -0,0 +1,56 @@
+use ratatui::{
+    prelude::*,
+    widgets::{Clear, Paragraph, Wrap},
+};
+
+use crate::app::state::LogDetailState;
+use crate::ui::layout::centered_rect;
+use crate::ui::theme::THEME;
+use crate::ui::widgets::modal_block;
+
+pub struct LogDetailModal;
+
+impl LogDetailModal {
+    pub fn render(frame: &mut Frame, detail: &LogDetailState) {
+        let area = centered_rect(80, 60, frame.area());
+
+        frame.render_widget(Clear, area);
+
+        let block = modal_block("Log Entry");
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(1)
+            .constraints([
+                Constraint::Length(1), // Timestamp and level
+                Constraint::Length(1), // Spacer
+                Constraint::Min(3),    // Message
+                Constraint::Length(1), // Hint
+            ])
+            .split(inner);
+
+        let entry = &detail.entry;
+        let header = Line::from(vec![
+            Span::styled(entry.timestamp.format("%Y-%m-%d %H:%M:%S%.3f UTC  ").to_string(), THEME.muted_style()),
+            Span::styled(entry.level.label(), entry.level.style()),
+        ]);
+        frame.render_widget(Paragraph::new(header), chunks[0]);
+
+        let message = Paragraph::new(entry.message.as_str())
+            .style(THEME.normal_style())
+            .wrap(Wrap { trim: false });
+
+        // Keep the scroll offset within the wrapped message
+        let line_count = u16::try_from(message.line_count(chunks[2].width)).unwrap_or(u16::MAX);
+        let max_scroll = line_count.saturating_sub(chunks[2].height);
+        detail.max_scroll.set(max_scroll);
+        frame.render_widget(message.scroll((detail.scroll.min(max_scroll), 0)), chunks[2]);
+
+        let hint = Paragraph::new("[j/k] Scroll  [y] Copy  [Esc] Close")
+            .style(THEME.muted_style())
+            .alignment(Alignment::Center);
+        frame.render_widget(hint, chunks[3]);
+    }
+}
@@ -1,12 +1,17 @@
 pub mod add_partitions_form_modal;
 pub mod alter_config_form_modal;
+pub mod command_palette;
 pub mod confirm_modal;
 pub mod connection_form_modal;
 pub mod header;
 pub mod help_modal;
+pub mod history_modal;
 pub mod input_modal;
+pub mod log_detail_modal;
 pub mod produce_form_modal;
 pub mod purge_topic_form_modal;
+pub mod reset_offsets_form_modal;
+pub mod seek_form_modal;
 pub mod sidebar;
 pub mod status_bar;
 pub mod toast;
@@ -14,13 +19,18 @@ pub mod topic_create_form_modal;
 
 pub use add_partitions_form_modal::AddPartitionsFormModal;
 pub use alter_config_form_modal::AlterConfigFormModal;
+pub use command_palette::CommandPalette;
 pub use confirm_modal::ConfirmModal;
 pub use connection_form_modal::ConnectionFormModal;
 pub use header::Header;
 pub use help_modal::HelpModal;
+pub use history_modal::HistoryModal;
 pub use input_modal::InputModal;
+pub use log_detail_modal::LogDetailModal;
 pub use produce_form_modal::ProduceFormModal;
 pub use purge_topic_form_modal::PurgeTopicFormModal;
+pub use reset_offsets_form_modal::ResetOffsetsFormModal;
+pub use seek_form_modal::SeekFormModal;
 pub use sidebar::Sidebar;
 pub use status_bar::StatusBar;
 pub use toast::Toast;
@@ -6,13 +6,19 @@ use ratatui::{
 use crate::app::state::{ProduceFormField, ProduceFormState};
 use crate::ui::layout::centered_rect_fixed;
 use crate::ui::theme::THEME;
-use crate::ui::widgets::render_labeled_input;
+use crate::ui::widgets::{
+    label_style, render_labeled_multiline_input, render_labeled_text_input, render_pair_list, VISIBLE_PAIRS,
+};
+
+/// Rows given to the multi-line value editor
+const VALUE_ROWS: u16 = 6;
 
 pub struct ProduceFormModal;
 
 impl ProduceFormModal {
     pub fn render(frame: &mut Frame, form_state: &ProduceFormState) {
-        let area = centered_rect_fixed(60, 13, frame.area());
+        let header_rows = form_state.headers.len().clamp(1, VISIBLE_PAIRS) as u16;
+        let area = centered_rect_fixed(60, VALUE_ROWS + 14 + header_rows, frame.area());
 
         frame.render_widget(Clear, area);
 
@@ -30,30 +36,81 @@ impl ProduceFormModal {
             .direction(Direction::Vertical)
             .margin(1)
             .constraints([
-                Constraint::Length(1), // Key label
-                Constraint::Length(1), // Key input
-                Constraint::Length(1), // Spacer
-                Constraint::Length(1), // Value label
-                Constraint::Length(3), // Value input (multiline)
-                Constraint::Length(1), // Hint
+                Constraint::Length(1),           // Key label
+                Constraint::Length(1),           // Key input
+                Constraint::Length(1),           // Spacer
+                Constraint::Length(1),           // Value label
+                Constraint::Length(VALUE_ROWS),  // Value input (multiline)
+                Constraint::Length(1),           // Headers label
+                Constraint::Length(header_rows), // Header list
+                Constraint::Length(1),           // Partition and count labels
+                Constraint::Length(1),           // Partition and count inputs
+                Constraint::Length(1),           // Hint
             ])
             .split(inner);
 
-        let key_focused = form_state.focused_field == ProduceFormField::Key;
-        render_labeled_input(
+        let cursor = |field: ProduceFormField, value: &str| {
+            (form_state.focused_field == field).then(|| form_state.cursor.position(value))
+        };
+        render_labeled_text_input(
             frame, chunks[0], chunks[1],
-            "Key (optional):", &form_state.key, "(null)", key_focused,
+            "Key (optional):", &form_state.key, "(null)",
+            cursor(ProduceFormField::Key, &form_state.key),
         );
 
         let value_focused = form_state.focused_field == ProduceFormField::Value;
-        render_labeled_input(
-            frame, chunks[3], chunks[4],
-            "Value:", &form_state.value, "(required)", value_focused,
+        let (value_label, value_placeholder) = if form_state.value_from_file {
+            ("Value from file:", "path to payload file")
+        } else {
+            ("Value:", "(required)")
+        };
+        let value_cursor = cursor(ProduceFormField::Value, &form_state.value);
+        if form_state.value_from_file {
+            render_labeled_text_input(
+                frame, chunks[3], chunks[4],
+                value_label, &form_state.value, value_placeholder, value_cursor,
+            );
+        } else {
+            render_labeled_multiline_input(
+                frame, chunks[3], chunks[4],
+                value_label, &form_state.value, value_placeholder, value_cursor,
+            );
+        }
+
+        let headers_focused = form_state.focused_field == ProduceFormField::Headers;
+        let label = Paragraph::new(format!("Headers ({}):", form_state.headers.len()))
+            .style(label_style(headers_focused));
+        frame.render_widget(label, chunks[5]);
+        render_pair_list(
+            frame, chunks[6], &form_state.headers, form_state.header_index,
+            form_state.header_value_focused, headers_focused, "Type to add a header",
+        );
+
+        let halves = |area: Rect| Layout::horizontal([Constraint::Fill(1); 2]).spacing(2).split(area);
+        let (labels, inputs) = (halves(chunks[7]), halves(chunks[8]));
+        render_labeled_text_input(
+            frame, labels[0], inputs[0],
+            "Partition:", &form_state.partition, "any",
+            cursor(ProduceFormField::Partition, &form_state.partition),
+        );
+        render_labeled_text_input(
+            frame, labels[1], inputs[1],
+            "Count:", &form_state.count, "1",
+            cursor(ProduceFormField::Count, &form_state.count),
         );
 
-        let hint = Paragraph::new("Tab: switch field | Enter: send | Esc: cancel")
+        let hint_text = if headers_focused {
+            "↑/↓: select | ←/→: name/value | Ctrl+N: add | Ctrl+D: remove"
+        } else if value_focused && form_state.value_from_file {
+            "Ctrl+F: type value / read file | Tab: switch field | Enter: send"
+        } else if value_focused {
+            "Enter: new line | Ctrl+S: send | Ctrl+F: read file | Tab: switch"
+        } else {
+            "Tab: switch field | Enter: send | Esc: cancel"
+        };
+        let hint = Paragraph::new(hint_text)
             .style(THEME.muted_style())
             .alignment(Alignment::Center);
-        frame.render_widget(hint, chunks[5]);
+        frame.render_widget(hint, chunks[9]);
     }
 }
@@ -0,0 +1,103 @@
+use ratatui::{
+    prelude::*,
+    widgets::{Clear, Paragraph},
+};
+
+use crate::app::state::{ResetOffsetMode, ResetOffsetsFormField, ResetOffsetsFormState};
+use crate::ui::layout::centered_rect_fixed;
+use crate::ui::theme::THEME;
+use crate::ui::widgets::{label_style, modal_block, render_labeled_input};
+
+pub struct ResetOffsetsFormModal;
+
+impl ResetOffsetsFormModal {
+    pub fn render(frame: &mut Frame, form_state: &ResetOffsetsFormState) {
+        let area = centered_rect_fixed(60, 18, frame.area());
+
+        frame.render_widget(Clear, area);
+
+        let block = modal_block("Reset Offsets");
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(1)
+            .constraints([
+                Constraint::Length(1), // Group info
+                Constraint::Length(1), // Spacer
+                Constraint::Length(1), // Topic label
+                Constraint::Length(1), // Topic selector
+                Constraint::Length(1), // Partition label
+                Constraint::Length(1), // Partition input
+                Constraint::Length(1), // Mode label
+                Constraint::Length(1), // Mode selector
+                Constraint::Length(1), // Value label
+                Constraint::Length(1), // Value input
+                Constraint::Length(1), // Spacer
+                Constraint::Length(1), // Hint
+            ])
+            .split(inner);
+
+        let group_info = Paragraph::new(format!("Group: {}", form_state.group_id))
+            .style(THEME.title_style());
+        frame.render_widget(group_info, chunks[0]);
+
+        let topic_focused = form_state.focused_field == ResetOffsetsFormField::Topic;
+        Self::render_selector(
+            frame, chunks[2], chunks[3],
+            "Topic:", form_state.selected_topic().unwrap_or("-"), topic_focused,
+        );
+
+        let partition_focused = form_state.focused_field == ResetOffsetsFormField::Partition;
+        render_labeled_input(
+            frame, chunks[4], chunks[5],
+            "Partition (empty = all):", &form_state.partition, "all", partition_focused,
+        );
+
+        let mode_focused = form_state.focused_field == ResetOffsetsFormField::Mode;
+        Self::render_selector(
+            frame, chunks[6], chunks[7],
+            "Reset to:", form_state.mode.display_name(), mode_focused,
+        );
+
+        if form_state.mode.requires_value() {
+            let (label, placeholder) = match form_state.mode {
+                ResetOffsetMode::Timestamp => ("Timestamp (UTC):", "YYYY-MM-DD HH:MM:SS"),
+                _ => ("Offset:", "0"),
+            };
+            let value_focused = form_state.focused_field == ResetOffsetsFormField::Value;
+            render_labeled_input(
+                frame, chunks[8], chunks[9],
+                label, &form_state.value, placeholder, value_focused,
+            );
+        }
+
+        let hint_text = match form_state.focused_field {
+            ResetOffsetsFormField::Topic | ResetOffsetsFormField::Mode => {
+                "←/→: change | Tab: next | Enter: reset | Esc: cancel"
+            }
+            _ => "Tab: next field | Enter: reset | Esc: cancel",
+        };
+        let hint = Paragraph::new(hint_text)
+            .style(THEME.muted_style())
+            .alignment(Alignment::Center);
+        frame.render_widget(hint, chunks[11]);
+    }
+
+    fn render_selector(
+        frame: &mut Frame,
+        label_area: Rect,
+        input_area: Rect,
+        label_text: &str,
+        value: &str,
+        focused: bool,
+    ) {
+        let label = Paragraph::new(label_text).style(label_style(focused));
+        frame.render_widget(label, label_area);
+
+        let display = format!("◀ {} ▶", value);
+        let input = Paragraph::new(display).style(THEME.input_style(focused));
+        frame.render_widget(input, input_area);
+    }
+}
@@ -0,0 +1,78 @@
+use ratatui::{
+    prelude::*,
+    widgets::{Clear, Paragraph},
+};
+
+use crate::app::state::{ResetOffsetMode, SeekFormField, SeekFormState};
+use crate::ui::layout::centered_rect_fixed;
+use crate::ui::theme::THEME;
+use crate::ui::widgets::{label_style, modal_block, render_labeled_input};
+
+pub struct SeekFormModal;
+
+impl SeekFormModal {
+    pub fn render(frame: &mut Frame, form_state: &SeekFormState) {
+        let area = centered_rect_fixed(60, 16, frame.area());
+
+        frame.render_widget(Clear, area);
+
+        let block = modal_block("Seek");
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(1)
+            .constraints([
+                Constraint::Length(1), // Topic info
+                Constraint::Length(1), // Spacer
+                Constraint::Length(1), // Mode label
+                Constraint::Length(1), // Mode selector
+                Constraint::Length(1), // Value label
+                Constraint::Length(1), // Value input
+                Constraint::Length(1), // Partition label
+                Constraint::Length(1), // Partition input
+                Constraint::Length(1), // Spacer
+                Constraint::Length(1), // Hint
+            ])
+            .split(inner);
+
+        let topic_info = Paragraph::new(format!("Topic: {}", form_state.topic))
+            .style(THEME.title_style());
+        frame.render_widget(topic_info, chunks[0]);
+
+        let mode_focused = form_state.focused_field == SeekFormField::Mode;
+        let mode_label = Paragraph::new("Start from:").style(label_style(mode_focused));
+        frame.render_widget(mode_label, chunks[2]);
+        let mode = Paragraph::new(format!("◀ {} ▶", form_state.mode.display_name()))
+            .style(THEME.input_style(mode_focused));
+        frame.render_widget(mode, chunks[3]);
+
+        if form_state.mode.requires_value() {
+            let (label, placeholder) = match form_state.mode {
+                ResetOffsetMode::Timestamp => ("Timestamp (UTC):", "YYYY-MM-DD HH:MM:SS"),
+                _ => ("Offset:", "0"),
+            };
+            let value_focused = form_state.focused_field == SeekFormField::Value;
+            render_labeled_input(
+                frame, chunks[4], chunks[5],
+                label, &form_state.value, placeholder, value_focused,
+            );
+        }
+
+        let partition_focused = form_state.focused_field == SeekFormField::Partition;
+        render_labeled_input(
+            frame, chunks[6], chunks[7],
+            "Partition (empty = all):", &form_state.partition, "all", partition_focused,
+        );
+
+        let hint_text = match form_state.focused_field {
+            SeekFormField::Mode => "←/→: change | Tab: next | Enter: fetch | Esc: cancel",
+            _ => "Tab: next field | Enter: fetch | Esc: cancel",
+        };
+        let hint = Paragraph::new(hint_text)
+            .style(THEME.muted_style())
+            .alignment(Alignment::Center);
+        frame.render_widget(hint, chunks[9]);
+    }
+}
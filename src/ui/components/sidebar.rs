@@ -24,6 +24,7 @@ impl Sidebar {
                     SidebarItem::ConsumerGroups => "󰡨",
                     SidebarItem::Brokers => "",
                     SidebarItem::Logs => "",
+                    SidebarItem::Throughput => "󰓅",
                 };
 
                 let is_selected = state.ui_state.selected_sidebar_item == *item;
@@ -3,7 +3,7 @@ use ratatui::{
     widgets::Paragraph,
 };
 
-use crate::app::state::AppState;
+use crate::app::state::{AppState, PingStatus, Screen};
 use crate::events::key_bindings::get_help_text;
 use crate::ui::theme::THEME;
 
@@ -11,14 +11,43 @@ pub struct StatusBar;
 
 impl StatusBar {
     pub fn render(frame: &mut Frame, area: Rect, state: &AppState) {
+        // Connection indicator
+        let conn_line = if let Some(ref profile) = state.connection.active_profile {
+            let (health, health_style) = Self::health(state.connection.last_ping.as_ref());
+            Line::from(vec![
+                Span::styled("● ", health_style),
+                Span::styled(profile.name.clone(), THEME.normal_style()),
+                Span::styled(format!(" ({}) ", profile.auth.summary()), THEME.muted_style()),
+                Span::styled(health, health_style),
+            ])
+        } else {
+            Line::from(vec![
+                Span::styled("○ ", THEME.status_disconnected()),
+                Span::styled("Not connected", THEME.status_disconnected()),
+            ])
+        };
+
+        let crumbs = state.breadcrumb();
+        let breadcrumb_line = if state.active_screen == Screen::Welcome {
+            Line::default()
+        } else {
+            Line::from(vec![
+                Span::styled(format!(" {}", crumbs.join(" › ")), THEME.normal_style()),
+                Span::styled(" │", THEME.muted_style()),
+            ])
+        };
+
         let chunks = Layout::default()
             .direction(Direction::Horizontal)
             .constraints([
-                Constraint::Min(40),      // Key hints
-                Constraint::Length(40),   // Connection info
+                Constraint::Length(breadcrumb_line.width() as u16), // Breadcrumb
+                Constraint::Min(20),                                // Key hints
+                Constraint::Length(conn_line.width() as u16 + 1),   // Connection info
             ])
             .split(area);
 
+        frame.render_widget(Paragraph::new(breadcrumb_line), chunks[0]);
+
         // Key hints
         let help_items = get_help_text(&state.active_screen);
         let hints: Vec<Span> = help_items
@@ -34,26 +63,27 @@ impl StatusBar {
 
         let hints_line = Line::from(hints);
         let hints_paragraph = Paragraph::new(hints_line);
-        frame.render_widget(hints_paragraph, chunks[0]);
-
-        // Connection indicator
-        let conn_line = if let Some(ref profile) = state.connection.active_profile {
-            Line::from(vec![
-                Span::styled("● ", THEME.status_connected()),
-                Span::styled(&profile.name, THEME.normal_style()),
-                Span::styled(" (", THEME.muted_style()),
-                Span::styled(&profile.brokers, THEME.muted_style()),
-                Span::styled(")", THEME.muted_style()),
-            ])
-        } else {
-            Line::from(vec![
-                Span::styled("○ ", THEME.status_disconnected()),
-                Span::styled("Not connected", THEME.status_disconnected()),
-            ])
-        };
+        frame.render_widget(hints_paragraph, chunks[1]);
 
         let conn_paragraph = Paragraph::new(conn_line).alignment(Alignment::Right);
-        frame.render_widget(conn_paragraph, chunks[1]);
+        frame.render_widget(conn_paragraph, chunks[2]);
+    }
+
+    /// Latency text and colour: green under 100ms, yellow under 500ms, red beyond or unreachable.
+    fn health(ping: Option<&PingStatus>) -> (String, Style) {
+        match ping.map(|p| p.latency) {
+            None => (String::new(), THEME.status_connected()),
+            Some(None) => ("unreachable".into(), THEME.error_style()),
+            Some(Some(latency)) => {
+                let ms = latency.as_millis();
+                let style = match ms {
+                    0..100 => THEME.success_style(),
+                    100..500 => THEME.warning_style(),
+                    _ => THEME.error_style(),
+                };
+                (format!("{}ms", ms), style)
+            }
+        }
     }
 
     pub fn render_loading(frame: &mut Frame, area: Rect, message: &str) {
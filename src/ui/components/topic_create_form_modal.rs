@@ -4,15 +4,18 @@ use ratatui::{
 };
 
 use crate::app::state::{TopicCreateFormField, TopicCreateFormState};
+use crate::app::validation::{parse_partitions, parse_replication_factor};
+use crate::error::AppError;
 use crate::ui::layout::centered_rect_fixed;
 use crate::ui::theme::THEME;
-use crate::ui::widgets::{modal_block, render_labeled_input};
+use crate::ui::widgets::{label_style, modal_block, render_labeled_text_input, render_pair_list, VISIBLE_PAIRS};
 
 pub struct TopicCreateFormModal;
 
 impl TopicCreateFormModal {
     pub fn render(frame: &mut Frame, form_state: &TopicCreateFormState) {
-        let area = centered_rect_fixed(60, 15, frame.area());
+        let config_rows = form_state.configs.len().clamp(1, VISIBLE_PAIRS) as u16;
+        let area = centered_rect_fixed(60, 16 + config_rows, frame.area());
 
         frame.render_widget(Clear, area);
 
@@ -29,35 +32,82 @@ impl TopicCreateFormModal {
                 Constraint::Length(1), // Spacer
                 Constraint::Length(1), // Partitions label
                 Constraint::Length(1), // Partitions input
-                Constraint::Length(1), // Spacer
+                Constraint::Length(1), // Partitions feedback
                 Constraint::Length(1), // Replication label
-                Constraint::Length(1), // Replication input
-                Constraint::Length(1), // Spacer
-                Constraint::Length(1), // Hint
+                Constraint::Length(1),           // Replication input
+                Constraint::Length(1),           // Replication feedback
+                Constraint::Length(1),           // Configs label
+                Constraint::Length(config_rows), // Config list
+                Constraint::Length(1),           // Hint
             ])
             .split(inner);
 
-        let name_focused = form_state.focused_field == TopicCreateFormField::Name;
-        render_labeled_input(
+        let cursor = |field: TopicCreateFormField, value: &str| {
+            (form_state.focused_field == field).then(|| form_state.cursor.position(value))
+        };
+        render_labeled_text_input(
             frame, chunks[0], chunks[1],
-            "Topic Name:", &form_state.name, "(required)", name_focused,
+            "Topic Name:", &form_state.name, "(required)",
+            cursor(TopicCreateFormField::Name, &form_state.name),
         );
 
-        let partitions_focused = form_state.focused_field == TopicCreateFormField::Partitions;
-        render_labeled_input(
+        render_labeled_text_input(
             frame, chunks[3], chunks[4],
-            "Partitions:", &form_state.partitions, "1", partitions_focused,
+            "Partitions:", &form_state.partitions, "1",
+            cursor(TopicCreateFormField::Partitions, &form_state.partitions),
         );
+        let partitions_hint = numeric_hint(&form_state.partitions, parse_partitions, |n| {
+            format!("{} partition{}", n, if n == 1 { "" } else { "s" })
+        });
+        frame.render_widget(Paragraph::new(partitions_hint), chunks[5]);
 
-        let replication_focused = form_state.focused_field == TopicCreateFormField::ReplicationFactor;
-        render_labeled_input(
+        render_labeled_text_input(
             frame, chunks[6], chunks[7],
-            "Replication Factor:", &form_state.replication_factor, "1", replication_focused,
+            "Replication Factor:", &form_state.replication_factor, "1",
+            cursor(TopicCreateFormField::ReplicationFactor, &form_state.replication_factor),
+        );
+        let replication_hint = match (parse_replication_factor(&form_state.replication_factor), form_state.broker_count) {
+            (Ok(rf), Some(brokers)) if rf as usize > brokers => Line::styled(
+                format!("  Only {} broker{} in the cluster", brokers, if brokers == 1 { "" } else { "s" }),
+                THEME.warning_style(),
+            ),
+            _ => numeric_hint(&form_state.replication_factor, parse_replication_factor, |n| {
+                format!("{} cop{} of each partition", n, if n == 1 { "y" } else { "ies" })
+            }),
+        };
+        frame.render_widget(Paragraph::new(replication_hint), chunks[8]);
+
+        let configs_focused = form_state.focused_field == TopicCreateFormField::Configs;
+        let label = Paragraph::new(format!("Config overrides ({}):", form_state.configs.len()))
+            .style(label_style(configs_focused));
+        frame.render_widget(label, chunks[9]);
+        render_pair_list(
+            frame, chunks[10], &form_state.configs, form_state.config_index,
+            form_state.config_value_focused, configs_focused, "Type a name, e.g. cleanup.policy",
         );
 
-        let hint = Paragraph::new("Tab: switch field | Enter: create | Esc: cancel")
+        let hint_text = if configs_focused {
+            "↑/↓: select | ←/→: name/value | Ctrl+N: add | Ctrl+D: remove"
+        } else {
+            "Tab: switch field | Enter: create | Esc: cancel"
+        };
+        let hint = Paragraph::new(hint_text)
             .style(THEME.muted_style())
             .alignment(Alignment::Center);
-        frame.render_widget(hint, chunks[9]);
+        frame.render_widget(hint, chunks[11]);
+    }
+}
+
+/// Feedback under a numeric field: what the input will be read as, or why it can't be
+fn numeric_hint(
+    input: &str,
+    parse: impl Fn(&str) -> Result<i32, AppError>,
+    describe: impl Fn(i32) -> String,
+) -> Line<'static> {
+    match parse(input) {
+        Ok(n) => Line::styled(format!("  {}", describe(n)), THEME.muted_style()),
+        Err(_) if input.trim().is_empty() => Line::styled("  Required", THEME.error_style()),
+        Err(AppError::Validation { message, .. }) => Line::styled(format!("  {}", message), THEME.error_style()),
+        Err(e) => Line::styled(format!("  {}", e), THEME.error_style()),
     }
 }
@@ -1,7 +1,9 @@
+pub mod clipboard;
 pub mod components;
 pub mod layout;
 pub mod render;
 pub mod screens;
+pub mod table_text;
 pub mod theme;
 pub mod widgets;
 
@@ -1,17 +1,18 @@
 use ratatui::prelude::*;
 
-use crate::app::state::{AppState, ModalType, Screen};
+use crate::app::state::{AppState, InputAction, ModalType, Screen};
 use crate::ui::components::{
-    AddPartitionsFormModal, AlterConfigFormModal, ConfirmModal, ConnectionFormModal,
-    Header, HelpModal, InputModal, ProduceFormModal, PurgeTopicFormModal, Sidebar,
-    StatusBar, Toast, TopicCreateFormModal,
+    AddPartitionsFormModal, AlterConfigFormModal, CommandPalette, ConfirmModal, ConnectionFormModal,
+    Header, HelpModal, HistoryModal, InputModal, LogDetailModal, ProduceFormModal, PurgeTopicFormModal, ResetOffsetsFormModal,
+    SeekFormModal, Sidebar, StatusBar, Toast, TopicCreateFormModal,
 };
 use crate::ui::layout::{welcome_layout, AppLayout};
 use crate::ui::screens::{
-    brokers::BrokersScreen,
+    brokers::{BrokerDetailsScreen, BrokersScreen},
     consumer_groups::{ConsumerGroupDetailsScreen, ConsumerGroupsListScreen},
     logs::LogsScreen,
     messages::MessageBrowserScreen,
+    throughput::ThroughputScreen,
     topics::{TopicDetailsScreen, TopicsListScreen},
     welcome::WelcomeScreen,
 };
@@ -48,6 +49,8 @@ fn render_content(frame: &mut Frame, area: Rect, state: &AppState) {
         Screen::ConsumerGroups => ConsumerGroupsListScreen::render(frame, area, state),
         Screen::ConsumerGroupDetails { group_id } => ConsumerGroupDetailsScreen::render(frame, area, state, group_id),
         Screen::Brokers => BrokersScreen::render(frame, area, state),
+        Screen::BrokerDetails { broker_id } => BrokerDetailsScreen::render(frame, area, state, *broker_id),
+        Screen::Throughput => ThroughputScreen::render(frame, area, state),
         Screen::Logs => LogsScreen::render(frame, area, state),
     }
 }
@@ -61,13 +64,30 @@ fn render_overlays(frame: &mut Frame, state: &AppState) {
     if let Some(modal) = &state.ui_state.active_modal {
         match modal {
             ModalType::Confirm { title, message, .. } => ConfirmModal::render(frame, title, message),
-            ModalType::Input { title, placeholder, value, .. } => InputModal::render(frame, title, placeholder, value),
+            ModalType::Input { title, placeholder, value, action: InputAction::FilterTopics } => {
+                let (title, hint) = if state.topics_state.filter_regex {
+                    (format!("{} (regex)", title), "Ctrl+R: substring | Ctrl+F: fuzzy")
+                } else if state.topics_state.filter_fuzzy {
+                    (format!("{} (fuzzy)", title), "Ctrl+F: substring | Ctrl+R: regex")
+                } else {
+                    (title.clone(), "Ctrl+R: regex | Ctrl+F: fuzzy | Esc: cancel")
+                };
+                InputModal::render(frame, &title, placeholder, value, hint)
+            }
+            ModalType::Input { title, placeholder, value, .. } => {
+                InputModal::render(frame, title, placeholder, value, "Enter to confirm, Esc to cancel")
+            }
             ModalType::ConnectionForm(f) => ConnectionFormModal::render(frame, f),
             ModalType::TopicCreateForm(f) => TopicCreateFormModal::render(frame, f),
             ModalType::ProduceForm(f) => ProduceFormModal::render(frame, f),
             ModalType::AddPartitionsForm(f) => AddPartitionsFormModal::render(frame, f),
             ModalType::AlterConfigForm(f) => AlterConfigFormModal::render(frame, f),
             ModalType::PurgeTopicForm(f) => PurgeTopicFormModal::render(frame, f),
+            ModalType::ResetOffsetsForm(f) => ResetOffsetsFormModal::render(frame, f),
+            ModalType::SeekForm(f) => SeekFormModal::render(frame, f),
+            ModalType::History(h) => HistoryModal::render(frame, h),
+            ModalType::LogDetail(d) => LogDetailModal::render(frame, d),
+            ModalType::CommandPalette(p) => CommandPalette::render(frame, p),
         }
     }
 
@@ -1,10 +1,11 @@
 use ratatui::{
     prelude::*,
-    widgets::{Block, Borders, Cell, Paragraph, Row, Table},
+    widgets::{Block, Borders, Cell, Paragraph, Row},
 };
 
 use crate::app::state::AppState;
 use crate::ui::theme::THEME;
+use crate::ui::widgets::{render_config_table, render_table};
 
 pub struct BrokersScreen;
 
@@ -47,44 +48,64 @@ impl BrokersScreen {
             .map(|b| format!("Controller: {}", b.id))
             .unwrap_or_else(|| "Controller: Unknown".to_string());
 
+        let cluster = state.brokers_state.cluster_id.as_deref().unwrap_or("Unknown");
+
         let summary = Paragraph::new(format!(
-            " {} brokers | {}",
-            broker_count, controller
+            " {} brokers | {} | Cluster ID: {}",
+            broker_count, controller, cluster
         )).style(THEME.muted_style());
         frame.render_widget(summary, chunks[0]);
 
         // Table
-        let header = Row::new(vec![
-            Cell::from(" ID").style(THEME.table_header_style()),
-            Cell::from("Host").style(THEME.table_header_style()),
-            Cell::from("Port").style(THEME.table_header_style()),
-            Cell::from("Role").style(THEME.table_header_style()),
-        ]).height(1);
+        let headers = [" ID", "Host", "Port", "Rack", "Role"];
 
         let rows: Vec<Row> = state.brokers_state.brokers.iter().map(|b| {
             let role = if b.is_controller { "Controller" } else { "Follower" };
             let role_style = if b.is_controller { THEME.success_style() } else { THEME.normal_style() };
+            // The whole controller row stands out, not just its role
+            let row_style = if b.is_controller { Style::default().add_modifier(Modifier::BOLD) } else { Style::default() };
 
             Row::new(vec![
                 Cell::from(format!(" {}", b.id)).style(THEME.partition_style()),
                 Cell::from(b.host.clone()),
                 Cell::from(b.port.to_string()),
+                Cell::from(b.rack.clone().unwrap_or_default()).style(THEME.muted_style()),
                 Cell::from(role).style(role_style),
-            ])
+            ]).style(row_style)
         }).collect();
 
-        let table = Table::new(
-            rows,
-            [
-                Constraint::Length(8),
-                Constraint::Percentage(50),
-                Constraint::Length(10),
-                Constraint::Min(15),
-            ]
-        )
-        .header(header)
-        .row_highlight_style(THEME.selected_style());
-
-        frame.render_widget(table, chunks[1]);
+        let widths = [
+            Constraint::Length(8),
+            Constraint::Percentage(40),
+            Constraint::Length(10),
+            Constraint::Length(16),
+            Constraint::Min(15),
+        ];
+        render_table(frame, chunks[1], &headers, rows, &widths, Some(state.brokers_state.selected_index));
+    }
+}
+
+pub struct BrokerDetailsScreen;
+
+impl BrokerDetailsScreen {
+    pub fn render(frame: &mut Frame, area: Rect, state: &AppState, broker_id: i32) {
+        let block = Block::default()
+            .title(format!(" Broker {} Configuration ", broker_id))
+            .title_style(THEME.header_style())
+            .borders(Borders::ALL)
+            .border_style(THEME.border_style(!state.ui_state.sidebar_focused));
+
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        match &state.brokers_state.current_detail {
+            Some(detail) => render_config_table(frame, inner, &detail.config, state.brokers_state.config_selected),
+            None => {
+                let loading = Paragraph::new("Loading...")
+                    .style(THEME.loading_style())
+                    .alignment(Alignment::Center);
+                frame.render_widget(loading, inner);
+            }
+        }
     }
 }
@@ -1,20 +1,29 @@
 use ratatui::{
     prelude::*,
-    widgets::{Block, Borders, Cell, Paragraph, Row, Table, Tabs},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Tabs},
 };
 
-use crate::app::state::{AppState, ConsumerGroupDetail, ConsumerGroupDetailTab};
+use crate::app::state::{AppState, ConsumerGroupDetail, ConsumerGroupDetailTab, NumberFormat};
 use crate::ui::theme::THEME;
+use crate::ui::widgets::{format_number, fraction_bar, render_table};
+
+/// Width of the per-partition lag bar on the Offsets tab
+const LAG_BAR_WIDTH: usize = 16;
 
 pub struct ConsumerGroupDetailsScreen;
 
 impl ConsumerGroupDetailsScreen {
     pub fn render(frame: &mut Frame, area: Rect, state: &AppState, group_id: &str) {
-        let block = Block::default()
+        let mut block = Block::default()
             .title(format!(" Consumer Group: {} ", group_id))
             .title_style(THEME.header_style())
             .borders(Borders::ALL)
             .border_style(THEME.border_style(true));
+        if let Some(c) = state.consumer_groups_state.current_detail.as_ref().and_then(|d| d.coordinator.as_ref()) {
+            block = block.title(
+                Line::from(format!(" Coordinator: {} ({}:{}) ", c.id, c.host, c.port)).right_aligned(),
+            );
+        }
 
         let inner = block.inner(area);
         frame.render_widget(block, area);
@@ -44,7 +53,9 @@ impl ConsumerGroupDetailsScreen {
             Some(detail) => {
                 match state.consumer_groups_state.detail_tab {
                     ConsumerGroupDetailTab::Members => Self::render_members(frame, chunks[1], detail),
-                    ConsumerGroupDetailTab::Offsets => Self::render_offsets(frame, chunks[1], detail),
+                    ConsumerGroupDetailTab::Offsets => {
+                        Self::render_offsets(frame, chunks[1], detail, state.ui_state.number_format)
+                    }
                 }
             }
             None => {
@@ -56,7 +67,7 @@ impl ConsumerGroupDetailsScreen {
         }
 
         // Hints
-        let hints = Paragraph::new(" [Tab/h/l] Switch tab | [F5] Refresh | [Esc] Back")
+        let hints = Paragraph::new(" [Tab/h/l] Switch tab | [r] Reset offsets | [F5] Refresh | [Esc] Back")
             .style(THEME.muted_style());
         frame.render_widget(hints, chunks[2]);
     }
@@ -83,11 +94,7 @@ impl ConsumerGroupDetailsScreen {
             return;
         }
 
-        let header = Row::new(vec![
-            Cell::from(" Client ID").style(THEME.table_header_style()),
-            Cell::from("Host").style(THEME.table_header_style()),
-            Cell::from("Assignments").style(THEME.table_header_style()),
-        ]).height(1);
+        let headers = [" Client ID", "Host", "Assignments"];
 
         let rows: Vec<Row> = detail.members.iter().map(|m| {
             let assignments = if m.assignments.is_empty() {
@@ -106,21 +113,15 @@ impl ConsumerGroupDetailsScreen {
             ])
         }).collect();
 
-        let table = Table::new(
-            rows,
-            [
-                Constraint::Percentage(30),
-                Constraint::Percentage(25),
-                Constraint::Percentage(45),
-            ]
-        )
-        .header(header)
-        .row_highlight_style(THEME.selected_style());
-
-        frame.render_widget(table, chunks[1]);
+        let widths = [
+            Constraint::Percentage(30),
+            Constraint::Percentage(25),
+            Constraint::Percentage(45),
+        ];
+        render_table(frame, chunks[1], &headers, rows, &widths, None);
     }
 
-    fn render_offsets(frame: &mut Frame, area: Rect, detail: &ConsumerGroupDetail) {
+    fn render_offsets(frame: &mut Frame, area: Rect, detail: &ConsumerGroupDetail, numbers: NumberFormat) {
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([Constraint::Length(2), Constraint::Min(5)])
@@ -133,7 +134,7 @@ impl ConsumerGroupDetailsScreen {
         let lag_style = THEME.lag_style(total_lag);
         let info = Paragraph::new(format!(
             " Partitions: {}  |  Total Lag: {}",
-            partition_count, format_number(total_lag)
+            partition_count, format_number(total_lag, numbers)
         )).style(lag_style);
         frame.render_widget(info, chunks[0]);
 
@@ -145,49 +146,29 @@ impl ConsumerGroupDetailsScreen {
             return;
         }
 
-        let header = Row::new(vec![
-            Cell::from(" Topic").style(THEME.table_header_style()),
-            Cell::from("Partition").style(THEME.table_header_style()),
-            Cell::from("Current").style(THEME.table_header_style()),
-            Cell::from("End").style(THEME.table_header_style()),
-            Cell::from("Lag").style(THEME.table_header_style()),
-        ]).height(1);
+        let headers = [" Topic", "Partition", "Current", "End", "Lag", "Lag share"];
 
+        // Bars are scaled to the most lagging partition so skew stands out
+        let max_lag = detail.offsets.iter().map(|o| o.lag).max().unwrap_or(0);
         let rows: Vec<Row> = detail.offsets.iter().map(|o| {
             Row::new(vec![
                 Cell::from(format!(" {}", o.topic)),
                 Cell::from(o.partition.to_string()).style(THEME.partition_style()),
-                Cell::from(format_number(o.current_offset)).style(THEME.offset_style()),
-                Cell::from(format_number(o.log_end_offset)).style(THEME.offset_style()),
-                Cell::from(format_number(o.lag)).style(THEME.lag_style(o.lag)),
+                Cell::from(format_number(o.current_offset, numbers)).style(THEME.offset_style()),
+                Cell::from(format_number(o.log_end_offset, numbers)).style(THEME.offset_style()),
+                Cell::from(format_number(o.lag, numbers)).style(THEME.lag_style(o.lag)),
+                Cell::from(fraction_bar(o.lag, max_lag, LAG_BAR_WIDTH)).style(THEME.lag_style(o.lag)),
             ])
         }).collect();
 
-        let table = Table::new(
-            rows,
-            [
-                Constraint::Percentage(35),
-                Constraint::Length(10),
-                Constraint::Length(12),
-                Constraint::Length(12),
-                Constraint::Min(10),
-            ]
-        )
-        .header(header)
-        .row_highlight_style(THEME.selected_style());
-
-        frame.render_widget(table, chunks[1]);
-    }
-}
-
-fn format_number(n: i64) -> String {
-    if n >= 1_000_000_000 {
-        format!("{:.1}B", n as f64 / 1_000_000_000.0)
-    } else if n >= 1_000_000 {
-        format!("{:.1}M", n as f64 / 1_000_000.0)
-    } else if n >= 1_000 {
-        format!("{:.1}K", n as f64 / 1_000.0)
-    } else {
-        n.to_string()
+        let widths = [
+            Constraint::Percentage(35),
+            Constraint::Length(10),
+            Constraint::Length(numbers.column_width().max(12)),
+            Constraint::Length(numbers.column_width().max(12)),
+            Constraint::Length(numbers.column_width()),
+            Constraint::Min(LAG_BAR_WIDTH as u16),
+        ];
+        render_table(frame, chunks[1], &headers, rows, &widths, None);
     }
 }
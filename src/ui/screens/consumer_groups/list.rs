@@ -1,11 +1,22 @@
 use ratatui::{
+    layout::Flex,
     prelude::*,
-    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+    widgets::{Block, Borders, Cell, Paragraph, Row, TableState},
 };
 
-use crate::app::state::AppState;
+use crate::app::state::{AppState, GroupSortField};
 use crate::ui::layout::consumer_groups_layout;
 use crate::ui::theme::THEME;
+use crate::ui::widgets::{render_scrollbar, table_rows, table_widget};
+
+const COLUMN_WIDTHS: [Constraint; 6] = [
+    Constraint::Min(30),
+    Constraint::Length(20),
+    Constraint::Length(10),
+    Constraint::Length(10),
+    Constraint::Length(10),
+    Constraint::Length(15),
+];
 
 pub struct ConsumerGroupsListScreen;
 
@@ -45,7 +56,14 @@ impl ConsumerGroupsListScreen {
                 THEME.info_style(),
             )
         };
-        let filter_widget = Paragraph::new(filter_text);
+        let mut filter_spans = vec![filter_text];
+        if state.consumer_groups_state.bookmarks_only {
+            filter_spans.push(Span::styled("  [★ bookmarks]", THEME.warning_style()));
+        }
+        if state.consumer_groups_state.consumers_only {
+            filter_spans.push(Span::styled("  [consumers only]", THEME.warning_style()));
+        }
+        let filter_widget = Paragraph::new(Line::from(filter_spans));
         frame.render_widget(filter_widget, chunks[0]);
 
         // Group count
@@ -83,7 +101,11 @@ impl ConsumerGroupsListScreen {
         let filtered_groups = state.consumer_groups_state.filtered_groups();
 
         if filtered_groups.is_empty() {
-            let empty_message = if state.consumer_groups_state.filter.is_empty() {
+            let empty_message = if state.consumer_groups_state.bookmarks_only {
+                "No bookmarked groups. Press 'b' on a group to bookmark it."
+            } else if state.consumer_groups_state.consumers_only && state.consumer_groups_state.filter.is_empty() {
+                "No consumer groups. Press 'c' to show groups of every protocol."
+            } else if state.consumer_groups_state.filter.is_empty() {
                 "No consumer groups found."
             } else {
                 "No consumer groups match the filter."
@@ -98,14 +120,21 @@ impl ConsumerGroupsListScreen {
 
         frame.render_widget(block, area);
 
-        // Table header
-        let header = Row::new(vec![
-            Cell::from(" Group ID").style(THEME.table_header_style()),
-            Cell::from("State").style(THEME.table_header_style()),
-            Cell::from("Members").style(THEME.table_header_style()),
-            Cell::from("Lag").style(THEME.table_header_style()),
-        ])
-        .height(1);
+        // Table header, with an arrow on the sorted column
+        let cs = &state.consumer_groups_state;
+        let arrow = if cs.sort_ascending { " ▲" } else { " ▼" };
+        let label = |title: &str, field: Option<GroupSortField>| {
+            let suffix = if field.as_ref() == Some(&cs.sort_by) { arrow } else { "" };
+            format!("{}{}", title, suffix)
+        };
+        let headers = [
+            label(" Group ID", Some(GroupSortField::GroupId)),
+            label("State", Some(GroupSortField::State)),
+            label("Protocol", None),
+            label("Members", Some(GroupSortField::Members)),
+            label("Topics", None),
+            label("Lag", Some(GroupSortField::Lag)),
+        ];
 
         // Table rows
         let rows: Vec<Row> = filtered_groups
@@ -114,31 +143,44 @@ impl ConsumerGroupsListScreen {
                 let state_style = THEME.consumer_group_state_style(&group.state);
                 let lag_style = THEME.lag_style(group.total_lag);
 
+                let star = if cs.bookmarks.contains(&group.group_id) { "★ " } else { "" };
+
                 Row::new(vec![
-                    Cell::from(format!(" {}", group.group_id)),
+                    Cell::from(format!(" {}{}", star, group.group_id)),
                     Cell::from(group.state.clone()).style(state_style),
+                    Cell::from(if group.protocol_type.is_empty() { "-" } else { group.protocol_type.as_str() })
+                        .style(if group.is_consumer() { Style::default() } else { THEME.muted_style() }),
                     Cell::from(group.members_count.to_string()),
+                    Cell::from(group.topics.len().to_string()),
                     Cell::from(group.total_lag.to_string()).style(lag_style),
                 ])
                 .height(1)
             })
             .collect();
 
-        let widths = [
-            Constraint::Min(30),
-            Constraint::Length(20),
-            Constraint::Length(10),
-            Constraint::Length(15),
-        ];
-
-        let table = Table::new(rows, widths)
-            .header(header)
-            .row_highlight_style(THEME.selected_style())
-            .highlight_symbol(" ");
+        let table = table_widget(&headers, rows, &COLUMN_WIDTHS).highlight_symbol(" ");
 
         let mut table_state = TableState::default();
         table_state.select(Some(state.consumer_groups_state.selected_index));
 
         frame.render_stateful_widget(table, inner, &mut table_state);
+        render_scrollbar(frame, table_rows(inner), cs);
+    }
+}
+
+/// Sort field for a click at column `x` on the header row of the groups table in `area`.
+pub fn sort_field_at(area: Rect, x: u16) -> Option<GroupSortField> {
+    // Mirror the table layout: inside the border, after the one-column highlight symbol
+    let inner = area.inner(Margin::new(1, 1));
+    let columns_area = Rect { x: inner.x + 1, width: inner.width.saturating_sub(1), ..inner };
+    let columns = Layout::horizontal(COLUMN_WIDTHS).flex(Flex::Start).spacing(1).split(columns_area);
+
+    let index = columns.iter().position(|c| x >= c.x && x < c.x + c.width)?;
+    match index {
+        0 => Some(GroupSortField::GroupId),
+        1 => Some(GroupSortField::State),
+        3 => Some(GroupSortField::Members),
+        5 => Some(GroupSortField::Lag),
+        _ => None,
     }
 }
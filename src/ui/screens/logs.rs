@@ -1,11 +1,11 @@
 use ratatui::{
     prelude::*,
-    widgets::{Block, Borders, Cell, Paragraph, Row, Table},
+    widgets::{Block, Borders, Cell, Paragraph, Row},
 };
 
 use crate::app::state::{AppState, Level};
 use crate::ui::theme::THEME;
-use crate::ui::widgets::render_empty;
+use crate::ui::widgets::{render_empty, render_scrollbar, render_table, table_rows, visible_window};
 
 pub struct LogsScreen;
 
@@ -41,9 +41,14 @@ impl LogsScreen {
         let total = state.logs_state.entries.len();
         let filtered = state.logs_state.filtered_entries().len();
 
+        let search = match &state.logs_state.filter_text {
+            Some(text) => format!("  [/] Text: \"{}\"", text),
+            None => "  [/] Text".to_string(),
+        };
+
         let toolbar = Paragraph::new(format!(
-            " [c] Clear  [f] Filter: {}  |  {} / {} entries",
-            filter_text, filtered, total
+            " [Enter] View  [y] Copy  [c] Clear  [e] Export  [f] Level: {}{}  |  {} / {} entries",
+            filter_text, search, filtered, total
         )).style(THEME.muted_style());
         frame.render_widget(toolbar, area);
     }
@@ -52,21 +57,21 @@ impl LogsScreen {
         let entries = state.logs_state.filtered_entries();
 
         if entries.is_empty() {
-            render_empty(frame, area, "No log entries");
+            let text = if state.logs_state.entries.is_empty() { "No log entries" } else { "No entries match the filter" };
+            render_empty(frame, area, text);
             return;
         }
 
-        let header = Row::new(vec![
-            Cell::from(" Time").style(THEME.table_header_style()),
-            Cell::from("Level").style(THEME.table_header_style()),
-            Cell::from("Message").style(THEME.table_header_style()),
-        ]).height(1);
+        let headers = [" Time", "Level", "Message"];
+
+        let selected = state.logs_state.selected_index;
+        let window = visible_window(entries.len(), selected, area.height.saturating_sub(1) as usize);
 
-        let rows: Vec<Row> = entries.iter().enumerate().map(|(i, entry)| {
+        let rows: Vec<Row> = entries[window.clone()].iter().enumerate().map(|(i, entry)| {
             let time = entry.timestamp.format("%H:%M:%S").to_string();
             let level_text = format!("{} {}", entry.level.icon(), entry.level.label());
 
-            let row_style = if i == state.logs_state.selected_index {
+            let row_style = if window.start + i == selected {
                 THEME.selected_style()
             } else {
                 Style::default()
@@ -79,17 +84,12 @@ impl LogsScreen {
             ]).style(row_style)
         }).collect();
 
-        let table = Table::new(
-            rows,
-            [
-                Constraint::Length(10),
-                Constraint::Length(8),
-                Constraint::Min(30),
-            ]
-        )
-        .header(header)
-        .row_highlight_style(THEME.selected_style());
-
-        frame.render_widget(table, area);
+        let widths = [
+            Constraint::Length(10),
+            Constraint::Length(8),
+            Constraint::Min(30),
+        ];
+        render_table(frame, area, &headers, rows, &widths, None);
+        render_scrollbar(frame, table_rows(area), &state.logs_state);
     }
 }
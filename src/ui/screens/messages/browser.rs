@@ -1,11 +1,14 @@
+use chrono::Utc;
 use ratatui::{
     prelude::*,
-    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState, Wrap},
+    widgets::{Block, Borders, Cell, Paragraph, Row, TableState, Wrap},
 };
 
-use crate::app::state::AppState;
+use crate::app::json_tree::{TreeNode, TreeRow};
+use crate::app::state::{AppState, PayloadView};
 use crate::ui::layout::{messages_layout, messages_layout_collapsed};
 use crate::ui::theme::THEME;
+use crate::ui::widgets::{format_bytes, render_scrollbar, table_rows, table_widget, visible_window};
 
 pub struct MessageBrowserScreen;
 
@@ -42,18 +45,44 @@ impl MessageBrowserScreen {
             .constraints([
                 Constraint::Min(30),      // Topic name
                 Constraint::Length(15),   // Consuming status
-                Constraint::Length(15),   // Message count
+                Constraint::Length(26),   // Message count / fetch limit
             ])
             .split(inner);
 
-        // Topic name
-        let topic_text = format!(" Messages: {}", topic_name);
-        let topic_widget = Paragraph::new(topic_text).style(THEME.title_style());
+        // Topic name, read position and search
+        let ms = &state.messages_state;
+        let partition = ms.partition_filter.map_or("all".to_string(), |p| p.to_string());
+        let mut topic_text = format!(
+            " Messages: {}  [{} | partition {} | {}]",
+            topic_name,
+            ms.offset_mode.label(),
+            partition,
+            ms.order.display_name()
+        );
+        if let Some(query) = &ms.message_search {
+            topic_text.push_str(&format!("  [search: {}]", query));
+        }
+        if let Some(scan) = &ms.scan {
+            topic_text.push_str(&format!("  [find: {} | {} of {} scanned]", scan.pattern, scan.matched, scan.scanned));
+        }
+        // Second line: which offsets of each partition are loaded, for paging with [ and ]
+        let ranges = ms
+            .offset_ranges()
+            .into_iter()
+            .map(|(p, (low, high))| format!("P{} {}–{}", p, low, high))
+            .collect::<Vec<_>>()
+            .join(" · ");
+        let topic_widget = Paragraph::new(vec![
+            Line::styled(topic_text, THEME.title_style()),
+            Line::styled(format!(" Offsets: {}", if ranges.is_empty() { "-" } else { &ranges }), THEME.muted_style()),
+        ]);
         frame.render_widget(topic_widget, chunks[0]);
 
         // Consuming status
         let status = if state.messages_state.consumer_running {
             Span::styled(" Live", THEME.success_style())
+        } else if ms.scan_running() {
+            Span::styled(" Scanning", THEME.loading_style())
         } else {
             Span::styled(" Paused", THEME.muted_style())
         };
@@ -61,7 +90,11 @@ impl MessageBrowserScreen {
         frame.render_widget(status_widget, chunks[1]);
 
         // Message count
-        let count = format!("{} msgs ", state.messages_state.messages.len());
+        let count = format!(
+            "{} msgs (limit {}) ",
+            state.messages_state.messages.len(),
+            state.messages_state.fetch_limit
+        );
         let count_widget = Paragraph::new(count)
             .style(THEME.muted_style())
             .alignment(Alignment::Right);
@@ -87,7 +120,12 @@ impl MessageBrowserScreen {
         }
 
         if state.messages_state.messages.is_empty() {
-            let empty = Paragraph::new("No messages. Press 'p' to produce a message.")
+            let text = match &state.messages_state.scan {
+                Some(scan) if scan.running => " Scanning for matches...",
+                Some(_) => "No matching messages. Press 'f' to find something else.",
+                None => "No messages. Press 'p' to produce a message.",
+            };
+            let empty = Paragraph::new(text)
                 .style(THEME.muted_style())
                 .alignment(Alignment::Center)
                 .block(block);
@@ -98,24 +136,22 @@ impl MessageBrowserScreen {
         frame.render_widget(block, area);
 
         // Table header
-        let header = Row::new(vec![
-            Cell::from(" Partition").style(THEME.table_header_style()),
-            Cell::from("Offset").style(THEME.table_header_style()),
-            Cell::from("Timestamp").style(THEME.table_header_style()),
-            Cell::from("Key").style(THEME.table_header_style()),
-            Cell::from("Value (preview)").style(THEME.table_header_style()),
-        ])
-        .height(1);
+        let headers = [" Partition", "Offset", "Timestamp", "Key", "Size", "Value (preview)"];
+
+        // Only build rows for the visible slice (minus the header row)
+        let messages = &state.messages_state.messages;
+        let selected = state.messages_state.selected_index;
+        let window = visible_window(messages.len(), selected, inner.height.saturating_sub(1) as usize);
 
         // Table rows
-        let rows: Vec<Row> = state
-            .messages_state
-            .messages
+        let time_format = state.messages_state.timestamp_format;
+        let now = Utc::now();
+        let rows: Vec<Row> = messages[window.clone()]
             .iter()
             .map(|msg| {
                 let timestamp = msg
                     .timestamp
-                    .map(|ts| ts.format("%H:%M:%S").to_string())
+                    .map(|ts| time_format.format(ts, now, false))
                     .unwrap_or_else(|| "-".to_string());
 
                 let key = msg.key.as_deref().unwrap_or("-").to_string();
@@ -133,13 +169,21 @@ impl MessageBrowserScreen {
                 // Replace newlines for preview
                 let value_preview = value_preview.replace('\n', " ");
 
+                let row_style = if state.messages_state.matches_search(msg) {
+                    THEME.search_match_style()
+                } else {
+                    Style::default()
+                };
+
                 Row::new(vec![
                     Cell::from(format!(" {}", msg.partition)).style(THEME.partition_style()),
                     Cell::from(msg.offset.to_string()).style(THEME.offset_style()),
                     Cell::from(timestamp),
                     Cell::from(key_display),
+                    Cell::from(format_bytes(msg.value_size)),
                     Cell::from(value_preview),
                 ])
+                .style(row_style)
                 .height(1)
             })
             .collect();
@@ -147,25 +191,33 @@ impl MessageBrowserScreen {
         let widths = [
             Constraint::Length(10),
             Constraint::Length(12),
-            Constraint::Length(10),
+            Constraint::Length(time_format.column_width()),
             Constraint::Length(15),
+            Constraint::Length(8),
             Constraint::Min(20),
         ];
 
-        let table = Table::new(rows, widths)
-            .header(header)
-            .row_highlight_style(THEME.selected_style())
-            .highlight_symbol(" ");
+        let table = table_widget(&headers, rows, &widths).highlight_symbol(" ");
 
         let mut table_state = TableState::default();
-        table_state.select(Some(state.messages_state.selected_index));
+        table_state.select(Some(selected.saturating_sub(window.start)));
 
         frame.render_stateful_widget(table, inner, &mut table_state);
+        render_scrollbar(frame, table_rows(inner), &state.messages_state);
     }
 
     fn render_detail(frame: &mut Frame, area: Rect, state: &AppState) {
+        let ms = &state.messages_state;
+        // Values that aren't JSON show as text even with the tree on
+        let tree = if ms.json_tree { state.json_tree_rows() } else { None };
+        let title = match ms.payload_view {
+            _ if tree.is_some() => " Message Detail (JSON tree) ".to_string(),
+            PayloadView::Utf8 if ms.pretty_json => " Message Detail (pretty JSON) ".to_string(),
+            PayloadView::Utf8 => " Message Detail ".to_string(),
+            view => format!(" Message Detail ({}) ", view.display_name()),
+        };
         let block = Block::default()
-            .title(" Message Detail ")
+            .title(title)
             .borders(Borders::ALL)
             .border_style(THEME.border_style(false));
 
@@ -188,26 +240,100 @@ impl MessageBrowserScreen {
             // Metadata line
             let timestamp = msg
                 .timestamp
-                .map(|ts| ts.format("%Y-%m-%d %H:%M:%S").to_string())
+                .map(|ts| ms.timestamp_format.format(ts, Utc::now(), true))
                 .unwrap_or_else(|| "-".to_string());
+            let time_label = match msg.timestamp {
+                Some(_) => format!("  Time ({}): ", msg.timestamp_type.display_name()),
+                None => "  Time: ".to_string(),
+            };
             let key = msg.key.as_deref().unwrap_or("-");
 
-            let metadata = Line::from(vec![
+            let mut metadata = Line::from(vec![
                 Span::styled("Partition: ", THEME.muted_style()),
                 Span::styled(msg.partition.to_string(), THEME.partition_style()),
                 Span::styled("  Offset: ", THEME.muted_style()),
                 Span::styled(msg.offset.to_string(), THEME.offset_style()),
-                Span::styled("  Time: ", THEME.muted_style()),
+                Span::styled(time_label, THEME.muted_style()),
                 Span::styled(timestamp, THEME.normal_style()),
                 Span::styled("  Key: ", THEME.muted_style()),
                 Span::styled(key, THEME.normal_style()),
+                Span::styled("  Size: ", THEME.muted_style()),
+                Span::styled(format_bytes(msg.value_size), THEME.normal_style()),
+                Span::styled("  Headers: ", THEME.muted_style()),
+                Span::styled(msg.headers.len().to_string(), THEME.normal_style()),
             ]);
+            if let Some(id) = msg.schema_id {
+                metadata.push_span(Span::styled("  Avro schema: ", THEME.muted_style()));
+                metadata.push_span(Span::styled(id.to_string(), THEME.info_style()));
+            }
+            let decompressed = ms.decompress.then(|| ms.decompressed(msg));
+            let payload: &[u8] = match decompressed.as_deref() {
+                Some(Ok((_, bytes))) => bytes,
+                _ => &msg.raw_value,
+            };
+            match decompressed.as_deref() {
+                Some(Ok((codec, bytes))) => {
+                    metadata.push_span(Span::styled("  Decompressed: ", THEME.muted_style()));
+                    metadata.push_span(Span::styled(
+                        format!("{} → {}", codec.display_name(), format_bytes(bytes.len())),
+                        THEME.info_style(),
+                    ));
+                }
+                Some(Err(e)) => {
+                    metadata.push_span(Span::styled("  Decompressed: ", THEME.muted_style()));
+                    metadata.push_span(Span::styled(e.clone(), THEME.warning_style()));
+                }
+                None => {}
+            }
+            let decoded = state.decode_protobuf(msg);
+            let protobuf = decoded.as_ref().map(|(type_name, result)| (*type_name, result.as_ref()));
+            match &protobuf {
+                Some((type_name, Ok(_))) => {
+                    metadata.push_span(Span::styled("  Protobuf: ", THEME.muted_style()));
+                    metadata.push_span(Span::styled(type_name.to_string(), THEME.info_style()));
+                }
+                Some((type_name, Err(e))) => {
+                    metadata.push_span(Span::styled("  Protobuf: ", THEME.muted_style()));
+                    metadata.push_span(Span::styled(format!("not a {} ({})", type_name, e), THEME.error_style()));
+                }
+                None => {}
+            }
             frame.render_widget(Paragraph::new(metadata), chunks[0]);
 
+            if let Some(rows) = &tree {
+                Self::render_json_tree(frame, chunks[2], rows, ms.tree_selected);
+                return;
+            }
+
             // Value
-            let value_widget = Paragraph::new(msg.value.clone())
+            let value = match (ms.payload_view, protobuf) {
+                (PayloadView::Utf8, Some((_, Ok(decoded)))) if ms.pretty_json => {
+                    serde_json::to_string_pretty(&decoded).unwrap_or_else(|_| decoded.to_string())
+                }
+                (PayloadView::Utf8, Some((_, Ok(decoded)))) => decoded.to_string(),
+                (PayloadView::Utf8, _) => {
+                    let text = match decompressed.as_deref() {
+                        Some(Ok(_)) => String::from_utf8_lossy(payload).into_owned(),
+                        _ => msg.value.clone(),
+                    };
+                    if ms.pretty_json {
+                        pretty_json(&text).unwrap_or(text)
+                    } else {
+                        text
+                    }
+                }
+                (PayloadView::Hex, _) => hex_dump(payload),
+                (PayloadView::Escaped, _) => payload.escape_ascii().to_string(),
+            };
+            let value_widget = Paragraph::new(value)
                 .style(THEME.normal_style())
                 .wrap(Wrap { trim: false });
+
+            // Keep the scroll offset within the wrapped content
+            let line_count = u16::try_from(value_widget.line_count(chunks[2].width)).unwrap_or(u16::MAX);
+            let max_scroll = line_count.saturating_sub(chunks[2].height);
+            state.messages_state.detail_max_scroll.set(max_scroll);
+            let value_widget = value_widget.scroll((state.messages_state.detail_scroll.min(max_scroll), 0));
             frame.render_widget(value_widget, chunks[2]);
         } else {
             let empty = Paragraph::new("Select a message to view details")
@@ -216,4 +342,62 @@ impl MessageBrowserScreen {
             frame.render_widget(empty, inner);
         }
     }
+
+    fn render_json_tree(frame: &mut Frame, area: Rect, rows: &[TreeRow], selected: usize) {
+        let selected = selected.min(rows.len().saturating_sub(1));
+        let window = visible_window(rows.len(), selected, area.height as usize);
+        let lines: Vec<Line> = rows[window.clone()]
+            .iter()
+            .zip(window.clone())
+            .map(|(row, i)| {
+                let line = json_tree_line(row);
+                if i == selected { line.style(THEME.selected_style()) } else { line }
+            })
+            .collect();
+        frame.render_widget(Paragraph::new(lines), area);
+    }
+}
+
+/// One tree row: indented, with a disclosure marker on objects and arrays
+fn json_tree_line(row: &TreeRow) -> Line<'_> {
+    let mut spans = vec![Span::raw("  ".repeat(row.depth))];
+    spans.push(match row.node {
+        TreeNode::Object { expanded: true, .. } | TreeNode::Array { expanded: true, .. } => Span::styled("▾ ", THEME.key_hint_style()),
+        TreeNode::Object { .. } | TreeNode::Array { .. } => Span::styled("▸ ", THEME.key_hint_style()),
+        _ => Span::raw("  "),
+    });
+    if !row.label.is_empty() {
+        spans.push(Span::styled(row.label.as_str(), THEME.info_style()));
+        spans.push(Span::styled(": ", THEME.muted_style()));
+    }
+    spans.push(match &row.node {
+        TreeNode::Object { len, .. } => Span::styled(format!("{{{}}}", len), THEME.muted_style()),
+        TreeNode::Array { len, .. } => Span::styled(format!("[{}]", len), THEME.muted_style()),
+        TreeNode::String(s) => Span::styled(format!("{:?}", s), THEME.success_style()),
+        TreeNode::Literal(text) => Span::styled(text.as_str(), THEME.offset_style()),
+    });
+    Line::from(spans)
+}
+
+/// Re-indent a JSON payload, or `None` if the value isn't valid JSON
+fn pretty_json(raw: &str) -> Option<String> {
+    let value: serde_json::Value = serde_json::from_str(raw).ok()?;
+    serde_json::to_string_pretty(&value).ok()
+}
+
+/// Classic hex dump: offset, 16 hex bytes, and a printable ASCII gutter per line
+fn hex_dump(bytes: &[u8]) -> String {
+    bytes
+        .chunks(16)
+        .enumerate()
+        .map(|(i, chunk)| {
+            let hex: Vec<String> = chunk.iter().map(|b| format!("{:02x}", b)).collect();
+            let ascii: String = chunk
+                .iter()
+                .map(|&b| if b.is_ascii_graphic() || b == b' ' { b as char } else { '.' })
+                .collect();
+            format!("{:08x}  {:<47}  |{}|", i * 16, hex.join(" "), ascii)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
 }
@@ -2,5 +2,6 @@ pub mod brokers;
 pub mod consumer_groups;
 pub mod logs;
 pub mod messages;
+pub mod throughput;
 pub mod topics;
 pub mod welcome;
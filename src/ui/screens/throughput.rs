@@ -0,0 +1,80 @@
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, Cell, Paragraph, Row},
+};
+
+use crate::app::state::{AppState, THROUGHPUT_INTERVAL};
+use crate::ui::theme::THEME;
+use crate::ui::widgets::render_table;
+
+pub struct ThroughputScreen;
+
+impl ThroughputScreen {
+    pub fn render(frame: &mut Frame, area: Rect, state: &AppState) {
+        let block = Block::default()
+            .title(" Throughput ")
+            .title_style(THEME.header_style())
+            .borders(Borders::ALL)
+            .border_style(THEME.border_style(!state.ui_state.sidebar_focused));
+
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let ts = &state.throughput_state;
+        let Some(interval) = ts.interval else {
+            // The first sample is only a baseline
+            let waiting = Paragraph::new(format!(
+                "Sampling high watermarks, rates appear after {}s...",
+                THROUGHPUT_INTERVAL.as_secs()
+            ))
+            .style(THEME.loading_style())
+            .alignment(Alignment::Center);
+            frame.render_widget(waiting, inner);
+            return;
+        };
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(2), Constraint::Min(5)])
+            .split(inner);
+
+        let active = ts.rates.iter().filter(|r| r.messages > 0).count();
+        let summary = Paragraph::new(format!(
+            " {} msg/s across the cluster | {} of {} topics active | over the last {:.1}s{}",
+            format_rate(ts.total_per_second()),
+            active,
+            ts.rates.len(),
+            interval.as_secs_f64(),
+            if ts.loading { " | sampling..." } else { "" }
+        ))
+        .style(THEME.muted_style());
+        frame.render_widget(summary, chunks[0]);
+
+        let rows: Vec<Row> = ts
+            .top_rates()
+            .iter()
+            .map(|r| {
+                let style = if r.messages > 0 { THEME.normal_style() } else { THEME.muted_style() };
+                Row::new(vec![
+                    Cell::from(format!(" {}", r.topic)),
+                    Cell::from(format_rate(r.per_second)),
+                    Cell::from(r.messages.to_string()),
+                ])
+                .style(style)
+            })
+            .collect();
+
+        let widths = [Constraint::Min(20), Constraint::Length(12), Constraint::Length(12)];
+        render_table(frame, chunks[1], &[" Topic", "Msgs/s", "Messages"], rows, &widths, Some(ts.selected_index));
+    }
+}
+
+fn format_rate(rate: f64) -> String {
+    if rate >= 1_000_000.0 {
+        format!("{:.1}M", rate / 1_000_000.0)
+    } else if rate >= 1_000.0 {
+        format!("{:.1}K", rate / 1_000.0)
+    } else {
+        format!("{:.1}", rate)
+    }
+}
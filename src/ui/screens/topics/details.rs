@@ -1,10 +1,13 @@
+use std::collections::HashMap;
+
 use ratatui::{
     prelude::*,
-    widgets::{Block, Borders, Cell, Paragraph, Row, Table, Tabs},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Tabs},
 };
 
-use crate::app::state::{AppState, TopicDetailTab};
+use crate::app::state::{AppState, NumberFormat, TopicDetailTab};
 use crate::ui::theme::THEME;
+use crate::ui::widgets::{format_bytes, format_duration_ms, format_number, render_config_table, render_table};
 
 pub struct TopicDetailsScreen;
 
@@ -43,8 +46,14 @@ impl TopicDetailsScreen {
         match &state.topics_state.current_detail {
             Some(detail) => {
                 match state.topics_state.detail_tab {
-                    TopicDetailTab::Partitions => Self::render_partitions(frame, chunks[1], detail),
-                    TopicDetailTab::Config => Self::render_config(frame, chunks[1], detail),
+                    TopicDetailTab::Partitions => {
+                        let selected = state.topics_state.partition_selected;
+                        let numbers = state.ui_state.number_format;
+                        Self::render_partitions(frame, chunks[1], detail, selected, &state.brokers_state.hosts, numbers)
+                    }
+                    TopicDetailTab::Config => {
+                        render_config_table(frame, chunks[1], &detail.config, state.topics_state.config_selected)
+                    }
                 }
             }
             None => {
@@ -56,37 +65,56 @@ impl TopicDetailsScreen {
         }
 
         // Hints
-        let hints = Paragraph::new(" [Tab/h/l] Switch tab | [m] Messages | [d] Delete | [Esc] Back")
+        let hints = Paragraph::new(
+            " [Tab/h/l] Switch tab | [m] Messages | [Enter/o] Partition messages | [w] Watermarks | [y] Copy value | [d] Delete | [Esc] Back",
+        )
             .style(THEME.muted_style());
         frame.render_widget(hints, chunks[2]);
     }
 
-    fn render_partitions(frame: &mut Frame, area: Rect, detail: &crate::app::state::TopicDetail) {
-        let header = Row::new(vec![
-            Cell::from(" ID").style(THEME.table_header_style()),
-            Cell::from("Leader").style(THEME.table_header_style()),
-            Cell::from("Replicas").style(THEME.table_header_style()),
-            Cell::from("ISR").style(THEME.table_header_style()),
-            Cell::from("Low").style(THEME.table_header_style()),
-            Cell::from("High").style(THEME.table_header_style()),
-            Cell::from("Messages").style(THEME.table_header_style()),
-        ]).height(1);
+    fn render_partitions(
+        frame: &mut Frame,
+        area: Rect,
+        detail: &crate::app::state::TopicDetail,
+        selected: usize,
+        hosts: &HashMap<i32, String>,
+        numbers: NumberFormat,
+    ) {
+        let headers = [" ID", "Leader", "Leader Host", "Replicas", "ISR", "Low", "High", "Messages", "Status"];
+
+        let replication = detail.replication_range();
+        let max_replicas = replication.map_or(0, |(_, max)| max);
 
         let rows: Vec<Row> = detail.partitions.iter().map(|p| {
             let replicas = p.replicas.iter().map(|r| r.to_string()).collect::<Vec<_>>().join(",");
             let isr = p.isr.iter().map(|r| r.to_string()).collect::<Vec<_>>().join(",");
             let msg_count = p.message_count();
+            let status = match p.reassignment() {
+                Some(r) => {
+                    let brokers = r.catching_up.iter().map(|b| b.to_string()).collect::<Vec<_>>().join(",");
+                    Cell::from(format!("reassigning → {}", brokers)).style(THEME.warning_style())
+                }
+                None => Cell::from(""),
+            };
 
             Row::new(vec![
                 Cell::from(format!(" {}", p.id)).style(THEME.partition_style()),
                 Cell::from(p.leader.to_string()),
-                Cell::from(format!("[{}]", replicas)),
+                match hosts.get(&p.leader) {
+                    Some(host) => Cell::from(host.as_str()),
+                    None => Cell::from("-").style(THEME.muted_style()),
+                },
+                // Partitions short of the others' replicas stand out
+                Cell::from(format!("[{}]", replicas)).style(
+                    if p.replicas.len() < max_replicas { THEME.warning_style() } else { THEME.normal_style() }
+                ),
                 Cell::from(format!("[{}]", isr)).style(
-                    if p.isr.len() < p.replicas.len() { THEME.warning_style() } else { THEME.normal_style() }
+                    if p.is_under_replicated() { THEME.warning_style() } else { THEME.normal_style() }
                 ),
-                Cell::from(format_number(p.low_watermark)).style(THEME.offset_style()),
-                Cell::from(format_number(p.high_watermark)).style(THEME.offset_style()),
-                Cell::from(format_number(msg_count)).style(THEME.info_style()),
+                Cell::from(format_number(p.low_watermark, numbers)).style(THEME.offset_style()),
+                Cell::from(format_number(p.high_watermark, numbers)).style(THEME.offset_style()),
+                Cell::from(format_number(msg_count, numbers)).style(THEME.info_style()),
+                status,
             ])
         }).collect();
 
@@ -94,88 +122,64 @@ impl TopicDetailsScreen {
         let total_messages: i64 = detail.partitions.iter().map(|p| p.message_count()).sum();
         let partition_count = detail.partitions.len();
 
-        let summary = format!(
-            " {} partitions | {} total messages",
-            partition_count,
-            format_number(total_messages)
-        );
+        let replication = match replication {
+            Some((min, max)) if min != max => {
+                Span::styled(format!("⚠ RF {}–{}, uneven across partitions", min, max), THEME.warning_style())
+            }
+            Some((rf, _)) => Span::raw(format!("RF {}", rf)),
+            None => Span::raw("RF -"),
+        };
+        let summary = Line::from(vec![
+            Span::raw(format!(" {} partitions | ", partition_count)),
+            replication,
+            Span::raw(format!(" | {} total messages", format_number(total_messages, numbers))),
+        ]);
 
         let chunks = Layout::default()
             .direction(Direction::Vertical)
-            .constraints([Constraint::Length(1), Constraint::Min(5)])
+            .constraints([Constraint::Length(1), Constraint::Length(1), Constraint::Min(5)])
             .split(area);
 
-        let summary_text = Paragraph::new(summary).style(THEME.muted_style());
-        frame.render_widget(summary_text, chunks[0]);
-
-        let table = Table::new(
-            rows,
-            [
-                Constraint::Length(5),   // ID
-                Constraint::Length(7),   // Leader
-                Constraint::Length(12),  // Replicas
-                Constraint::Length(12),  // ISR
-                Constraint::Length(10),  // Low
-                Constraint::Length(10),  // High
-                Constraint::Min(10),     // Messages
-            ]
-        )
-        .header(header)
-        .row_highlight_style(THEME.selected_style());
-
-        frame.render_widget(table, chunks[1]);
-    }
+        frame.render_widget(Paragraph::new(Self::policy_line(detail)), chunks[0]);
 
-    fn render_config(frame: &mut Frame, area: Rect, detail: &crate::app::state::TopicDetail) {
-        if detail.config.is_empty() {
-            let empty = Paragraph::new("No configuration available")
-                .style(THEME.muted_style())
-                .alignment(Alignment::Center);
-            frame.render_widget(empty, area);
-            return;
-        }
-
-        let header = Row::new(vec![
-            Cell::from(" Name").style(THEME.table_header_style()),
-            Cell::from("Value").style(THEME.table_header_style()),
-        ]).height(1);
-
-        let rows: Vec<Row> = detail.config.iter().map(|(name, value)| {
-            let value_style = if value == "true" {
-                THEME.success_style()
-            } else if value == "false" {
-                THEME.muted_style()
-            } else if value.parse::<i64>().is_ok() {
-                THEME.info_style()
-            } else {
-                THEME.normal_style()
-            };
-
-            Row::new(vec![
-                Cell::from(format!(" {}", name)).style(THEME.normal_style()),
-                Cell::from(value.clone()).style(value_style),
-            ])
-        }).collect();
-
-        let table = Table::new(
-            rows,
-            [Constraint::Percentage(50), Constraint::Percentage(50)]
-        )
-        .header(header)
-        .row_highlight_style(THEME.selected_style());
-
-        frame.render_widget(table, area);
+        let summary_text = Paragraph::new(summary).style(THEME.muted_style());
+        frame.render_widget(summary_text, chunks[1]);
+
+        let widths = [
+            Constraint::Length(5),   // ID
+            Constraint::Length(7),   // Leader
+            Constraint::Length(24),  // Leader Host
+            Constraint::Length(12),  // Replicas
+            Constraint::Length(12),  // ISR
+            Constraint::Length(numbers.column_width()), // Low
+            Constraint::Length(numbers.column_width()), // High
+            Constraint::Length(numbers.column_width()), // Messages
+            Constraint::Min(16),     // Status
+        ];
+        render_table(frame, chunks[2], &headers, rows, &widths, Some(selected));
     }
-}
 
-fn format_number(n: i64) -> String {
-    if n >= 1_000_000_000 {
-        format!("{:.1}B", n as f64 / 1_000_000_000.0)
-    } else if n >= 1_000_000 {
-        format!("{:.1}M", n as f64 / 1_000_000.0)
-    } else if n >= 1_000 {
-        format!("{:.1}K", n as f64 / 1_000.0)
-    } else {
-        n.to_string()
+    /// The settings people check first: compaction, retention and maximum message size.
+    fn policy_line(detail: &crate::app::state::TopicDetail) -> Line<'static> {
+        let config = |name: &str| detail.config.iter().find(|(k, _)| k == name).map(|(_, v)| v.as_str());
+        let cleanup = config("cleanup.policy").unwrap_or("-").to_string();
+        let retention = config("retention.ms")
+            .and_then(|v| v.parse().ok())
+            .map(format_duration_ms)
+            .unwrap_or_else(|| "-".into());
+        let max_message = config("max.message.bytes")
+            .and_then(|v| v.parse().ok())
+            .map(format_bytes)
+            .unwrap_or_else(|| "-".into());
+
+        let cleanup_style = if cleanup.contains("compact") { THEME.warning_style() } else { THEME.info_style() };
+        Line::from(vec![
+            Span::styled(" Cleanup: ", THEME.muted_style()),
+            Span::styled(cleanup, cleanup_style),
+            Span::styled(" | Retention: ", THEME.muted_style()),
+            Span::styled(retention, THEME.info_style()),
+            Span::styled(" | Max message: ", THEME.muted_style()),
+            Span::styled(max_message, THEME.info_style()),
+        ])
     }
 }
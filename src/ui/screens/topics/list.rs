@@ -1,11 +1,16 @@
+use std::ops::Range;
+
 use ratatui::{
+    layout::Flex,
     prelude::*,
-    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+    widgets::{Block, Borders, Cell, Paragraph, Row, TableState},
 };
 
-use crate::app::state::AppState;
+use crate::app::state::{AppState, TopicInfo, TopicSortField, TopicsState};
 use crate::ui::layout::topics_list_layout;
 use crate::ui::theme::THEME;
+use crate::ui::widgets::{format_number, render_scrollbar, table_rows, table_widget, visible_window};
+
 
 pub struct TopicsListScreen;
 
@@ -13,17 +18,26 @@ impl TopicsListScreen {
     pub fn render(frame: &mut Frame, area: Rect, state: &AppState) {
         let layout = topics_list_layout(area);
 
+        // Filter once per frame; on clusters with many topics this is the expensive part
+        let topics = state.topics_state.filtered_topics();
+
+        // Rows below the list's border and header, remembered for paging
+        let page_rows = layout.list.height.saturating_sub(3) as usize;
+        state.topics_state.page_rows.set(page_rows);
+        let window = visible_window(topics.len(), state.topics_state.selected_index, page_rows);
+
         // Render toolbar
-        Self::render_toolbar(frame, layout.toolbar, state);
+        Self::render_toolbar(frame, layout.toolbar, state, topics.len(), &window);
 
         // Render topics list
-        Self::render_list(frame, layout.list, state);
+        Self::render_list(frame, layout.list, state, &topics, window);
 
         // Render details panel
-        Self::render_details(frame, layout.details, state);
+        let selected = topics.get(state.topics_state.selected_index).copied();
+        Self::render_details(frame, layout.details, state, selected);
     }
 
-    fn render_toolbar(frame: &mut Frame, area: Rect, state: &AppState) {
+    fn render_toolbar(frame: &mut Frame, area: Rect, state: &AppState, filtered_count: usize, window: &Range<usize>) {
         let block = Block::default()
             .borders(Borders::BOTTOM)
             .border_style(THEME.border_style(false));
@@ -35,7 +49,7 @@ impl TopicsListScreen {
             .direction(Direction::Horizontal)
             .constraints([
                 Constraint::Min(20),      // Filter info
-                Constraint::Length(20),   // Topic count
+                Constraint::Length(36),   // Visible range and topic count
             ])
             .split(inner);
 
@@ -48,16 +62,42 @@ impl TopicsListScreen {
                 THEME.info_style(),
             )
         };
-        let filter_widget = Paragraph::new(filter_text);
+        let mut filter_spans = vec![filter_text];
+        if state.topics_state.filter_regex {
+            let mode = if state.topics_state.filter_regex_invalid() {
+                "  [regex invalid, substring]"
+            } else {
+                "  [regex]"
+            };
+            filter_spans.push(Span::styled(mode, THEME.muted_style()));
+        }
+        if state.topics_state.filter_fuzzy {
+            filter_spans.push(Span::styled("  [fuzzy]", THEME.muted_style()));
+        }
+        if state.topics_state.bookmarks_only {
+            filter_spans.push(Span::styled("  [★ bookmarks]", THEME.warning_style()));
+        }
+        if state.topics_state.under_replicated_only {
+            filter_spans.push(Span::styled("  [! under-replicated]", THEME.warning_style()));
+        }
+        if !state.topics_state.marked.is_empty() {
+            let checked = format!("  [✓ {} checked, d deletes]", state.topics_state.marked.len());
+            filter_spans.push(Span::styled(checked, THEME.success_style()));
+        }
+        let filter_widget = Paragraph::new(Line::from(filter_spans));
         frame.render_widget(filter_widget, chunks[0]);
 
-        // Topic count
-        let filtered_count = state.topics_state.filtered_topics().len();
+        // Topic count, with the visible range once the list no longer fits
         let total_count = state.topics_state.topics.len();
-        let count_text = if filtered_count == total_count {
-            format!("{} topics ", total_count)
+        let count = if filtered_count == total_count {
+            format!("{} topics", total_count)
         } else {
-            format!("{}/{} topics ", filtered_count, total_count)
+            format!("{}/{} topics", filtered_count, total_count)
+        };
+        let count_text = if window.len() < filtered_count {
+            format!("showing {}–{} of {} ", window.start + 1, window.end, count)
+        } else {
+            format!("{} ", count)
         };
         let count_widget = Paragraph::new(count_text)
             .style(THEME.muted_style())
@@ -65,8 +105,9 @@ impl TopicsListScreen {
         frame.render_widget(count_widget, chunks[1]);
     }
 
-    fn render_list(frame: &mut Frame, area: Rect, state: &AppState) {
+    fn render_list(frame: &mut Frame, area: Rect, state: &AppState, filtered_topics: &[&TopicInfo], window: Range<usize>) {
         let focused = !state.ui_state.sidebar_focused;
+        let numbers = state.ui_state.number_format;
 
         let block = Block::default()
             .borders(Borders::ALL)
@@ -83,10 +124,10 @@ impl TopicsListScreen {
             return;
         }
 
-        let filtered_topics = state.topics_state.filtered_topics();
-
         if filtered_topics.is_empty() {
-            let empty_message = if state.topics_state.filter.is_empty() {
+            let empty_message = if state.topics_state.bookmarks_only {
+                "No bookmarked topics. Press 'b' on a topic to bookmark it."
+            } else if state.topics_state.filter.is_empty() {
                 "No topics found. Press 'n' to create one."
             } else {
                 "No topics match the filter."
@@ -101,57 +142,75 @@ impl TopicsListScreen {
 
         frame.render_widget(block, area);
 
-        // Table header
-        let header = Row::new(vec![
-            Cell::from(" Name").style(THEME.table_header_style()),
-            Cell::from("Partitions").style(THEME.table_header_style()),
-            Cell::from("Replication").style(THEME.table_header_style()),
-        ])
-        .height(1);
+        let show_counts = show_counts(&state.topics_state);
+
+        // Table header, with an arrow on the sorted column
+        let ts = &state.topics_state;
+        let arrow = if ts.sort_ascending { " ▲" } else { " ▼" };
+        let label = |title: &str, field: TopicSortField| {
+            let suffix = if ts.sort_by == field { arrow } else { "" };
+            format!("{}{}", title, suffix)
+        };
+        let mut headers = vec![
+            label(" Name", TopicSortField::Name),
+            label("Partitions", TopicSortField::Partitions),
+            label("Replication", TopicSortField::Replication),
+        ];
+        if show_counts {
+            headers.push("Messages".into());
+        }
+
+        // Only build rows for the visible slice
+        let selected = state.topics_state.selected_index;
 
         // Table rows
-        let rows: Vec<Row> = filtered_topics
+        let rows: Vec<Row> = filtered_topics[window.clone()]
             .iter()
             .map(|topic| {
-                let style = if topic.is_internal {
+                let style = if topic.error.is_some() || topic.has_under_replicated {
+                    THEME.warning_style()
+                } else if topic.is_internal {
                     THEME.topic_internal_style()
                 } else {
                     THEME.normal_style()
                 };
 
-                let name = if topic.is_internal {
-                    format!(" {} (internal)", topic.name)
-                } else {
-                    format!(" {}", topic.name)
+                let check = if ts.marked.contains(&topic.name) { "✓ " } else { "" };
+                let star = if ts.bookmarks.contains(&topic.name) { "★ " } else { "" };
+                let marker = match (topic.error.is_some(), topic.has_under_replicated) {
+                    (true, _) => "⚠ ",
+                    (false, true) => "! ",
+                    (false, false) => "",
                 };
-
-                Row::new(vec![
-                    Cell::from(name).style(style),
+                let mut name = vec![Span::styled(format!(" {}", check), THEME.success_style()), Span::raw(format!("{}{}", star, marker))];
+                name.extend(highlight_matches(&topic.name, ts.fuzzy_positions(&topic.name)));
+                if topic.is_internal {
+                    name.push(Span::raw(" (internal)"));
+                }
+
+                let mut cells = vec![
+                    Cell::from(Line::from(name)).style(style),
                     Cell::from(topic.partition_count.to_string()).style(THEME.partition_style()),
                     Cell::from(topic.replication_factor.to_string()),
-                ])
-                .height(1)
+                ];
+                if show_counts {
+                    let count = topic.message_count.map(|n| format_number(n, numbers)).unwrap_or_else(|| "-".into());
+                    cells.push(Cell::from(count).style(THEME.info_style()));
+                }
+                Row::new(cells).height(1)
             })
             .collect();
 
-        let widths = [
-            Constraint::Min(30),
-            Constraint::Length(12),
-            Constraint::Length(12),
-        ];
-
-        let table = Table::new(rows, widths)
-            .header(header)
-            .row_highlight_style(THEME.selected_style())
-            .highlight_symbol(" ");
+        let table = table_widget(&headers, rows, &column_widths(show_counts)).highlight_symbol(" ");
 
         let mut table_state = TableState::default();
-        table_state.select(Some(state.topics_state.selected_index));
+        table_state.select(Some(selected.saturating_sub(window.start)));
 
         frame.render_stateful_widget(table, inner, &mut table_state);
+        render_scrollbar(frame, table_rows(inner), ts);
     }
 
-    fn render_details(frame: &mut Frame, area: Rect, state: &AppState) {
+    fn render_details(frame: &mut Frame, area: Rect, state: &AppState, selected_topic: Option<&TopicInfo>) {
         let block = Block::default()
             .title(" Topic Details ")
             .borders(Borders::ALL)
@@ -160,8 +219,6 @@ impl TopicsListScreen {
         let inner = block.inner(area);
         frame.render_widget(block, area);
 
-        let selected_topic = state.topics_state.selected_topic();
-
         if let Some(topic) = selected_topic {
             let chunks = Layout::default()
                 .direction(Direction::Vertical)
@@ -172,6 +229,8 @@ impl TopicsListScreen {
                     Constraint::Length(1), // Partitions
                     Constraint::Length(1), // Replication
                     Constraint::Length(1), // Internal
+                    Constraint::Length(1), // Messages
+                    Constraint::Length(1), // Health
                     Constraint::Min(1),    // Spacer
                 ])
                 .split(inner);
@@ -215,6 +274,23 @@ impl TopicsListScreen {
                 ),
             ]);
             frame.render_widget(Paragraph::new(internal_line), chunks[4]);
+
+            // Approximate message count
+            let count_span = match topic.message_count {
+                Some(count) => Span::styled(format!("~{}", format_number(count, state.ui_state.number_format)), THEME.info_style()),
+                None if state.topics_state.counts_loading => Span::styled("loading...", THEME.loading_style()),
+                None => Span::styled("press 'c' to load", THEME.muted_style()),
+            };
+            let count_line = Line::from(vec![Span::styled("Messages: ", THEME.muted_style()), count_span]);
+            frame.render_widget(Paragraph::new(count_line), chunks[5]);
+
+            // Metadata health
+            let health_span = match &topic.error {
+                Some(e) => Span::styled(e.as_str(), THEME.warning_style()),
+                None => Span::styled("OK", THEME.success_style()),
+            };
+            let health_line = Line::from(vec![Span::styled("Health: ", THEME.muted_style()), health_span]);
+            frame.render_widget(Paragraph::new(health_line), chunks[6]);
         } else {
             let empty = Paragraph::new("Select a topic to view details")
                 .style(THEME.muted_style())
@@ -223,3 +299,61 @@ impl TopicsListScreen {
         }
     }
 }
+
+/// Message counts are loaded on demand; the column only appears once some exist
+fn show_counts(topics_state: &TopicsState) -> bool {
+    topics_state.topics.iter().any(|t| t.message_count.is_some())
+}
+
+fn column_widths(show_counts: bool) -> Vec<Constraint> {
+    let mut widths = vec![
+        Constraint::Min(30),
+        Constraint::Length(12),
+        Constraint::Length(12),
+    ];
+    if show_counts {
+        // Wide enough for exact counts too, so header clicks map the same in both formats
+        widths.push(Constraint::Length(14));
+    }
+    widths
+}
+
+/// Sort field for a click at column `x` on the header row of the topics table in `area`.
+pub fn sort_field_at(area: Rect, x: u16, topics_state: &TopicsState) -> Option<TopicSortField> {
+    // Mirror the table layout: inside the border, after the one-column highlight symbol
+    let inner = area.inner(Margin::new(1, 1));
+    let columns_area = Rect { x: inner.x + 1, width: inner.width.saturating_sub(1), ..inner };
+    let columns = Layout::horizontal(column_widths(show_counts(topics_state)))
+        .flex(Flex::Start)
+        .spacing(1)
+        .split(columns_area);
+
+    let index = columns.iter().position(|c| x >= c.x && x < c.x + c.width)?;
+    match index {
+        0 => Some(TopicSortField::Name),
+        1 => Some(TopicSortField::Partitions),
+        2 => Some(TopicSortField::Replication),
+        _ => None,
+    }
+}
+
+/// The topic name split into spans, with the characters the fuzzy filter matched highlighted.
+fn highlight_matches(name: &str, positions: Option<Vec<usize>>) -> Vec<Span<'static>> {
+    let Some(positions) = positions else {
+        return vec![Span::raw(name.to_string())];
+    };
+    let mut spans = Vec::new();
+    let mut run = String::new();
+    let mut run_matched = false;
+    for (i, c) in name.chars().enumerate() {
+        let matched = positions.binary_search(&i).is_ok();
+        if matched != run_matched && !run.is_empty() {
+            let text = std::mem::take(&mut run);
+            spans.push(if run_matched { Span::styled(text, THEME.search_match_style()) } else { Span::raw(text) });
+        }
+        run_matched = matched;
+        run.push(c);
+    }
+    spans.push(if run_matched { Span::styled(run, THEME.search_match_style()) } else { Span::raw(run) });
+    spans
+}
@@ -3,8 +3,11 @@ use ratatui::{
     widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
 };
 
-use crate::app::state::AppState;
+use chrono::Utc;
+
+use crate::app::state::{AppState, ConnectionStatus, TimestampFormat};
 use crate::ui::theme::THEME;
+use crate::ui::widgets::spinner_frame;
 
 pub struct WelcomeScreen;
 
@@ -51,6 +54,7 @@ impl WelcomeScreen {
                 .alignment(Alignment::Center);
             frame.render_widget(no_profiles, chunks[1]);
         } else {
+            let now = Utc::now();
             let items: Vec<ListItem> = state
                 .connection
                 .available_profiles
@@ -62,7 +66,12 @@ impl WelcomeScreen {
                         profile.name,
                         profile.brokers
                     );
-                    ListItem::new(text).style(THEME.normal_style())
+                    let last_used = profile
+                        .last_used
+                        .map(|at| format!("  · used {}", TimestampFormat::Relative.format(at, now, false)))
+                        .unwrap_or_default();
+                    ListItem::new(Line::from(vec![Span::raw(text), Span::styled(last_used, THEME.muted_style())]))
+                        .style(THEME.normal_style())
                 })
                 .collect();
 
@@ -82,12 +91,20 @@ impl WelcomeScreen {
             frame.render_stateful_widget(list, chunks[1], &mut list_state);
         }
 
+        // While connecting the hints give way to progress, since Enter does nothing until it ends
+        if let Some(progress) = connecting_line(state) {
+            frame.render_widget(Paragraph::new(progress).alignment(Alignment::Center), chunks[2]);
+            return;
+        }
+
         // Hints
         let hints = Line::from(vec![
             Span::styled("[Enter]", THEME.key_hint_style()),
             Span::styled(" Connect  ", THEME.muted_style()),
             Span::styled("[n]", THEME.key_hint_style()),
             Span::styled(" New connection  ", THEME.muted_style()),
+            Span::styled("[x/i]", THEME.key_hint_style()),
+            Span::styled(" Export/Import  ", THEME.muted_style()),
             Span::styled("[q]", THEME.key_hint_style()),
             Span::styled(" Quit", THEME.muted_style()),
         ]);
@@ -97,3 +114,17 @@ impl WelcomeScreen {
         frame.render_widget(hints_widget, chunks[2]);
     }
 }
+
+fn connecting_line(state: &AppState) -> Option<Line<'static>> {
+    let conn = &state.connection;
+    let attempt = match conn.status {
+        ConnectionStatus::Connecting => String::new(),
+        ConnectionStatus::Retrying { attempt, max } => format!(" (attempt {}/{})", attempt, max),
+        _ => return None,
+    };
+    let target = conn.active_profile.as_ref().map(|p| format!(" to {} ({})", p.name, p.brokers)).unwrap_or_default();
+    Some(Line::from(vec![
+        Span::styled(format!("{} ", spinner_frame()), THEME.status_connecting()),
+        Span::styled(format!("Connecting{}{}...", target, attempt), THEME.status_connecting()),
+    ]))
+}
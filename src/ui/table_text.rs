@@ -0,0 +1,142 @@
+//! The table on the active screen as Markdown, for pasting into tickets or chat.
+
+use crate::app::state::{AppState, ConsumerGroupDetailTab, Screen, TopicDetailTab};
+
+/// The active screen's table as a Markdown table, or `None` when it has nothing tabular.
+///
+/// Rows follow the current filter and sort order; numbers are copied unformatted.
+pub fn current_view(state: &AppState) -> Option<String> {
+    let (headers, rows): (&[&str], Vec<Vec<String>>) = match &state.active_screen {
+        Screen::Topics => {
+            let ts = &state.topics_state;
+            let rows = ts.filtered_topics().iter().map(|t| vec![
+                t.name.clone(),
+                t.partition_count.to_string(),
+                t.replication_factor.to_string(),
+                t.message_count.map_or("-".into(), |c| c.to_string()),
+            ]).collect();
+            (&["Name", "Partitions", "Replication", "Messages"], rows)
+        }
+        Screen::TopicDetails { .. } => {
+            let ts = &state.topics_state;
+            let detail = ts.current_detail.as_ref()?;
+            match ts.detail_tab {
+                TopicDetailTab::Partitions => {
+                    let hosts = &state.brokers_state.hosts;
+                    let rows = detail.partitions.iter().map(|p| vec![
+                        p.id.to_string(),
+                        p.leader.to_string(),
+                        hosts.get(&p.leader).cloned().unwrap_or_else(|| "-".into()),
+                        join_ids(&p.replicas),
+                        join_ids(&p.isr),
+                        p.low_watermark.to_string(),
+                        p.high_watermark.to_string(),
+                        p.message_count().to_string(),
+                    ]).collect();
+                    (&["ID", "Leader", "Leader Host", "Replicas", "ISR", "Low", "High", "Messages"], rows)
+                }
+                TopicDetailTab::Config => (&["Key", "Value"], key_values(&detail.config)),
+            }
+        }
+        Screen::ConsumerGroups => {
+            let rows = state.consumer_groups_state.filtered_groups().iter().map(|g| vec![
+                g.group_id.clone(),
+                g.state.clone(),
+                g.protocol_type.clone(),
+                g.members_count.to_string(),
+                g.topics.len().to_string(),
+                g.total_lag.to_string(),
+            ]).collect();
+            (&["Group ID", "State", "Protocol", "Members", "Topics", "Lag"], rows)
+        }
+        Screen::ConsumerGroupDetails { .. } => {
+            let cs = &state.consumer_groups_state;
+            let detail = cs.current_detail.as_ref()?;
+            match cs.detail_tab {
+                ConsumerGroupDetailTab::Members => {
+                    let rows = detail.members.iter().map(|m| vec![
+                        m.client_id.clone(),
+                        m.client_host.clone(),
+                        m.assignments.iter().map(|a| format!("{}-{}", a.topic, a.partition)).collect::<Vec<_>>().join(", "),
+                    ]).collect();
+                    (&["Client ID", "Host", "Assignments"], rows)
+                }
+                ConsumerGroupDetailTab::Offsets => {
+                    let rows = detail.offsets.iter().map(|o| vec![
+                        o.topic.clone(),
+                        o.partition.to_string(),
+                        o.current_offset.to_string(),
+                        o.log_end_offset.to_string(),
+                        o.lag.to_string(),
+                    ]).collect();
+                    (&["Topic", "Partition", "Current", "End", "Lag"], rows)
+                }
+            }
+        }
+        Screen::Brokers => {
+            let rows = state.brokers_state.brokers.iter().map(|b| vec![
+                b.id.to_string(),
+                b.host.clone(),
+                b.port.to_string(),
+                b.rack.clone().unwrap_or_default(),
+                if b.is_controller { "Controller" } else { "Follower" }.to_string(),
+            ]).collect();
+            (&["ID", "Host", "Port", "Rack", "Role"], rows)
+        }
+        Screen::BrokerDetails { .. } => (&["Key", "Value"], key_values(&state.brokers_state.current_detail.as_ref()?.config)),
+        Screen::Throughput => {
+            let rows = state.throughput_state.top_rates().iter().map(|r| vec![
+                r.topic.clone(),
+                format!("{:.1}", r.per_second),
+                r.messages.to_string(),
+            ]).collect();
+            (&["Topic", "Msgs/s", "Messages"], rows)
+        }
+        Screen::Welcome | Screen::Messages { .. } | Screen::Logs => return None,
+    };
+    Some(markdown_table(headers, &rows))
+}
+
+fn join_ids(ids: &[i32]) -> String {
+    ids.iter().map(|id| id.to_string()).collect::<Vec<_>>().join(",")
+}
+
+fn key_values(pairs: &[(String, String)]) -> Vec<Vec<String>> {
+    pairs.iter().map(|(k, v)| vec![k.clone(), v.clone()]).collect()
+}
+
+/// Render a Markdown table, escaping pipes so cell text can't break the columns.
+fn markdown_table(headers: &[&str], rows: &[Vec<String>]) -> String {
+    let line = |cells: Vec<String>| format!("| {} |\n", cells.join(" | "));
+    let mut out = line(headers.iter().map(|h| h.to_string()).collect());
+    out.push_str(&line(headers.iter().map(|_| "---".to_string()).collect()));
+    for row in rows {
+        out.push_str(&line(row.iter().map(|c| c.replace('|', "\\|").replace('\n', " ")).collect()));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::state::BrokerInfo;
+
+    #[test]
+    fn test_brokers_as_markdown() {
+        let mut state = AppState { active_screen: Screen::Brokers, ..Default::default() };
+        state.brokers_state.brokers = vec![
+            BrokerInfo { id: 1, host: "kafka-1".into(), port: 9092, rack: Some("use1-az1".into()), is_controller: true },
+            BrokerInfo { id: 2, host: "a|b".into(), port: 9093, rack: None, is_controller: false },
+        ];
+        assert_eq!(
+            current_view(&state).unwrap(),
+            "| ID | Host | Port | Rack | Role |\n\
+             | --- | --- | --- | --- | --- |\n\
+             | 1 | kafka-1 | 9092 | use1-az1 | Controller |\n\
+             | 2 | a\\|b | 9093 |  | Follower |\n"
+        );
+
+        state.active_screen = Screen::Logs;
+        assert_eq!(current_view(&state), None);
+    }
+}
@@ -143,6 +143,10 @@ impl Theme {
         Style::default().fg(self.muted).add_modifier(Modifier::ITALIC)
     }
 
+    pub fn search_match_style(&self) -> Style {
+        Style::default().fg(self.warning).add_modifier(Modifier::BOLD)
+    }
+
     pub fn highlight_style(&self) -> Style {
         Style::default().bg(self.accent).fg(self.bg).add_modifier(Modifier::BOLD)
     }
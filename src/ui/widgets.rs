@@ -1,8 +1,13 @@
 use ratatui::{
     prelude::*,
-    widgets::{Block, Borders, Paragraph},
+    widgets::{
+        Block, Borders, Cell, Paragraph, Row, Scrollbar, ScrollbarOrientation, ScrollbarState, Table, TableState, Wrap,
+    },
 };
 
+use std::ops::Range;
+
+use crate::app::state::{byte_index, Navigable, NumberFormat};
 use crate::ui::theme::THEME;
 
 /// Formats a text input field with cursor and placeholder support.
@@ -23,6 +28,33 @@ pub fn format_input(value: &str, focused: bool, placeholder: &str) -> String {
     }
 }
 
+/// Formats a text input being edited at char index `cursor`, or unfocused when `cursor` is `None`.
+///
+/// The char under the cursor is drawn reversed; at the end of the value (or on a line break) a
+/// cursor block is drawn instead, as in [`format_input`].
+pub fn input_text(value: &str, placeholder: &str, cursor: Option<usize>) -> Text<'static> {
+    let Some(cursor) = cursor else {
+        return Text::raw(format_input(value, false, placeholder));
+    };
+    let (before, after) = value.split_at(byte_index(value, cursor));
+    let (mark, after) = match after.chars().next() {
+        Some(c) if c != '\n' => (
+            Span::styled(c.to_string(), Style::default().add_modifier(Modifier::REVERSED)),
+            &after[c.len_utf8()..],
+        ),
+        _ => (Span::raw("█"), after),
+    };
+
+    let mut lines: Vec<Line> = before.split('\n').map(|l| Line::raw(l.to_string())).collect();
+    let mut rest = after.split('\n');
+    if let Some(line) = lines.last_mut() {
+        line.push_span(mark);
+        line.push_span(Span::raw(rest.next().unwrap_or_default().to_string()));
+    }
+    lines.extend(rest.map(|l| Line::raw(l.to_string())));
+    Text::from(lines)
+}
+
 /// Returns the appropriate style for a label based on focus state.
 pub fn label_style(focused: bool) -> Style {
     if focused {
@@ -45,11 +77,53 @@ pub fn render_labeled_input(
     placeholder: &str,
     focused: bool,
 ) {
+    let cursor = focused.then(|| value.chars().count());
+    render_labeled_text_input(frame, label_area, input_area, label_text, value, placeholder, cursor);
+}
+
+/// Renders a labeled input field with the cursor at char index `cursor`; `None` when unfocused.
+pub fn render_labeled_text_input(
+    frame: &mut Frame,
+    label_area: Rect,
+    input_area: Rect,
+    label_text: &str,
+    value: &str,
+    placeholder: &str,
+    cursor: Option<usize>,
+) {
+    let label = Paragraph::new(label_text).style(label_style(cursor.is_some()));
+    frame.render_widget(label, label_area);
+
+    let input = Paragraph::new(input_text(value, placeholder, cursor)).style(THEME.input_style(cursor.is_some()));
+    frame.render_widget(input, input_area);
+}
+
+/// Renders a labeled input whose value may span several lines.
+///
+/// Long values wrap, and the view scrolls so the line holding the cursor stays visible.
+pub fn render_labeled_multiline_input(
+    frame: &mut Frame,
+    label_area: Rect,
+    input_area: Rect,
+    label_text: &str,
+    value: &str,
+    placeholder: &str,
+    cursor: Option<usize>,
+) {
+    let focused = cursor.is_some();
     let label = Paragraph::new(label_text).style(label_style(focused));
     frame.render_widget(label, label_area);
 
-    let display = format_input(value, focused, placeholder);
-    let input = Paragraph::new(display).style(THEME.input_style(focused));
+    let text = input_text(value, placeholder, cursor);
+    // Wrapped rows down to the end of the cursor's line; unfocused, that's the whole value
+    let cursor_line = cursor.map_or(text.lines.len(), |c| value[..byte_index(value, c)].matches('\n').count() + 1);
+    let rows = Paragraph::new(text.lines[..cursor_line].to_vec())
+        .wrap(Wrap { trim: false })
+        .line_count(input_area.width);
+    let input = Paragraph::new(text)
+        .style(THEME.input_style(focused))
+        .wrap(Wrap { trim: false })
+        .scroll((u16::try_from(rows).unwrap_or(u16::MAX).saturating_sub(input_area.height), 0));
     frame.render_widget(input, input_area);
 }
 
@@ -87,3 +161,350 @@ pub fn render_empty(frame: &mut Frame, area: Rect, message: &str) {
         .alignment(Alignment::Center);
     frame.render_widget(empty, area);
 }
+
+/// Frames of the busy indicator, advanced every 100ms
+const SPINNER: [char; 10] = ['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
+
+/// The busy indicator frame for the current time, so it animates with each redraw
+pub fn spinner_frame() -> char {
+    let tenths = chrono::Utc::now().timestamp_millis() / 100;
+    SPINNER[tenths.rem_euclid(SPINNER.len() as i64) as usize]
+}
+
+/// Rows shown at once by [`render_pair_list`]; the list scrolls to keep the selection visible.
+pub const VISIBLE_PAIRS: usize = 4;
+
+/// Renders an editable name/value list such as produce headers or topic config overrides.
+pub fn render_pair_list(
+    frame: &mut Frame,
+    area: Rect,
+    pairs: &[(String, String)],
+    index: usize,
+    value_focused: bool,
+    focused: bool,
+    empty_hint: &str,
+) {
+    if pairs.is_empty() {
+        let placeholder = if focused { empty_hint } else { "(none)" };
+        frame.render_widget(Paragraph::new(placeholder).style(THEME.muted_style()), area);
+        return;
+    }
+
+    let first = index.saturating_sub(VISIBLE_PAIRS - 1);
+    let lines: Vec<Line> = pairs
+        .iter()
+        .enumerate()
+        .skip(first)
+        .take(VISIBLE_PAIRS)
+        .map(|(i, (name, value))| {
+            let selected = focused && i == index;
+            let name_editing = selected && !value_focused;
+            let value_editing = selected && value_focused;
+            Line::from(vec![
+                Span::raw(if selected { "> " } else { "  " }),
+                Span::styled(format_input(name, name_editing, "name"), THEME.input_style(name_editing)),
+                Span::styled(": ", THEME.muted_style()),
+                Span::styled(format_input(value, value_editing, "value"), THEME.input_style(value_editing)),
+            ])
+        })
+        .collect();
+    frame.render_widget(Paragraph::new(lines), area);
+}
+
+/// Renders a name/value configuration table with the given row selected.
+pub fn render_config_table(frame: &mut Frame, area: Rect, config: &[(String, String)], selected: usize) {
+    if config.is_empty() {
+        let empty = Paragraph::new("No configuration available")
+            .style(THEME.muted_style())
+            .alignment(Alignment::Center);
+        frame.render_widget(empty, area);
+        return;
+    }
+
+    let rows: Vec<Row> = config.iter().map(|(name, value)| {
+        let value_style = if value == "true" {
+            THEME.success_style()
+        } else if value == "false" {
+            THEME.muted_style()
+        } else if value.parse::<i64>().is_ok() {
+            THEME.info_style()
+        } else {
+            THEME.normal_style()
+        };
+
+        Row::new(vec![
+            Cell::from(format!(" {}", name)).style(THEME.normal_style()),
+            Cell::from(value.clone()).style(value_style),
+        ])
+    }).collect();
+
+    let widths = [Constraint::Percentage(50), Constraint::Percentage(50)];
+    render_table(frame, area, &[" Name", "Value"], rows, &widths, Some(selected));
+}
+
+/// A table under a one-line header in the header style, highlighting the selected row.
+///
+/// Cells start with a space to clear the border, so the first header should too.
+pub fn table_widget<'a>(headers: &[impl AsRef<str>], rows: Vec<Row<'a>>, widths: &[Constraint]) -> Table<'a> {
+    let header = Row::new(headers.iter().map(|h| Cell::from(h.as_ref().to_string()).style(THEME.table_header_style())))
+        .height(1);
+    Table::new(rows, widths.to_vec())
+        .header(header)
+        .row_highlight_style(THEME.selected_style())
+}
+
+/// Renders `table_widget` in `area` with row `selected` of `rows` highlighted.
+pub fn render_table(
+    frame: &mut Frame,
+    area: Rect,
+    headers: &[impl AsRef<str>],
+    rows: Vec<Row>,
+    widths: &[Constraint],
+    selected: Option<usize>,
+) {
+    let mut table_state = TableState::default();
+    table_state.select(selected);
+    frame.render_stateful_widget(table_widget(headers, rows, widths), area, &mut table_state);
+}
+
+/// Formats a byte count compactly, e.g. `512B`, `1.5KB`, `2.0MB`.
+pub fn format_bytes(n: usize) -> String {
+    let n = n as f64;
+    if n >= 1_000_000_000.0 {
+        format!("{:.1}GB", n / 1_000_000_000.0)
+    } else if n >= 1_000_000.0 {
+        format!("{:.1}MB", n / 1_000_000.0)
+    } else if n >= 1_000.0 {
+        format!("{:.1}KB", n / 1_000.0)
+    } else {
+        format!("{}B", n)
+    }
+}
+
+/// Formats an offset, lag or message count, e.g. `1.5M` abbreviated or `1,500,000` exact.
+pub fn format_number(n: i64, format: NumberFormat) -> String {
+    match format {
+        NumberFormat::Abbreviated if n >= 1_000_000_000 => format!("{:.1}B", n as f64 / 1_000_000_000.0),
+        NumberFormat::Abbreviated if n >= 1_000_000 => format!("{:.1}M", n as f64 / 1_000_000.0),
+        NumberFormat::Abbreviated if n >= 1_000 => format!("{:.1}K", n as f64 / 1_000.0),
+        NumberFormat::Abbreviated => n.to_string(),
+        NumberFormat::Exact => {
+            let digits = n.unsigned_abs().to_string();
+            let mut grouped = String::with_capacity(digits.len() + digits.len() / 3 + 1);
+            if n < 0 {
+                grouped.push('-');
+            }
+            for (i, c) in digits.chars().enumerate() {
+                if i > 0 && (digits.len() - i).is_multiple_of(3) {
+                    grouped.push(',');
+                }
+                grouped.push(c);
+            }
+            grouped
+        }
+    }
+}
+
+/// Formats a millisecond duration in the largest whole unit, e.g. `7d`, `12h`, `90s`.
+///
+/// Negative values are Kafka's "no limit" and render as `unlimited`.
+pub fn format_duration_ms(ms: i64) -> String {
+    const UNITS: [(i64, &str); 4] = [(86_400_000, "d"), (3_600_000, "h"), (60_000, "m"), (1_000, "s")];
+    if ms < 0 {
+        return "unlimited".into();
+    }
+    UNITS
+        .iter()
+        .find(|(size, _)| ms >= *size && ms % size == 0)
+        .map(|(size, unit)| format!("{}{}", ms / size, unit))
+        .unwrap_or_else(|| format!("{}ms", ms))
+}
+
+/// Horizontal bar `width` cells wide, filled in eighths to `value / max`.
+///
+/// Any positive value shows at least a sliver so it never reads as zero.
+pub fn fraction_bar(value: i64, max: i64, width: usize) -> String {
+    const PARTIALS: [char; 8] = [' ', '▏', '▎', '▍', '▌', '▋', '▊', '▉'];
+    let eighths = if value <= 0 || max <= 0 {
+        0
+    } else {
+        ((value.min(max) as f64 / max as f64 * (width * 8) as f64).round() as usize).max(1)
+    };
+    let mut bar = "█".repeat(eighths / 8);
+    if eighths % 8 > 0 {
+        bar.push(PARTIALS[eighths % 8]);
+    }
+    let filled = bar.chars().count();
+    bar + &" ".repeat(width - filled)
+}
+
+/// Slice of a list that fits in `height` rows while keeping `selected` in view.
+///
+/// Lists only build rows for this window, so render cost tracks the viewport
+/// rather than the number of items.
+pub fn visible_window(len: usize, selected: usize, height: usize) -> Range<usize> {
+    let height = height.max(1);
+    let start = selected.saturating_sub(height - 1).min(len.saturating_sub(height));
+    start..(start + height).min(len)
+}
+
+/// Like [`visible_window`], but starts from the window shown last time (`scroll`) and
+/// only moves it when `selected` has left it, so moving within the view doesn't scroll.
+pub fn scrolled_window(len: usize, selected: usize, height: usize, scroll: &std::cell::Cell<usize>) -> Range<usize> {
+    let height = height.max(1);
+    let mut start = scroll.get();
+    if selected < start {
+        start = selected;
+    } else if selected >= start + height {
+        start = selected + 1 - height;
+    }
+    start = start.min(len.saturating_sub(height));
+    scroll.set(start);
+    start..(start + height).min(len)
+}
+
+/// Draws a scroll position indicator for a list whose rows occupy `rows`.
+///
+/// The bar sits on the column just right of `rows` (the enclosing block's border)
+/// and is skipped when every item fits.
+pub fn render_scrollbar(frame: &mut Frame, rows: Rect, list: &impl Navigable) {
+    let len = list.item_count();
+    let viewport = rows.height as usize;
+    if len <= viewport {
+        return;
+    }
+
+    let track = Rect { x: rows.right(), width: 1, ..rows };
+    let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
+        .begin_symbol(None)
+        .end_symbol(None)
+        .style(THEME.muted_style());
+    let mut state = ScrollbarState::new(len)
+        .position(list.selected_index())
+        .viewport_content_length(viewport);
+    frame.render_stateful_widget(scrollbar, track, &mut state);
+}
+
+/// Area below a table's one-line header.
+pub fn table_rows(inner: Rect) -> Rect {
+    Rect { y: inner.y + 1, height: inner.height.saturating_sub(1), ..inner }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_visible_window_large_list() {
+        assert_eq!(visible_window(10_000, 0, 20), 0..20);
+        assert_eq!(visible_window(10_000, 5_000, 20), 4_981..5_001);
+        assert_eq!(visible_window(10_000, 9_999, 20), 9_980..10_000);
+        assert_eq!(visible_window(10_000, 5_000, 20).len(), 20);
+    }
+
+    #[test]
+    fn test_scrolled_window_keeps_position() {
+        let scroll = std::cell::Cell::new(0);
+        assert_eq!(scrolled_window(100, 25, 10, &scroll), 16..26);
+        // Moving up inside the window leaves it where it is
+        assert_eq!(scrolled_window(100, 18, 10, &scroll), 16..26);
+        assert_eq!(scrolled_window(100, 15, 10, &scroll), 15..25);
+        // A shorter list (e.g. after filtering) pulls the window back
+        assert_eq!(scrolled_window(12, 11, 10, &scroll), 2..12);
+        assert_eq!(scrolled_window(3, 0, 10, &scroll), 0..3);
+    }
+
+    #[test]
+    fn test_visible_window_short_list() {
+        assert_eq!(visible_window(5, 3, 20), 0..5);
+        assert_eq!(visible_window(0, 0, 20), 0..0);
+    }
+
+    #[test]
+    fn test_format_number() {
+        assert_eq!(format_number(999, NumberFormat::Abbreviated), "999");
+        assert_eq!(format_number(1_500, NumberFormat::Abbreviated), "1.5K");
+        assert_eq!(format_number(2_345_678, NumberFormat::Abbreviated), "2.3M");
+        assert_eq!(format_number(7_000_000_000, NumberFormat::Abbreviated), "7.0B");
+        assert_eq!(format_number(0, NumberFormat::Exact), "0");
+        assert_eq!(format_number(999, NumberFormat::Exact), "999");
+        assert_eq!(format_number(1_000, NumberFormat::Exact), "1,000");
+        assert_eq!(format_number(2_345_678, NumberFormat::Exact), "2,345,678");
+        assert_eq!(format_number(-12_345, NumberFormat::Exact), "-12,345");
+    }
+
+    #[test]
+    fn test_format_duration_ms() {
+        assert_eq!(format_duration_ms(604_800_000), "7d");
+        assert_eq!(format_duration_ms(43_200_000), "12h");
+        assert_eq!(format_duration_ms(90_000), "90s");
+        assert_eq!(format_duration_ms(1_500), "1500ms");
+        assert_eq!(format_duration_ms(0), "0ms");
+        assert_eq!(format_duration_ms(-1), "unlimited");
+    }
+
+    #[test]
+    fn test_input_text_marks_cursor() {
+        let plain = |text: Text| text.lines.iter().map(|l| l.to_string()).collect::<Vec<_>>();
+        assert_eq!(plain(input_text("abc", "-", Some(1))), ["abc"]);
+        assert_eq!(input_text("abc", "-", Some(1)).lines[0].spans[1].content, "b");
+        assert_eq!(plain(input_text("abc", "-", Some(3))), ["abc█"]);
+        assert_eq!(plain(input_text("a\nb", "-", Some(1))), ["a█", "b"]);
+        assert_eq!(plain(input_text("", "-", None)), ["-"]);
+    }
+
+    #[test]
+    fn test_fraction_bar() {
+        assert_eq!(fraction_bar(0, 100, 4), "    ");
+        assert_eq!(fraction_bar(100, 100, 4), "████");
+        assert_eq!(fraction_bar(50, 100, 4), "██  ");
+        assert_eq!(fraction_bar(1, 1_000_000, 4), "▏   ");
+        assert_eq!(fraction_bar(5, 0, 4), "    ");
+    }
+
+    #[test]
+    fn test_render_table_layout() {
+        use ratatui::{backend::TestBackend, Terminal};
+
+        let rows = vec![
+            Row::new(vec![Cell::from(" orders"), Cell::from("3")]),
+            Row::new(vec![Cell::from(" payments"), Cell::from("12")]),
+        ];
+        let widths = [Constraint::Length(10), Constraint::Length(4)];
+        let mut terminal = Terminal::new(TestBackend::new(16, 4)).unwrap();
+        terminal
+            .draw(|frame| render_table(frame, frame.area(), &[" Topic", "Parts"], rows, &widths, Some(1)))
+            .unwrap();
+
+        let buffer = terminal.backend().buffer().clone();
+        let lines: Vec<String> = (0..4)
+            .map(|y| (0..16).map(|x| buffer[(x, y)].symbol()).collect())
+            .collect();
+        assert_eq!(lines, [" Topic     Part ", " orders    3    ", " payments  12   ", "                "]);
+        assert_eq!(buffer[(1, 0)].style().fg, THEME.table_header_style().fg);
+        assert_eq!(buffer[(1, 2)].style().bg, THEME.selected_style().bg);
+        assert_ne!(buffer[(1, 1)].style().bg, THEME.selected_style().bg);
+    }
+
+    #[test]
+    fn test_scrollbar_only_when_overflowing() {
+        use crate::app::state::{Level, LogsState};
+        use ratatui::{backend::TestBackend, Terminal};
+
+        let border_column = |len: usize| {
+            let mut logs = LogsState::default();
+            for i in 0..len {
+                logs.add(Level::Info, format!("entry {}", i));
+            }
+            let mut terminal = Terminal::new(TestBackend::new(10, 6)).unwrap();
+            terminal
+                .draw(|frame| render_scrollbar(frame, Rect::new(0, 0, 9, 6), &logs))
+                .unwrap();
+            let buffer = terminal.backend().buffer().clone();
+            (0..6).map(|y| buffer[(9, y)].symbol().to_string()).collect::<String>()
+        };
+
+        assert_eq!(border_column(6).trim(), "");
+        assert_ne!(border_column(50).trim(), "");
+    }
+}